@@ -26,6 +26,20 @@ fn main() {
 
         debug_mode: { any(feature = "debug_order", debug_assertions) },
 
+        // the crate is no_std on linux unless one of those features is enabled
+        has_std: { any(
+            feature = "parking_lot_core",
+            feature = "poison",
+            feature = "timeout",
+            feature = "stats",
+            feature = "tracing",
+            feature = "startup_profile",
+            feature = "cycle_detect",
+            feature = "deadlock_detection",
+            debug_mode,
+            not(any(target_os = "linux", target_os = "android"))
+            )},
+
         //TODO: use crate linkme to implement priorities for mach_o
         support_priority: { any(elf,coff) },
 
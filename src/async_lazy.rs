@@ -0,0 +1,549 @@
+use crate::generic_lazy::{AccessError, LazyData, LazyPolicy, UnInited};
+use crate::lazy::InitializedChecker;
+use crate::phase_locker::{Mutex, PhaseGuard, SyncPhaseGuard, SyncPhaseLocker, SyncReadPhaseGuard};
+use crate::{Generator, LockNature, LockResult, Phase, Phased};
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Formatter};
+use core::future::{poll_fn, Future};
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+use core::pin::{pin, Pin};
+use core::task::{Context, Poll, Waker};
+
+/// The future type used by default by [AsyncLazy] and [AsyncLockedLazy].
+pub type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+
+/// A phase locker whose lock attempts do not block the thread:
+/// tasks that can not get the lock park on a waker list.
+struct AsyncPhaseLocker {
+    locker: SyncPhaseLocker,
+    wakers: Mutex<Vec<Waker>>,
+}
+
+/// Wake all parked tasks when dropped.
+struct Wake<'a>(&'a Mutex<Vec<Waker>>);
+
+struct AsyncWriteGuard<'a, T>(SyncPhaseGuard<'a, T>, Wake<'a>);
+
+struct AsyncReadGuard<'a, T>(SyncReadPhaseGuard<'a, T>, Wake<'a>);
+
+/// The data storage of the async lazies.
+///
+/// References to the storage are held across await points by the lock futures
+/// and the guards, so it must be `Sync` for these futures to be `Send`. The
+/// phase locker ensures that the value is only mutated under the write lock.
+struct AsyncUnInited<T>(UnInited<T>);
+
+unsafe impl<T: Send + Sync> Sync for AsyncUnInited<T> {}
+
+impl<T> AsyncUnInited<T> {
+    #[inline(always)]
+    const fn new() -> Self {
+        Self(UnInited::INIT)
+    }
+}
+
+impl<T> LazyData for AsyncUnInited<T> {
+    type Target = T;
+    #[inline(always)]
+    fn get(&self) -> *mut T {
+        self.0.get()
+    }
+    #[inline(always)]
+    unsafe fn init(&self, v: T) {
+        self.0.init(v)
+    }
+    #[inline(always)]
+    fn init_mut(&mut self, v: T) {
+        self.0.init_mut(v)
+    }
+}
+
+impl<'a> Drop for Wake<'a> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        wake_all(self.0)
+    }
+}
+
+#[inline]
+fn wake_all(wakers: &Mutex<Vec<Waker>>) {
+    let wakers = core::mem::take(&mut *wakers.lock());
+    for w in wakers {
+        w.wake()
+    }
+}
+
+impl<'a, T> From<AsyncWriteGuard<'a, T>> for AsyncReadGuard<'a, T> {
+    #[inline(always)]
+    fn from(this: AsyncWriteGuard<'a, T>) -> Self {
+        let AsyncWriteGuard(guard, wake) = this;
+        let r = AsyncReadGuard(guard.into(), wake);
+        // other readers can now get the lock
+        wake_all(r.1 .0);
+        r
+    }
+}
+
+impl AsyncPhaseLocker {
+    #[inline(always)]
+    const fn new() -> Self {
+        Self {
+            locker: SyncPhaseLocker::new(Phase::empty()),
+            wakers: Mutex::new(Vec::new()),
+        }
+    }
+    #[inline(always)]
+    fn phase(&self) -> Phase {
+        self.locker.phase()
+    }
+    #[inline(always)]
+    fn try_lock<'a, T>(
+        &'a self,
+        v: &'a T,
+        how: impl Fn(Phase) -> LockNature,
+    ) -> Option<LockResult<AsyncReadGuard<'a, T>, AsyncWriteGuard<'a, T>>> {
+        self.locker
+            .try_lock(v, how, Phase::INITIALIZED)
+            .map(|l| match l {
+                LockResult::Read(l) => LockResult::Read(AsyncReadGuard(l, Wake(&self.wakers))),
+                LockResult::Write(l) => LockResult::Write(AsyncWriteGuard(l, Wake(&self.wakers))),
+                LockResult::None(p) => LockResult::None(p),
+            })
+    }
+    /// Lock the phase, parking the task on the waker list while the lock
+    /// is held by an other task or thread.
+    async fn lock<'a, T>(
+        &'a self,
+        v: &'a T,
+        how: impl Fn(Phase) -> LockNature,
+    ) -> LockResult<AsyncReadGuard<'a, T>, AsyncWriteGuard<'a, T>> {
+        poll_fn(|cx| self.poll_lock(cx, v, &how)).await
+    }
+    fn poll_lock<'a, T>(
+        &'a self,
+        cx: &mut Context<'_>,
+        v: &'a T,
+        how: impl Fn(Phase) -> LockNature,
+    ) -> Poll<LockResult<AsyncReadGuard<'a, T>, AsyncWriteGuard<'a, T>>> {
+        if let Some(l) = self.try_lock(v, &how) {
+            return Poll::Ready(l);
+        }
+        {
+            let mut wakers = self.wakers.lock();
+            if !wakers.iter().any(|w| w.will_wake(cx.waker())) {
+                wakers.push(cx.waker().clone());
+            }
+        }
+        // the lock may have been released before the waker was registered
+        match self.try_lock(v, how) {
+            Some(l) => Poll::Ready(l),
+            None => Poll::Pending,
+        }
+    }
+}
+
+/// Run the future returned by the generator and initialize the data.
+///
+/// If the future panics, the phase is set to INITIALIZATION_PANICKED. If
+/// the future is dropped before completion, the phase is left unchanged so that
+/// an other task will attempt the initialization.
+async fn initialize<'a, T, F, G>(guard: &mut AsyncWriteGuard<'a, AsyncUnInited<T>>, generator: &G)
+where
+    F: Future<Output = T>,
+    G: Generator<F>,
+{
+    let cur = guard.0.phase();
+
+    let before_init =
+        cur & !(Phase::INITIALIZED | Phase::INITIALIZATION_PANICKED | Phase::INITIALIZATION_SKIPED);
+
+    let initialized = before_init | Phase::INITIALIZED;
+
+    let initialization_panic =
+        before_init | Phase::INITIALIZATION_PANICKED | Phase::INITIALIZATION_SKIPED;
+
+    let future = guard
+        .0
+        .transition(|_| generator.generate(), cur, initialization_panic);
+
+    let mut future = pin!(future);
+
+    let v = poll_fn(|cx| {
+        guard.0.set_phase(initialization_panic);
+        let r = future.as_mut().poll(cx);
+        guard.0.set_phase(cur);
+        r
+    })
+    .await;
+
+    // SAFETY: the write lock is held
+    guard.0.transition(
+        |data| unsafe { data.init(v) },
+        initialized,
+        initialization_panic,
+    );
+}
+
+#[inline(always)]
+fn shall_init<S: LazyPolicy>(p: Phase, otherwise: LockNature) -> LockNature {
+    if S::shall_init(p) {
+        LockNature::Write
+    } else {
+        otherwise
+    }
+}
+
+/// A lazy whose initializer is a future.
+///
+/// The value is initialized by the first task that awaits [get](Self::get). Other
+/// tasks that concurrently await [get](Self::get) are parked on a waker list
+/// instead of blocking the thread.
+///
+/// ```
+/// use static_init::AsyncLazy;
+///
+/// static V: AsyncLazy<Vec<i32>> = AsyncLazy::new(|| Box::pin(async { vec![1, 2] }));
+///
+/// async fn use_it() {
+///     assert_eq!(V.get().await.len(), 2);
+/// }
+/// ```
+///
+/// If the initializer future panics, the initialization will be attempted again
+/// by the next task awaiting [get](Self::get). If the task that initializes the value is
+/// cancelled, an other task will perform the initialization.
+pub struct AsyncLazy<T, F = BoxFuture<T>, G = fn() -> F> {
+    value: AsyncUnInited<T>,
+    locker: AsyncPhaseLocker,
+    generator: G,
+    phantom: PhantomData<fn() -> F>,
+}
+
+unsafe impl<T: Send + Sync, F, G: Sync> Sync for AsyncLazy<T, F, G> {}
+
+impl<T, F, G> AsyncLazy<T, F, G> {
+    #[inline(always)]
+    /// Build a new lazy whose value will be the output of the future
+    /// returned by `generator`.
+    pub const fn new(generator: G) -> Self {
+        Self {
+            value: AsyncUnInited::new(),
+            locker: AsyncPhaseLocker::new(),
+            generator,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, F, G> AsyncLazy<T, F, G>
+where
+    F: Future<Output = T>,
+    G: Generator<F>,
+{
+    /// Initialize if necessary then return a reference to the target.
+    ///
+    /// # Panics
+    ///
+    /// Panic if previous attempt to initialize has panicked and the lazy policy does not
+    /// tolorate further initialization attempt or if initialization
+    /// panic.
+    pub async fn get(&self) -> &T {
+        match self.init_then_try_get().await {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+    /// Initialize if necessary then return a reference to the target
+    /// if the initialization succeeded, otherwise return an error.
+    pub async fn init_then_try_get(&self) -> Result<&T, AccessError> {
+        let phase = match self
+            .locker
+            .lock(&self.value, |p| {
+                shall_init::<InitializedChecker<G>>(p, LockNature::None)
+            })
+            .await
+        {
+            LockResult::Write(mut l) => {
+                initialize(&mut l, &self.generator).await;
+                l.0.phase()
+            }
+            LockResult::None(p) => p,
+            LockResult::Read(_) => unreachable!(),
+        };
+        if InitializedChecker::<G>::is_accessible(phase) {
+            // SAFETY: the value is initialized
+            Ok(unsafe { &*self.value.get() })
+        } else {
+            Err(AccessError { phase })
+        }
+    }
+    /// Initialize the target if there were no previous attempt to initialize it
+    /// and return the phase.
+    pub async fn init(&self) -> Phase {
+        let _ = self.init_then_try_get().await;
+        self.phase()
+    }
+}
+
+impl<T, F, G> AsyncLazy<T, F, G> {
+    #[inline(always)]
+    /// Return a reference to the target if initialized otherwise return an error.
+    pub fn try_get(&self) -> Result<&T, AccessError> {
+        let phase = self.locker.phase();
+        if phase.intersects(Phase::INITIALIZED) {
+            // SAFETY: the value is initialized
+            Ok(unsafe { &*self.value.get() })
+        } else {
+            Err(AccessError { phase })
+        }
+    }
+    #[inline(always)]
+    /// Return the current phase
+    pub fn phase(&self) -> Phase {
+        self.locker.phase()
+    }
+}
+
+impl<T, F, G> Phased for AsyncLazy<T, F, G> {
+    #[inline(always)]
+    fn phase(this: &Self) -> Phase {
+        this.locker.phase()
+    }
+}
+
+impl<T: Debug, F, G> Debug for AsyncLazy<T, F, G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.try_get() {
+            Ok(v) => write!(f, "{:?}", v),
+            Err(_) => write!(f, "UnInitialized"),
+        }
+    }
+}
+
+impl<T, F, G> Drop for AsyncLazy<T, F, G> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        if self.locker.phase().intersects(Phase::INITIALIZED) {
+            unsafe { self.value.get().drop_in_place() }
+        }
+    }
+}
+
+/// A mutable lazy whose initializer is a future.
+///
+/// The value is initialized by the first task that awaits a lock. Tasks waiting
+/// for a lock are parked on a waker list instead of blocking the thread.
+///
+/// ```
+/// use static_init::AsyncLockedLazy;
+///
+/// static V: AsyncLockedLazy<Vec<i32>> =
+///     AsyncLockedLazy::new(|| Box::pin(async { vec![1, 2] }));
+///
+/// async fn use_it() {
+///     V.write().await.push(3);
+///     assert_eq!(V.read().await.len(), 3);
+/// }
+/// ```
+pub struct AsyncLockedLazy<T, F = BoxFuture<T>, G = fn() -> F> {
+    value: AsyncUnInited<T>,
+    locker: AsyncPhaseLocker,
+    generator: G,
+    phantom: PhantomData<fn() -> F>,
+}
+
+unsafe impl<T: Send + Sync, F, G: Sync> Sync for AsyncLockedLazy<T, F, G> {}
+
+/// A read guard returned by [AsyncLockedLazy::read].
+pub struct ReadGuard<'a, T>(AsyncReadGuard<'a, AsyncUnInited<T>>);
+
+/// A write guard returned by [AsyncLockedLazy::write].
+pub struct WriteGuard<'a, T>(AsyncWriteGuard<'a, AsyncUnInited<T>>);
+
+impl<'a, T> Deref for ReadGuard<'a, T> {
+    type Target = T;
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        // SAFETY: the guard is only built if the value is initialized
+        unsafe { &*(*self.0 .0).get() }
+    }
+}
+
+impl<'a, T> Deref for WriteGuard<'a, T> {
+    type Target = T;
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        // SAFETY: the guard is only built if the value is initialized
+        unsafe { &*(*self.0 .0).get() }
+    }
+}
+
+impl<'a, T> DerefMut for WriteGuard<'a, T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: the guard is only built if the value is initialized
+        // and the write lock is held
+        unsafe { &mut *(*self.0 .0).get() }
+    }
+}
+
+impl<'a, T> Phased for ReadGuard<'a, T> {
+    #[inline(always)]
+    fn phase(this: &Self) -> Phase {
+        Phased::phase(&this.0 .0)
+    }
+}
+
+impl<'a, T> Phased for WriteGuard<'a, T> {
+    #[inline(always)]
+    fn phase(this: &Self) -> Phase {
+        Phased::phase(&this.0 .0)
+    }
+}
+
+impl<'a, T: Debug> Debug for ReadGuard<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", **self)
+    }
+}
+
+impl<'a, T: Debug> Debug for WriteGuard<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", **self)
+    }
+}
+
+impl<T, F, G> AsyncLockedLazy<T, F, G> {
+    #[inline(always)]
+    /// Build a new lazy whose value will be the output of the future
+    /// returned by `generator`.
+    pub const fn new(generator: G) -> Self {
+        Self {
+            value: AsyncUnInited::new(),
+            locker: AsyncPhaseLocker::new(),
+            generator,
+            phantom: PhantomData,
+        }
+    }
+    #[inline(always)]
+    /// Return the current phase
+    pub fn phase(&self) -> Phase {
+        self.locker.phase()
+    }
+}
+
+impl<T, F, G> AsyncLockedLazy<T, F, G>
+where
+    F: Future<Output = T>,
+    G: Generator<F>,
+{
+    /// Initialize if necessary and returns a read lock
+    ///
+    /// # Panics
+    ///
+    /// Panics if initialization panics or if initialization has panicked in a previous attempt to
+    /// initialize.
+    pub async fn read(&self) -> ReadGuard<'_, T> {
+        match self.init_then_try_read().await {
+            Ok(l) => l,
+            Err(e) => panic!("{}", e),
+        }
+    }
+    /// Initialize if necessary and returns a write lock
+    ///
+    /// # Panics
+    ///
+    /// Panics if initialization panics or if initialization has panicked in a previous attempt to
+    /// initialize.
+    pub async fn write(&self) -> WriteGuard<'_, T> {
+        match self.init_then_try_write().await {
+            Ok(l) => l,
+            Err(e) => panic!("{}", e),
+        }
+    }
+    /// Initialize if necessary and returns a read lock if the
+    /// initialization succeeded, otherwise return an error.
+    pub async fn init_then_try_read(&self) -> Result<ReadGuard<'_, T>, AccessError> {
+        match self
+            .locker
+            .lock(&self.value, |p| {
+                shall_init::<InitializedChecker<G>>(p, LockNature::Read)
+            })
+            .await
+        {
+            LockResult::Write(mut l) => {
+                if InitializedChecker::<G>::shall_init(l.0.phase()) {
+                    initialize(&mut l, &self.generator).await;
+                }
+                let phase = l.0.phase();
+                if InitializedChecker::<G>::is_accessible(phase) {
+                    Ok(ReadGuard(l.into()))
+                } else {
+                    Err(AccessError { phase })
+                }
+            }
+            LockResult::Read(l) => {
+                let phase = Phased::phase(&l.0);
+                if InitializedChecker::<G>::is_accessible(phase) {
+                    Ok(ReadGuard(l))
+                } else {
+                    Err(AccessError { phase })
+                }
+            }
+            LockResult::None(_) => unreachable!(),
+        }
+    }
+    /// Initialize if necessary and returns a write lock if the
+    /// initialization succeeded, otherwise return an error.
+    pub async fn init_then_try_write(&self) -> Result<WriteGuard<'_, T>, AccessError> {
+        match self.locker.lock(&self.value, |_| LockNature::Write).await {
+            LockResult::Write(mut l) => {
+                if InitializedChecker::<G>::shall_init(l.0.phase()) {
+                    initialize(&mut l, &self.generator).await;
+                }
+                let phase = l.0.phase();
+                if InitializedChecker::<G>::is_accessible(phase) {
+                    Ok(WriteGuard(l))
+                } else {
+                    Err(AccessError { phase })
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+    /// Initialize the target if there were no previous attempt to initialize it
+    /// and return the phase.
+    pub async fn init(&self) -> Phase {
+        let _ = self.init_then_try_read().await;
+        self.phase()
+    }
+}
+
+impl<T, F, G> Phased for AsyncLockedLazy<T, F, G> {
+    #[inline(always)]
+    fn phase(this: &Self) -> Phase {
+        this.locker.phase()
+    }
+}
+
+impl<T, F, G> Debug for AsyncLockedLazy<T, F, G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.phase().intersects(Phase::INITIALIZED) {
+            write!(f, "Initialized")
+        } else {
+            write!(f, "UnInitialized")
+        }
+    }
+}
+
+impl<T, F, G> Drop for AsyncLockedLazy<T, F, G> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        if self.locker.phase().intersects(Phase::INITIALIZED) {
+            unsafe { self.value.get().drop_in_place() }
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use crate::StaticInfo;
+use core::cell::RefCell;
+use core::fmt::{self, Display, Formatter};
+
+/// A lazy being initialized by the current thread
+struct Frame {
+    lazy: usize,
+    info: Option<StaticInfo>,
+}
+
+thread_local! {
+    static INITIALIZING: RefCell<Vec<Frame>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A non null identifier of the current thread, or 0 if it is being destroyed.
+#[inline]
+pub(crate) fn thread_id() -> usize {
+    INITIALIZING
+        .try_with(|s| s as *const _ as usize)
+        .unwrap_or(0)
+}
+
+/// Keeps a lazy on the stack of lazies initialized by the current thread.
+#[must_use]
+pub(crate) struct InitFrame(bool);
+
+impl InitFrame {
+    pub(crate) fn enter(lazy: usize, info: Option<&StaticInfo>) -> Self {
+        Self(
+            INITIALIZING
+                .try_with(|s| {
+                    s.borrow_mut().push(Frame {
+                        lazy,
+                        info: info.copied(),
+                    })
+                })
+                .is_ok(),
+        )
+    }
+}
+
+impl Drop for InitFrame {
+    fn drop(&mut self) {
+        if self.0 {
+            let _ = INITIALIZING.try_with(|s| s.borrow_mut().pop());
+        }
+    }
+}
+
+/// Panic payload of a lazy initialization that depends on itself.
+///
+/// The payload obtained with [std::panic::catch_unwind], or in a panic hook, can be
+/// downcast to this type, whose [Display] lists the lazies of the cycle:
+///
+/// ```no_run
+/// use static_init::{dynamic, CycleError};
+///
+/// #[dynamic(lazy)]
+/// static A: i32 = *B;
+///
+/// #[dynamic(lazy)]
+/// static B: i32 = *A;
+///
+/// let err = std::panic::catch_unwind(|| *A).unwrap_err();
+/// let err = err.downcast::<CycleError>().unwrap();
+/// let names = err.path().iter().map(|i| i.unwrap().variable_name).collect::<Vec<_>>();
+/// assert_eq!(names, ["A", "B", "A"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError {
+    path: Vec<Option<StaticInfo>>,
+}
+
+impl CycleError {
+    /// The lazies whose initialization were in progress on the thread, starting
+    /// and ending with the lazy that depends on itself.
+    ///
+    /// An element is `None` if the lazy was not declared with the
+    /// [dynamic](macro@crate::dynamic) attribute.
+    pub fn path(&self) -> &[Option<StaticInfo>] {
+        &self.path
+    }
+}
+
+impl Display for CycleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("Circular lazy initialization: ")?;
+        let (last, init) = match self.path.split_last() {
+            Some(v) => v,
+            None => return Ok(()),
+        };
+        for info in init {
+            match info {
+                Some(info) => write!(
+                    f,
+                    "{} ({}:{}) -> ",
+                    info.variable_name, info.file_name, info.line
+                )?,
+                None => f.write_str("<lazy> -> ")?,
+            }
+        }
+        match last {
+            Some(info) => f.write_str(info.variable_name),
+            None => f.write_str("<lazy>"),
+        }
+    }
+}
+
+impl std::error::Error for CycleError {}
+
+/// Panics because the lazy `lazy` is accessed while the current thread initializes it.
+///
+/// The payload of the panic is a [CycleError].
+#[cold]
+pub(crate) fn cycle_panic(lazy: usize, info: Option<&StaticInfo>) -> ! {
+    let mut path = INITIALIZING
+        .try_with(|s| {
+            let s = s.borrow();
+            match s.iter().position(|f| f.lazy == lazy) {
+                Some(pos) => s[pos..].iter().map(|f| f.info).collect(),
+                None => Vec::new(),
+            }
+        })
+        .unwrap_or_default();
+    path.push(info.copied());
+    let err = CycleError { path };
+    std::panic::panic_any(err)
+}
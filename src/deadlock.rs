@@ -0,0 +1,298 @@
+//! Detection of dead locks between threads holding and waiting on the locks of lazies.
+//!
+//! Each thread records the locks it holds and the lock it is waiting on. [check_deadlocks]
+//! looks for cycles of threads each waiting on a lock held by the next one. It can be called
+//! periodically by a watchdog thread:
+//!
+//! ```no_run
+//! use std::{thread, time::Duration};
+//!
+//! thread::spawn(|| loop {
+//!     thread::sleep(Duration::from_secs(10));
+//!     for deadlock in static_init::check_deadlocks() {
+//!         eprintln!("{}", deadlock);
+//!     }
+//! });
+//! ```
+use crate::StaticInfo;
+use core::fmt::{self, Display, Formatter};
+use core::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+use std::thread::{self, ThreadId};
+
+struct Wait {
+    lock: usize,
+    upgrading: bool,
+    info: Option<StaticInfo>,
+    seq: u64,
+}
+
+struct ThreadState {
+    id: ThreadId,
+    held: Vec<usize>,
+    waiting: Option<Wait>,
+}
+
+type SharedState = Arc<Mutex<ThreadState>>;
+
+static THREADS: Mutex<Vec<Weak<Mutex<ThreadState>>>> = Mutex::new(Vec::new());
+
+static WAIT_SEQ: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    static CURRENT: SharedState = {
+        let state = Arc::new(Mutex::new(ThreadState {
+            id: thread::current().id(),
+            held: Vec::new(),
+            waiting: None,
+        }));
+        let mut threads = lock(&THREADS);
+        threads.retain(|t| t.strong_count() > 0);
+        threads.push(Arc::downgrade(&state));
+        state
+    };
+}
+
+fn lock<T>(m: &Mutex<T>) -> MutexGuard<'_, T> {
+    m.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// The state of the current thread, or None if it is being destroyed.
+fn current() -> Option<SharedState> {
+    CURRENT.try_with(Arc::clone).ok()
+}
+
+/// Records that the thread that created it holds the lock `lock` until it is dropped.
+pub(crate) struct Held {
+    lock: usize,
+    owner: Option<SharedState>,
+}
+
+impl Held {
+    pub(crate) fn new(lock: usize) -> Self {
+        let owner = current();
+        if let Some(owner) = &owner {
+            self::lock(owner).held.push(lock);
+        }
+        Self { lock, owner }
+    }
+}
+
+impl Clone for Held {
+    fn clone(&self) -> Self {
+        Self::new(self.lock)
+    }
+}
+
+impl Drop for Held {
+    fn drop(&mut self) {
+        if let Some(owner) = &self.owner {
+            let mut owner = lock(owner);
+            if let Some(pos) = owner.held.iter().rposition(|l| *l == self.lock) {
+                owner.held.swap_remove(pos);
+            }
+        }
+    }
+}
+
+/// Records that the current thread is waiting on the lock `lock` until it is dropped.
+pub(crate) struct Waiting(Option<SharedState>);
+
+impl Waiting {
+    /// `upgrading` is true if the thread holds an upgradable lock on `lock` and waits
+    /// for the other readers to release it.
+    pub(crate) fn enter(lock: usize, upgrading: bool) -> Self {
+        let state = current();
+        if let Some(state) = &state {
+            self::lock(state).waiting = Some(Wait {
+                lock,
+                upgrading,
+                info: None,
+                seq: WAIT_SEQ.fetch_add(1, Ordering::Relaxed),
+            });
+        }
+        Self(state)
+    }
+}
+
+impl Drop for Waiting {
+    fn drop(&mut self) {
+        if let Some(state) = &self.0 {
+            lock(state).waiting = None;
+        }
+    }
+}
+
+/// Attach the informations of the lazy to the lock the current thread is waiting on.
+pub(crate) fn describe_wait(info: Option<&StaticInfo>) {
+    if let Some(state) = current() {
+        if let Some(wait) = &mut lock(&state).waiting {
+            wait.info = info.copied();
+        }
+    }
+}
+
+/// A thread of a dead lock, waiting on a lock held by the next thread of the cycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeadlockedThread {
+    thread_id: ThreadId,
+    waiting_on: Option<StaticInfo>,
+}
+
+impl DeadlockedThread {
+    /// The id of the thread.
+    pub fn thread_id(&self) -> ThreadId {
+        self.thread_id
+    }
+    /// The lazy whose lock the thread is waiting on.
+    ///
+    /// `None` if the lazy was not declared with the [dynamic](macro@crate::dynamic) attribute.
+    pub fn waiting_on(&self) -> Option<&StaticInfo> {
+        self.waiting_on.as_ref()
+    }
+}
+
+/// A cycle of threads each waiting on a lock held by the next one, see [check_deadlocks].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deadlock {
+    threads: Vec<DeadlockedThread>,
+}
+
+impl Deadlock {
+    /// The threads of the cycle. The lock the last thread is waiting on is held by
+    /// the first one.
+    pub fn threads(&self) -> &[DeadlockedThread] {
+        &self.threads
+    }
+}
+
+impl Display for Deadlock {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("Dead lock:")?;
+        for (i, t) in self.threads.iter().enumerate() {
+            let next = &self.threads[(i + 1) % self.threads.len()];
+            write!(f, " {:?} waits for ", t.thread_id)?;
+            match &t.waiting_on {
+                Some(info) => write!(
+                    f,
+                    "{} ({}:{})",
+                    info.variable_name, info.file_name, info.line
+                )?,
+                None => f.write_str("<lazy>")?,
+            }
+            write!(f, " held by {:?};", next.thread_id)?;
+        }
+        Ok(())
+    }
+}
+
+struct Snapshot {
+    id: ThreadId,
+    held: Vec<usize>,
+    wait: Option<(usize, bool, Option<StaticInfo>, u64)>,
+}
+
+fn snapshot(threads: &[SharedState]) -> Vec<Snapshot> {
+    threads
+        .iter()
+        .map(|t| {
+            let t = lock(t);
+            Snapshot {
+                id: t.id,
+                held: t.held.clone(),
+                wait: t
+                    .waiting
+                    .as_ref()
+                    .map(|w| (w.lock, w.upgrading, w.info, w.seq)),
+            }
+        })
+        .collect()
+}
+
+/// Threads holding the lock thread `i` is waiting on.
+fn successors(snap: &[Snapshot], i: usize) -> Vec<usize> {
+    let (lock, upgrading) = match snap[i].wait {
+        Some((lock, upgrading, ..)) => (lock, upgrading),
+        None => return Vec::new(),
+    };
+    (0..snap.len())
+        .filter(|&j| {
+            let count = snap[j].held.iter().filter(|l| **l == lock).count();
+            // an upgrading thread only waits for the other holds
+            count > usize::from(j == i && upgrading)
+        })
+        .collect()
+}
+
+fn find_cycles(snap: &[Snapshot]) -> Vec<Vec<usize>> {
+    let succs = (0..snap.len())
+        .map(|i| successors(snap, i))
+        .collect::<Vec<_>>();
+    let mut explored = vec![false; snap.len()];
+    let mut cycles = Vec::new();
+    for start in 0..snap.len() {
+        if explored[start] {
+            continue;
+        }
+        let mut path = vec![start];
+        let mut next = vec![0];
+        while let Some(&i) = path.last() {
+            let k = next.last_mut().unwrap();
+            match succs[i].get(*k) {
+                Some(&j) => {
+                    *k += 1;
+                    if let Some(pos) = path.iter().position(|&p| p == j) {
+                        cycles.push(path[pos..].to_vec());
+                    } else if !explored[j] {
+                        path.push(j);
+                        next.push(0);
+                    }
+                }
+                None => {
+                    explored[i] = true;
+                    path.pop();
+                    next.pop();
+                }
+            }
+        }
+    }
+    cycles
+}
+
+/// Look for dead locks between threads holding and waiting on the locks of lazies.
+///
+/// Each returned [Deadlock] is a cycle of threads each waiting on a lock held by the
+/// next one. A cycle is only reported if all its threads are found blocked in the same
+/// lock attempt by two successive inspections, so that it can not be the result of
+/// locks being acquired and released during the inspection.
+///
+/// Lock attempts with a deadline may leave the cycle when the deadline is reached.
+pub fn check_deadlocks() -> Vec<Deadlock> {
+    let threads = lock(&THREADS)
+        .iter()
+        .filter_map(Weak::upgrade)
+        .collect::<Vec<_>>();
+    let first = snapshot(&threads);
+    let cycles = find_cycles(&first);
+    if cycles.is_empty() {
+        return Vec::new();
+    }
+    let second = snapshot(&threads);
+    cycles
+        .into_iter()
+        .filter(|c| {
+            c.iter().all(
+                |&i| matches!((first[i].wait, second[i].wait), (Some(a), Some(b)) if a.3 == b.3),
+            )
+        })
+        .map(|c| Deadlock {
+            threads: c
+                .into_iter()
+                .map(|i| DeadlockedThread {
+                    thread_id: second[i].id,
+                    waiting_on: second[i].wait.and_then(|w| w.2),
+                })
+                .collect(),
+        })
+        .collect()
+}
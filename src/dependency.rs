@@ -0,0 +1,160 @@
+use crate::StaticInfo;
+#[cfg(support_priority)]
+use core::fmt;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, AtomicU8, Ordering};
+
+static FIRST: AtomicPtr<Node> = AtomicPtr::new(ptr::null_mut());
+
+const UNVISITED: u8 = 0;
+const VISITING: u8 = 1;
+const VISITED: u8 = 2;
+
+#[doc(hidden)]
+/// How to initialize a lazy static named in `#[dynamic(after(..))]`, generated by the
+/// [dynamic](macro@crate::dynamic) attribute as the `DEPENDENCY` of its generator.
+#[derive(Clone, Copy)]
+pub struct Dependency {
+    init: fn(),
+    node: Option<fn() -> &'static Node>,
+}
+
+impl Dependency {
+    #[inline]
+    /// `init` shall initialize the static and `node` returns its dependency node if it is
+    /// itself declared to be initialized after other statics.
+    pub const fn new(init: fn(), node: Option<fn() -> &'static Node>) -> Self {
+        Self { init, node }
+    }
+    #[inline]
+    /// Unwrap the `DEPENDENCY` of the generator of a static named in `after(..)`.
+    ///
+    /// Evaluated in a const context, this fails to compile if the named static is not a
+    /// lazy static declared with the [dynamic](macro@crate::dynamic) attribute.
+    pub const fn of(dep: Option<Self>) -> Self {
+        match dep {
+            Some(dep) => dep,
+            None => panic!("Only lazy statics can be named in `after`"),
+        }
+    }
+}
+
+#[doc(hidden)]
+/// Dependency metadata of a lazy static declared to be initialized after other statics,
+/// generated by the [dynamic](macro@crate::dynamic) attribute.
+pub struct Node {
+    info: StaticInfo,
+    after: &'static [Dependency],
+    state: AtomicU8,
+    /// the dependency being visited when the node state is VISITING
+    visiting: AtomicPtr<Node>,
+    next: AtomicPtr<Node>,
+}
+
+impl Node {
+    #[inline]
+    /// `after` are the statics that must be initialized before it.
+    pub const fn new(info: StaticInfo, after: &'static [Dependency]) -> Self {
+        Self {
+            info,
+            after,
+            state: AtomicU8::new(UNVISITED),
+            visiting: AtomicPtr::new(ptr::null_mut()),
+            next: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+    #[inline]
+    /// Initialize the dependencies of the static.
+    ///
+    /// This is called by the generator of the static before it evaluates the
+    /// initialization expression.
+    pub fn init_dependencies(this: &Self) {
+        for dep in this.after {
+            (dep.init)()
+        }
+    }
+    /// Add the node to the list of nodes checked for cyclic dependencies at program
+    /// startup.
+    ///
+    /// This function is called once by a program constructor generated by
+    /// the [dynamic](macro@crate::dynamic) attribute.
+    pub fn register(this: &'static Self) {
+        let this_ptr = this as *const Node as *mut Node;
+        let mut cur = FIRST.load(Ordering::Relaxed);
+        loop {
+            this.next.store(cur, Ordering::Relaxed);
+            match FIRST.compare_exchange_weak(cur, this_ptr, Ordering::Release, Ordering::Relaxed) {
+                Ok(_) => return,
+                Err(x) => cur = x,
+            }
+        }
+    }
+}
+
+/// A cycle in the dependencies declared with `#[dynamic(after(..))]`, each static
+/// being declared to be initialized after the next one.
+#[cfg(support_priority)]
+struct Cycle {
+    start: &'static Node,
+}
+
+#[cfg(support_priority)]
+impl fmt::Display for Cycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut node = self.start;
+        loop {
+            let info = &node.info;
+            write!(
+                f,
+                "{} ({}:{}:{}) -> ",
+                info.variable_name, info.file_name, info.line, info.column
+            )?;
+            // SAFETY: nodes are statics
+            node = unsafe { &*node.visiting.load(Ordering::Relaxed) };
+            if ptr::eq(node, self.start) {
+                return write!(f, "{}", node.info.variable_name);
+            }
+        }
+    }
+}
+
+#[cfg(support_priority)]
+fn visit(node: &'static Node) -> Result<(), Cycle> {
+    match node.state.load(Ordering::Relaxed) {
+        VISITED => return Ok(()),
+        VISITING => return Err(Cycle { start: node }),
+        _ => (),
+    }
+    node.state.store(VISITING, Ordering::Relaxed);
+    for dep in node.after.iter().filter_map(|dep| dep.node) {
+        let dep = dep();
+        node.visiting
+            .store(dep as *const Node as *mut Node, Ordering::Relaxed);
+        visit(dep)?;
+    }
+    node.state.store(VISITED, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Look for a cycle in the dependencies of the registered nodes.
+///
+/// Must be called only once, while the program is still single threaded.
+#[cfg(support_priority)]
+fn find_cycle() -> Result<(), Cycle> {
+    let mut cur = FIRST.load(Ordering::Acquire);
+    while !cur.is_null() {
+        // SAFETY: nodes are statics that are never unlinked
+        let node: &'static Node = unsafe { &*cur };
+        visit(node)?;
+        cur = node.next.load(Ordering::Relaxed);
+    }
+    Ok(())
+}
+
+#[cfg(support_priority)]
+#[static_init_macro::constructor(__dependency_check)]
+extern "C" fn check_dependencies() {
+    if let Err(cycle) = find_cycle() {
+        panic!("Cyclic dependency between statics: {}", cycle)
+    }
+}
@@ -3,26 +3,38 @@
 mod exit_manager {
     use crate::lazy_sequentializer::SyncSequentializer as SubSequentializer;
     use crate::phase_locker::{LockNature, LockResult, SyncPhaseGuard, SyncReadPhaseGuard};
-    use crate::phase_locker::{Mutex, SyncPhaseLocker};
+    use crate::phase_locker::{Mutex, SyncPhaseLocker, SyncUpgradableReadPhaseGuard};
     use crate::Finaly;
     use crate::{
         FinalizableLazySequentializer, GeneratorTolerance, LazySequentializer, Phase, Phased,
-        Sequential, Sequentializer,
+        Sequential, Sequentializer, StaticInfo, UpgradableSequentializer,
     };
 
+    #[cfg(feature = "timeout")]
+    use crate::TimedLazySequentializer;
+
     #[cfg(any(feature = "parking_lot_core", debug_mode))]
     use std::panic::{RefUnwindSafe, UnwindSafe};
 
     trait OnExit {
+        fn next(&self) -> &Mutex<Option<&'static Node>>;
         fn take_next(&self) -> Option<&'static Node>;
-        fn execute(&self);
+        fn priority(&self) -> u16;
+        fn info(&self) -> Option<StaticInfo>;
+        fn execute(&self) -> Phase;
     }
 
     type Node = dyn 'static + OnExit + Sync;
 
+    #[inline(always)]
+    fn same_node(a: &Node, b: &Node) -> bool {
+        core::ptr::eq(a as *const Node as *const u8, b as *const Node as *const u8)
+    }
+
     struct ExitSequentializerBase<G> {
         sub: SubSequentializer<G>,
         next: Mutex<Option<&'static Node>>,
+        info: Option<StaticInfo>,
     }
 
     // if a panic is launched during a finalization
@@ -39,10 +51,14 @@ mod exit_manager {
 
     mod reg {
 
-        use super::{ExitSequentializer, Node};
-        use crate::{destructor, Finaly, GeneratorTolerance, Sequential};
+        use super::{same_node, ExitSequentializer, Node};
+        use crate::{destructor, Finaly, GeneratorTolerance, Phase, Sequential};
 
         use crate::phase_locker::Mutex;
+        use crate::StaticInfo;
+
+        #[cfg(feature = "alloc")]
+        use alloc::vec::Vec;
 
         struct Register {
             first: Option<&'static Node>,
@@ -53,11 +69,19 @@ mod exit_manager {
             registration_opened: true,
         });
 
-        #[destructor(0)]
-        extern "C" fn execute_at_exit() {
-            let mut l = REGISTER.lock();
-            let mut list: Option<&'static Node> = l.first.take();
-            drop(l);
+        /// Take the registered statics, or close the registration
+        /// if there are none
+        fn take_or_close() -> Option<&'static Node> {
+            let mut reg = REGISTER.lock();
+            if reg.first.is_none() {
+                reg.registration_opened = false;
+            }
+            reg.first.take()
+        }
+
+        /// Finalize the statics of `list` then all statics registered in the mean time
+        /// and close the registration
+        fn drain(mut list: Option<&'static Node>, mut on_finalized: impl FnMut(&Node, Phase)) {
             while let Some(on_exit) = list {
                 // SAFETY:
                 // the reference created mut point to an object:
@@ -65,17 +89,87 @@ mod exit_manager {
                 //     must be static.
                 //   - there should not have any mutable reference to the object: this is
                 //   a requirement of the ExitSequentializer object new method
-                on_exit.execute();
-                list = on_exit.take_next().or_else(|| {
-                    let mut reg = REGISTER.lock();
-                    if reg.first.is_none() {
-                        reg.registration_opened = false;
-                    }
-                    reg.first.take()
-                });
+                on_finalized(on_exit, on_exit.execute());
+                list = on_exit.take_next().or_else(take_or_close);
             }
         }
 
+        #[destructor(0)]
+        extern "C" fn execute_at_exit() {
+            let list = REGISTER.lock().first.take();
+            drain(list, |_, _| ());
+        }
+
+        /// A static finalized by [shutdown](crate::shutdown)
+        #[cfg(feature = "alloc")]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct FinalizedStatic {
+            /// Informations about the static, only available if it has been declared
+            /// with the [dynamic](macro@crate::dynamic) attribute
+            pub info: Option<StaticInfo>,
+            /// The phase of the static after its finalization
+            pub phase: Phase,
+        }
+
+        /// The report returned by [shutdown](crate::shutdown)
+        #[cfg(feature = "alloc")]
+        #[derive(Debug, Clone, Default, PartialEq, Eq)]
+        pub struct ShutdownReport {
+            /// The statics in the order of their finalization
+            pub statics: Vec<FinalizedStatic>,
+        }
+
+        #[cfg(feature = "alloc")]
+        impl ShutdownReport {
+            /// Returns true if no finalization panicked
+            pub fn succeeded(&self) -> bool {
+                !self
+                    .statics
+                    .iter()
+                    .any(|s| s.phase.intersects(Phase::FINALIZATION_PANICKED))
+            }
+        }
+
+        /// Finalize now all statics that would be finalized at program exit
+        ///
+        /// Statics are finalized in the reverse order of their registration, as
+        /// they would be at program exit. After this call, registration for finalization
+        /// is closed: a static that is accessed for the first time later fails to
+        /// initialize, unless its generator tolerates registration failures.
+        ///
+        /// `on_finalized` is called after the finalization of each static, with the
+        /// informations about this static, if any, and its phase after finalization.
+        /// This function does not allocate, see [shutdown](crate::shutdown) for
+        /// a version that returns a report.
+        ///
+        /// This is usefull when the program exit without running destructors, as
+        /// when `std::process::exit` is called. This function can be called many
+        /// times and at program exit statics will not be finalized a second time.
+        ///
+        /// # Panics
+        ///
+        /// If a finalization panics, the panic is propagated and statics that have
+        /// not yet been finalized will not be finalized.
+        pub fn shutdown_with(mut on_finalized: impl FnMut(Option<StaticInfo>, Phase)) {
+            drain(take_or_close(), |n, phase| on_finalized(n.info(), phase));
+        }
+
+        /// Finalize now all statics that would be finalized at program exit and
+        /// report them
+        ///
+        /// See [shutdown_with](crate::shutdown_with).
+        ///
+        /// # Panics
+        ///
+        /// If a finalization panics, the panic is propagated and statics that have
+        /// not yet been finalized will not be finalized.
+        #[cfg(feature = "alloc")]
+        pub fn shutdown() -> ShutdownReport {
+            let mut report = ShutdownReport::default();
+            shutdown_with(|info, phase| report.statics.push(FinalizedStatic { info, phase }));
+            report
+        }
+
         /// Store a reference of the static for execution of the
         /// finalize call back at program exit
         pub(crate) fn finalize_at_exit<
@@ -89,19 +183,87 @@ mod exit_manager {
         {
             let mut l = REGISTER.lock();
             if l.registration_opened {
-                let mut next = Sequential::sequentializer(st).0.next.lock();
+                let next = &Sequential::sequentializer(st).0.next;
                 assert!(
-                    next.is_none(),
+                    next.lock().is_none(),
                     "Double registration of an ExitSequentializer for finalization at program exit"
                 );
-                *next = l.first.replace(st as &Node);
+                // The list is sorted by priority, statics with lower priority are finalized
+                // first and statics with the same priority in the reverse order of their
+                // registration.
+                let priority = Tol::FINALIZATION_PRIORITY;
+                let mut prev: Option<&'static Node> = None;
+                let mut cur = l.first;
+                while let Some(n) = cur {
+                    if n.priority() >= priority {
+                        break;
+                    }
+                    prev = cur;
+                    cur = *n.next().lock();
+                }
+                *next.lock() = cur;
+                match prev {
+                    Some(prev) => *prev.next().lock() = Some(st as &Node),
+                    None => l.first = Some(st as &Node),
+                }
                 true
             } else {
                 false
             }
         }
+
+        /// Remove a static from the list of statics to be finalized
+        /// at program exit.
+        ///
+        /// Returns false if the static was not registered.
+        pub(super) fn unregister(st: &'static Node) -> bool {
+            let mut l = REGISTER.lock();
+            let mut cur = match l.first {
+                Some(first) if same_node(first, st) => {
+                    l.first = st.take_next();
+                    return true;
+                }
+                Some(first) => first,
+                None => return false,
+            };
+            loop {
+                let next = *cur.next().lock();
+                match next {
+                    Some(n) if same_node(n, st) => {
+                        *cur.next().lock() = st.take_next();
+                        return true;
+                    }
+                    Some(n) => cur = n,
+                    None => return false,
+                }
+            }
+        }
     }
     pub(crate) use reg::finalize_at_exit;
+    pub use reg::shutdown_with;
+    #[cfg(feature = "alloc")]
+    pub use reg::{shutdown, FinalizedStatic, ShutdownReport};
+
+    /// Finalize the static now, if it is initialized and not yet finalized, and
+    /// remove it from the list of statics to be finalized at program exit.
+    ///
+    /// Returns the phase of the static after the finalization.
+    pub(crate) fn finalize_now<
+        T: 'static + Sequential<Sequentializer = ExitSequentializer<Tol>> + Sync,
+        Tol: 'static + GeneratorTolerance,
+    >(
+        st: &'static T,
+    ) -> Phase
+    where
+        T::Data: 'static + Finaly,
+    {
+        reg::unregister(st);
+        <SubSequentializer<Tol> as FinalizableLazySequentializer<T>>::finalize_callback(
+            st,
+            Finaly::finaly,
+        );
+        Phased::phase(Sequential::sequentializer(st))
+    }
 
     #[allow(clippy::declare_interior_mutable_const)]
     /// This object is only used to for const initialization
@@ -117,6 +279,19 @@ mod exit_manager {
             Self(ExitSequentializerBase {
                 sub: SubSequentializer::new(l),
                 next: MUTEX_INIT,
+                info: None,
+            })
+        }
+        #[inline(always)]
+        /// Create a new ExitSequentializer that keeps the informations
+        /// about the static, as reported by [shutdown_with](crate::shutdown_with)
+        ///
+        /// Useless if the target object is not 'static
+        pub const fn new_with_info(l: SyncPhaseLocker, info: StaticInfo) -> Self {
+            Self(ExitSequentializerBase {
+                sub: SubSequentializer::new(l),
+                next: MUTEX_INIT,
+                info: Some(info),
             })
         }
     }
@@ -171,6 +346,20 @@ mod exit_manager {
         }
     }
 
+    // SAFETY: it is safe because it does implement synchronized locks
+    unsafe impl<'a, T: 'a + Sequential<Sequentializer = Self>, Tol: GeneratorTolerance + 'static>
+        UpgradableSequentializer<'a, T> for ExitSequentializer<Tol>
+    where
+        T: 'static + Sync,
+        T::Data: 'static + Finaly,
+    {
+        type UpgradableReadGuard = SyncUpgradableReadPhaseGuard<'a, T::Data>;
+        #[inline(always)]
+        fn upgradable_lock(st: &'a T) -> SyncUpgradableReadPhaseGuard<'a, T::Data> {
+            <SubSequentializer<Tol> as UpgradableSequentializer<T>>::upgradable_lock(st)
+        }
+    }
+
     // SAFETY: it is safe because it does implement synchronized locks
     unsafe impl<T: 'static + Sequential<Sequentializer = Self>, Tol: GeneratorTolerance + 'static>
         LazySequentializer<'static, T> for ExitSequentializer<Tol>
@@ -247,6 +436,46 @@ mod exit_manager {
         }
     }
 
+    // SAFETY: it is safe because it does implement synchronized locks
+    #[cfg(feature = "timeout")]
+    unsafe impl<T: 'static + Sequential<Sequentializer = Self>, Tol: GeneratorTolerance + 'static>
+        TimedLazySequentializer<'static, T> for ExitSequentializer<Tol>
+    where
+        T: 'static + Sync,
+        T::Data: 'static + Finaly,
+    {
+        #[inline(always)]
+        fn init_then_read_guard_until(
+            st: &'static T,
+            shall_init: impl Fn(Phase) -> bool,
+            init: impl FnOnce(&'static <T as Sequential>::Data),
+            deadline: std::time::Instant,
+        ) -> Result<Self::ReadGuard, Phase> {
+            SubSequentializer::<Tol>::finalizable_init_then_read_guard_until(
+                st,
+                shall_init,
+                init,
+                finalize_at_exit,
+                deadline,
+            )
+        }
+        #[inline(always)]
+        fn init_then_write_guard_until(
+            st: &'static T,
+            shall_init: impl Fn(Phase) -> bool,
+            init: impl FnOnce(&'static <T as Sequential>::Data),
+            deadline: std::time::Instant,
+        ) -> Result<Self::WriteGuard, Phase> {
+            SubSequentializer::<Tol>::finalizable_init_then_write_guard_until(
+                st,
+                shall_init,
+                init,
+                finalize_at_exit,
+                deadline,
+            )
+        }
+    }
+
     impl<
             T: Sequential<Sequentializer = ExitSequentializer<Tol>>,
             Tol: 'static + GeneratorTolerance,
@@ -254,18 +483,31 @@ mod exit_manager {
     where
         T::Data: 'static + Finaly,
     {
+        fn next(&self) -> &Mutex<Option<&'static Node>> {
+            &Sequential::sequentializer(self).0.next
+        }
         fn take_next(&self) -> Option<&'static Node> {
-            Sequential::sequentializer(self).0.next.lock().take()
+            self.next().lock().take()
         }
-        fn execute(&self) {
+        fn priority(&self) -> u16 {
+            Tol::FINALIZATION_PRIORITY
+        }
+        fn info(&self) -> Option<StaticInfo> {
+            Sequential::sequentializer(self).0.info
+        }
+        fn execute(&self) -> Phase {
             <SubSequentializer<Tol> as FinalizableLazySequentializer<T>>::finalize_callback(
                 self,
                 Finaly::finaly,
             );
+            Phased::phase(Sequential::sequentializer(self))
         }
     }
 }
-pub(crate) use exit_manager::ExitSequentializer;
+pub use exit_manager::shutdown_with;
+pub(crate) use exit_manager::{finalize_now, ExitSequentializer};
+#[cfg(feature = "alloc")]
+pub use exit_manager::{shutdown, FinalizedStatic, ShutdownReport};
 
 #[cfg(feature = "thread_local")]
 pub(crate) use local_manager::ThreadExitSequentializer;
@@ -276,7 +518,7 @@ mod local_manager {
     use crate::lazy_sequentializer::UnSyncSequentializer as SubSequentializer;
     use crate::{
         FinalizableLazySequentializer, Finaly, GeneratorTolerance, LazySequentializer, Phase,
-        Phased, Sequential, Sequentializer,
+        Phased, Sequential, Sequentializer, StaticInfo,
     };
 
     use core::cell::Cell;
@@ -329,6 +571,12 @@ mod local_manager {
                 next: CELL_INIT,
             })
         }
+        #[inline(always)]
+        /// Same as [new](Self::new): statics finalized at thread exit
+        /// are not reported so the informations are not kept
+        pub const fn new_with_info(l: UnSyncPhaseLocker, _info: StaticInfo) -> Self {
+            Self::new(l)
+        }
     }
 
     impl<Tol> AsRef<SubSequentializer<Tol>> for ThreadExitSequentializer<Tol> {
@@ -1,8 +1,11 @@
 #![allow(unused)] //functions that are usefull for extension
 
+use crate::phase_locker::PhaseGuard;
+use crate::try_lazy::InitError;
 use crate::{
-    Finaly, Generator, LazySequentializer, LockNature, LockResult, Phase, Phased, Sequential,
-    Sequentializer, StaticInfo, Uninit, UniqueLazySequentializer,
+    FallibleLazySequentializer, Finaly, Generator, LazySequentializer, LockNature, LockResult,
+    Phase, Phased, Sequential, Sequentializer, StaticInfo, TryGenerator, Uninit,
+    UniqueLazySequentializer, UpgradableSequentializer,
 };
 use core::cell::UnsafeCell;
 use core::fmt::{self, Debug, Display, Formatter};
@@ -11,8 +14,10 @@ use core::marker::PhantomData;
 use core::mem::MaybeUninit;
 use core::ops::{Deref, DerefMut};
 
-#[cfg(debug_mode)]
-use crate::CyclicPanic;
+#[cfg(feature = "timeout")]
+use crate::TimedLazySequentializer;
+#[cfg(feature = "timeout")]
+use std::time::Instant;
 
 #[cfg(any(feature = "parking_lot_core", debug_mode))]
 use std::panic::RefUnwindSafe;
@@ -168,14 +173,67 @@ impl Display for AccessError {
 #[cfg(feature = "parking_lot_core")]
 impl std::error::Error for AccessError {}
 
+/// Error returned when a lock on a locked lazy could not be obtained before a deadline
+#[cfg(feature = "timeout")]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum TimeoutError {
+    /// The lazy was still being initialized by an other thread
+    Initialization,
+    /// The lazy was initialized but locked by other threads
+    Contention,
+}
+
+#[cfg(feature = "timeout")]
+impl TimeoutError {
+    fn new<S: LazyPolicy>(phase: Phase) -> Self {
+        if S::shall_init(phase) {
+            TimeoutError::Initialization
+        } else {
+            TimeoutError::Contention
+        }
+    }
+}
+
+#[cfg(feature = "timeout")]
+impl Display for TimeoutError {
+    fn fmt(&self, ft: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeoutError::Initialization => {
+                write!(
+                    ft,
+                    "Error: lock timed out while the lazy was being initialized"
+                )
+            }
+            TimeoutError::Contention => write!(ft, "Error: lock timed out because of contention"),
+        }
+    }
+}
+
+#[cfg(feature = "timeout")]
+impl std::error::Error for TimeoutError {}
+
 pub(crate) struct GenericLazySeq<T, M> {
     value: T,
     sequentializer: M,
+    #[cfg(any(
+        debug_mode,
+        feature = "tracing",
+        feature = "cycle_detect",
+        feature = "deadlock_detection"
+    ))]
+    info: Option<StaticInfo>,
 }
 
 pub(crate) struct GenericLockedLazySeq<T, M> {
     value: T,
     sequentializer: M,
+    #[cfg(any(
+        debug_mode,
+        feature = "tracing",
+        feature = "cycle_detect",
+        feature = "deadlock_detection"
+    ))]
+    info: Option<StaticInfo>,
 }
 
 /// A type that wrap a Sequentializer and a raw data, and that may
@@ -185,8 +243,6 @@ pub(crate) struct GenericLazy<T, F, M, S> {
     seq: GenericLazySeq<T, M>,
     generator: F,
     phantom: PhantomData<S>,
-    #[cfg(debug_mode)]
-    _info: Option<StaticInfo>,
 }
 
 // SAFETY: The synchronization is ensured by the Sequentializer
@@ -252,11 +308,16 @@ impl<T, F, M, S> GenericLazy<T, F, M, S> {
             seq: GenericLazySeq {
                 value,
                 sequentializer,
+                #[cfg(any(
+                    debug_mode,
+                    feature = "tracing",
+                    feature = "cycle_detect",
+                    feature = "deadlock_detection"
+                ))]
+                info: None,
             },
             generator,
             phantom: PhantomData,
-            #[cfg(debug_mode)]
-            _info: None,
         }
     }
     #[inline(always)]
@@ -294,11 +355,16 @@ impl<T, F, M, S> GenericLazy<T, F, M, S> {
             seq: GenericLazySeq {
                 value,
                 sequentializer,
+                #[cfg(any(
+                    debug_mode,
+                    feature = "tracing",
+                    feature = "cycle_detect",
+                    feature = "deadlock_detection"
+                ))]
+                info: Some(_info),
             },
             generator,
             phantom: PhantomData,
-            #[cfg(debug_mode)]
-            _info: Some(_info),
         }
     }
     #[inline(always)]
@@ -307,6 +373,11 @@ impl<T, F, M, S> GenericLazy<T, F, M, S> {
         &this.seq.sequentializer
     }
     #[inline(always)]
+    ///get access to the sequential object that is registered for finalization
+    pub fn sequential(this: &Self) -> &GenericLazySeq<T, M> {
+        &this.seq
+    }
+    #[inline(always)]
     ///get a pointer to the raw data
     pub fn get_raw_data(this: &Self) -> &T {
         &this.seq.value
@@ -330,17 +401,6 @@ where
         &*self.seq.value.get()
     }
 
-    /// Get a reference to the target, returning an error if the
-    /// target is not in the correct phase.
-    #[inline(always)]
-    pub fn try_get(&'a self) -> Result<&'a T::Target, AccessError> {
-        check_access::<*mut T::Target, S>(
-            self.seq.value.get(),
-            Phased::phase(&self.seq.sequentializer),
-        )
-        .map(|ptr| unsafe { &*ptr })
-    }
-
     /// Get a reference to the target
     ///
     /// # Panics
@@ -410,24 +470,111 @@ where
     /// Potentialy initialize the inner data, returning the
     /// phase reached at the end of the initialization attempt
     pub fn init(&'a self) -> Phase {
-        may_debug(
-            || {
-                <M as LazySequentializer<'a, GenericLazySeq<T, M>>>::init(
-                    &self.seq,
-                    S::shall_init,
-                    |data: &T| {
-                        // SAFETY
-                        // This function is called only once within the init function
-                        // Only one thread can ever get this mutable access
-                        let d = Generator::generate(&self.generator);
-                        unsafe { data.init(d) };
-                    },
-                )
+        self.init_with(|| Generator::generate(&self.generator))
+    }
+}
+
+impl<'a, T, F, M, S> GenericLazy<T, F, M, S>
+where
+    T: 'a + LazyData,
+    M: 'a,
+    M: LazySequentializer<'a, GenericLazySeq<T, M>>,
+    S: 'a + LazyPolicy,
+{
+    #[inline(always)]
+    /// Potentialy initialize the inner data with the value returned by `generator`
+    /// instead of the one of the lazy, returning the phase reached at the end of the
+    /// initialization attempt
+    pub fn init_with(&'a self, generator: impl FnOnce() -> T::Target) -> Phase {
+        <M as LazySequentializer<'a, GenericLazySeq<T, M>>>::init(
+            &self.seq,
+            S::shall_init,
+            |data: &T| {
+                // SAFETY
+                // This function is called only once within the init function
+                // Only one thread can ever get this mutable access
+                let d = generator();
+                unsafe { data.init(d) };
             },
-            #[cfg(debug_mode)]
-            &self._info,
         )
     }
+    /// Attempt initialization with `generator` then get a reference to the target,
+    /// returning an error if the target is not in the correct phase.
+    #[inline(always)]
+    pub fn init_with_then_try_get(
+        &'a self,
+        generator: impl FnOnce() -> T::Target,
+    ) -> Result<&'a T::Target, AccessError> {
+        let phase = self.init_with(generator);
+        post_init_check_access::<*mut T::Target, S>(self.seq.value.get(), phase)
+            .map(|ptr| unsafe { &*ptr })
+    }
+    /// Get a reference to the target, returning an error if the
+    /// target is not in the correct phase.
+    #[inline(always)]
+    pub fn try_get(&'a self) -> Result<&'a T::Target, AccessError> {
+        check_access::<*mut T::Target, S>(
+            self.seq.value.get(),
+            Phased::phase(&self.seq.sequentializer),
+        )
+        .map(|ptr| unsafe { &*ptr })
+    }
+}
+
+impl<'a, T, F, M, S> GenericLazy<T, F, M, S>
+where
+    T: 'a + LazyData,
+    M: 'a,
+    M: FallibleLazySequentializer<'a, GenericLazySeq<T, M>>,
+    S: 'a + LazyPolicy,
+{
+    /// Attempt initialization with the fallible `generator` instead of the one of the
+    /// lazy, then get a reference to the target.
+    ///
+    /// If `generator` fails, the target stays uninitialized, the phase is set to
+    /// INITIALIZATION_SKIPED and the error of the generator is returned.
+    #[inline(always)]
+    pub fn try_init_with_then_try_get<E>(
+        &'a self,
+        generator: impl FnOnce() -> Result<T::Target, E>,
+    ) -> Result<&'a T::Target, InitError<E>> {
+        let mut error = None;
+        let phase = <M as FallibleLazySequentializer<'a, GenericLazySeq<T, M>>>::fallible_init(
+            &self.seq,
+            S::shall_init,
+            |data: &T| match generator() {
+                // SAFETY
+                // This function is called only once within the init function
+                // Only one thread can ever get this mutable access
+                Ok(d) => {
+                    unsafe { data.init(d) };
+                    true
+                }
+                Err(e) => {
+                    error = Some(e);
+                    false
+                }
+            },
+        );
+        if let Some(e) = error {
+            return Err(InitError::Generator(e));
+        }
+        post_init_check_access::<*mut T::Target, S>(self.seq.value.get(), phase)
+            .map(|ptr| unsafe { &*ptr })
+            .map_err(InitError::Access)
+    }
+    /// Attempt initialization with the fallible generator of the lazy then get a
+    /// reference to the target.
+    ///
+    /// If the generator fails, the target stays uninitialized, the phase is set to
+    /// INITIALIZATION_SKIPED and the error of the generator is returned.
+    #[inline(always)]
+    pub fn try_init_then_try_get<E>(&'a self) -> Result<&'a T::Target, InitError<E>>
+    where
+        F: TryGenerator<T::Target, E>,
+    {
+        self.try_init_with_then_try_get(|| self.generator.try_generate())
+    }
 }
 
 impl<T, F, M, S> GenericLazy<T, F, M, S>
@@ -500,10 +647,20 @@ unsafe impl<T: LazyData, M> Sequential for GenericLazySeq<T, M> {
     fn data(this: &Self) -> &Self::Data {
         &this.value
     }
+    #[cfg(any(
+        debug_mode,
+        feature = "tracing",
+        feature = "cycle_detect",
+        feature = "deadlock_detection"
+    ))]
+    #[inline(always)]
+    fn info(this: &Self) -> Option<&StaticInfo> {
+        this.info.as_ref()
+    }
 }
 
 #[must_use = "If unused the write lock is immediatly released"]
-pub(crate) struct WriteGuard<T>(T);
+pub(crate) struct WriteGuard<T>(pub(crate) T);
 
 impl<T> Deref for WriteGuard<T>
 where
@@ -550,7 +707,7 @@ where
 
 #[must_use = "If unused the read lock is immediatly released"]
 #[derive(Clone)]
-pub(crate) struct ReadGuard<T>(T);
+pub(crate) struct ReadGuard<T>(pub(crate) T);
 
 impl<T> Deref for ReadGuard<T>
 where
@@ -595,6 +752,62 @@ where
     }
 }
 
+#[must_use = "If unused the upgradable read lock is immediatly released"]
+pub(crate) struct UpgradableReadGuard<T>(pub(crate) T);
+
+impl<T> Deref for UpgradableReadGuard<T>
+where
+    T: Deref,
+    <T as Deref>::Target: LazyData,
+{
+    type Target = <<T as Deref>::Target as LazyData>::Target;
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*(*self.0).get() }
+    }
+}
+
+impl<T> Debug for UpgradableReadGuard<T>
+where
+    T: Deref,
+    <T as Deref>::Target: LazyData,
+    <<T as Deref>::Target as LazyData>::Target: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("UpgradableReadGuard").field(&*self).finish()
+    }
+}
+
+impl<T> Phased for UpgradableReadGuard<T>
+where
+    T: Phased,
+{
+    #[inline(always)]
+    fn phase(this: &Self) -> Phase {
+        Phased::phase(&this.0)
+    }
+}
+
+impl<T, U> From<WriteGuard<T>> for UpgradableReadGuard<U>
+where
+    U: From<T>,
+{
+    #[inline(always)]
+    fn from(v: WriteGuard<T>) -> Self {
+        Self(v.0.into())
+    }
+}
+
+impl<T, U> From<UpgradableReadGuard<T>> for ReadGuard<U>
+where
+    U: From<T>,
+{
+    #[inline(always)]
+    fn from(v: UpgradableReadGuard<T>) -> Self {
+        Self(v.0.into())
+    }
+}
+
 #[cfg(any(feature = "parking_lot_core", debug_mode))]
 impl<T: LazyData> RefUnwindSafe for ReadGuard<T> where <T as LazyData>::Target: RefUnwindSafe {}
 
@@ -608,8 +821,6 @@ pub(crate) struct GenericLockedLazy<T, F, M, S> {
     seq: GenericLockedLazySeq<T, M>,
     generator: F,
     phantom: PhantomData<S>,
-    #[cfg(debug_mode)]
-    _info: Option<StaticInfo>,
 }
 
 impl<T, F, M, S> GenericLockedLazy<T, F, M, S> {
@@ -643,11 +854,16 @@ impl<T, F, M, S> GenericLockedLazy<T, F, M, S> {
             seq: GenericLockedLazySeq {
                 value,
                 sequentializer,
+                #[cfg(any(
+                    debug_mode,
+                    feature = "tracing",
+                    feature = "cycle_detect",
+                    feature = "deadlock_detection"
+                ))]
+                info: None,
             },
             generator,
             phantom: PhantomData,
-            #[cfg(debug_mode)]
-            _info: None,
         }
     }
     #[inline(always)]
@@ -685,11 +901,16 @@ impl<T, F, M, S> GenericLockedLazy<T, F, M, S> {
             seq: GenericLockedLazySeq {
                 value,
                 sequentializer,
+                #[cfg(any(
+                    debug_mode,
+                    feature = "tracing",
+                    feature = "cycle_detect",
+                    feature = "deadlock_detection"
+                ))]
+                info: Some(_info),
             },
             generator,
             phantom: PhantomData,
-            #[cfg(debug_mode)]
-            _info: Some(_info),
         }
     }
     #[inline(always)]
@@ -697,13 +918,17 @@ impl<T, F, M, S> GenericLockedLazy<T, F, M, S> {
     pub fn sequentializer(this: &Self) -> &M {
         &this.seq.sequentializer
     }
+    #[inline(always)]
+    ///get access to the sequential object that is registered for finalization
+    pub fn sequential(this: &Self) -> &GenericLockedLazySeq<T, M> {
+        &this.seq
+    }
 }
 impl<'a, T, F, M, S> GenericLockedLazy<T, F, M, S>
 where
     T: 'a + LazyData,
     M: 'a,
     M: LazySequentializer<'a, GenericLockedLazySeq<T, M>>,
-    F: 'a + Generator<T::Target>,
     S: 'a + LazyPolicy,
     M::ReadGuard: Phased,
     M::WriteGuard: Phased,
@@ -900,36 +1125,112 @@ where
     }
 
     #[inline(always)]
-    /// Initialize if necessary then return a read lock
+    /// Initialize if necessary with the value returned by `generator` instead of the one
+    /// of the lazy, then return a read lock
     ///
     /// # Safety
     ///
     /// Undefined behaviour if after initialization the return object is not in an accessible
     /// state.
-    pub unsafe fn init_then_read_lock_unchecked(this: &'a Self) -> ReadGuard<M::ReadGuard> {
-        let r = may_debug(
-            || {
-                <M as LazySequentializer<'a, GenericLockedLazySeq<T, M>>>::init_then_read_guard(
-                    &this.seq,
-                    S::shall_init,
-                    |data: &T| {
-                        // SAFETY
-                        // This function is called only once within the init function
-                        // Only one thread can ever get this mutable access
-                        let d = Generator::generate(&this.generator);
-                        #[allow(unused_unsafe)]
-                        unsafe {
-                            data.init(d)
-                        };
-                    },
-                )
+    pub unsafe fn init_with_then_read_lock_unchecked(
+        this: &'a Self,
+        generator: impl FnOnce() -> T::Target,
+    ) -> ReadGuard<M::ReadGuard> {
+        let r = <M as LazySequentializer<'a, GenericLockedLazySeq<T, M>>>::init_then_read_guard(
+            &this.seq,
+            S::shall_init,
+            |data: &T| {
+                // SAFETY
+                // This function is called only once within the init function
+                // Only one thread can ever get this mutable access
+                let d = generator();
+                #[allow(unused_unsafe)]
+                unsafe {
+                    data.init(d)
+                };
             },
-            #[cfg(debug_mode)]
-            &this._info,
         );
         ReadGuard(r)
     }
 
+    /// Initialize if necessary with the value returned by `generator` then return a read lock
+    ///
+    /// Returns an error if after initialization the return object is not in an accessible
+    /// state.
+    #[inline(always)]
+    pub fn init_with_then_try_read_lock(
+        this: &'a Self,
+        generator: impl FnOnce() -> T::Target,
+    ) -> Result<ReadGuard<M::ReadGuard>, AccessError> {
+        post_init_checked_access::<ReadGuard<M::ReadGuard>, S>(unsafe {
+            Self::init_with_then_read_lock_unchecked(this, generator)
+        })
+    }
+
+    #[inline(always)]
+    /// Get a write lock, initialize the target if necessary with the value returned by
+    /// `generator` instead of the one of the lazy, then returns the write lock.
+    ///
+    /// # Safety
+    ///
+    /// If the target object is not accessible, this will cause undefined behaviour
+    pub unsafe fn init_with_then_write_lock_unchecked(
+        this: &'a Self,
+        generator: impl FnOnce() -> T::Target,
+    ) -> WriteGuard<M::WriteGuard> {
+        let r = <M as LazySequentializer<'a, GenericLockedLazySeq<T, M>>>::init_then_write_guard(
+            &this.seq,
+            S::shall_init,
+            |data: &T| {
+                // SAFETY
+                // This function is called only once within the init function
+                // Only one thread can ever get this mutable access
+                let d = generator();
+                #[allow(unused_unsafe)]
+                unsafe {
+                    data.init(d)
+                };
+            },
+        );
+        WriteGuard(r)
+    }
+
+    #[inline(always)]
+    /// Get a write lock, initialize the target if necessary with the value returned by
+    /// `generator` then returns the write lock.
+    ///
+    /// If the target object is not accessible an error is returned.
+    pub fn init_with_then_try_write_lock(
+        this: &'a Self,
+        generator: impl FnOnce() -> T::Target,
+    ) -> Result<WriteGuard<M::WriteGuard>, AccessError> {
+        post_init_checked_access::<WriteGuard<M::WriteGuard>, S>(unsafe {
+            Self::init_with_then_write_lock_unchecked(this, generator)
+        })
+    }
+}
+
+impl<'a, T, F, M, S> GenericLockedLazy<T, F, M, S>
+where
+    T: 'a + LazyData,
+    M: 'a,
+    M: LazySequentializer<'a, GenericLockedLazySeq<T, M>>,
+    F: 'a + Generator<T::Target>,
+    S: 'a + LazyPolicy,
+    M::ReadGuard: Phased,
+    M::WriteGuard: Phased,
+{
+    #[inline(always)]
+    /// Initialize if necessary then return a read lock
+    ///
+    /// # Safety
+    ///
+    /// Undefined behaviour if after initialization the return object is not in an accessible
+    /// state.
+    pub unsafe fn init_then_read_lock_unchecked(this: &'a Self) -> ReadGuard<M::ReadGuard> {
+        Self::init_with_then_read_lock_unchecked(this, || Generator::generate(&this.generator))
+    }
+
     /// Initialize if necessary then return a read lock
     ///
     /// Returns an error if after initialization the return object is not in an accessible
@@ -963,25 +1264,19 @@ where
     pub unsafe fn fast_init_then_read_lock_unchecked(
         this: &'a Self,
     ) -> Option<ReadGuard<M::ReadGuard>> {
-        may_debug(
-            || {
-                <M as LazySequentializer<'a, GenericLockedLazySeq<T, M>>>::try_init_then_read_guard(
-                    &this.seq,
-                    S::shall_init,
-                    |data: &T| {
-                        // SAFETY
-                        // This function is called only once within the init function
-                        // Only one thread can ever get this mutable access
-                        let d = Generator::generate(&this.generator);
-                        #[allow(unused_unsafe)]
-                        unsafe {
-                            data.init(d)
-                        };
-                    },
-                )
+        <M as LazySequentializer<'a, GenericLockedLazySeq<T, M>>>::try_init_then_read_guard(
+            &this.seq,
+            S::shall_init,
+            |data: &T| {
+                // SAFETY
+                // This function is called only once within the init function
+                // Only one thread can ever get this mutable access
+                let d = Generator::generate(&this.generator);
+                #[allow(unused_unsafe)]
+                unsafe {
+                    data.init(d)
+                };
             },
-            #[cfg(debug_mode)]
-            &this._info,
         )
         .map(ReadGuard)
     }
@@ -1018,27 +1313,7 @@ where
     ///
     /// If the target object is not accessible, this will cause undefined behaviour
     pub unsafe fn init_then_write_lock_unchecked(this: &'a Self) -> WriteGuard<M::WriteGuard> {
-        let r = may_debug(
-            || {
-                <M as LazySequentializer<'a, GenericLockedLazySeq<T, M>>>::init_then_write_guard(
-                    &this.seq,
-                    S::shall_init,
-                    |data: &T| {
-                        // SAFETY
-                        // This function is called only once within the init function
-                        // Only one thread can ever get this mutable access
-                        let d = Generator::generate(&this.generator);
-                        #[allow(unused_unsafe)]
-                        unsafe {
-                            data.init(d)
-                        };
-                    },
-                )
-            },
-            #[cfg(debug_mode)]
-            &this._info,
-        );
-        WriteGuard(r)
+        Self::init_with_then_write_lock_unchecked(this, || Generator::generate(&this.generator))
     }
 
     #[inline(always)]
@@ -1061,6 +1336,60 @@ where
         Self::init_then_try_write_lock(this).unwrap()
     }
 
+    /// Get a write lock, drop the target if it is initialized and clear the phase
+    /// so that the next access initializes the target again.
+    ///
+    /// Returns the phase before the reset.
+    pub fn reset(this: &'a Self) -> Phase
+    where
+        M::WriteGuard: PhaseGuard<'a, T>,
+    {
+        let mut l = unsafe { Self::write_lock_unchecked(this) }.0;
+        let cur = l.phase();
+        if cur.intersects(Phase::INITIALIZED) {
+            // If drop panics the target is considered droped
+            l.transition(
+                |data: &T| unsafe { data.get().drop_in_place() },
+                Phase::empty(),
+                Phase::empty(),
+            );
+        } else {
+            l.set_phase(Phase::empty());
+        }
+        cur
+    }
+
+    /// Get a write lock, drop the target if it is initialized then initialize it
+    /// again and return the write lock.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the initialization panics.
+    pub fn reinit_then_write_lock(this: &'a Self) -> WriteGuard<M::WriteGuard>
+    where
+        M::WriteGuard: PhaseGuard<'a, T>,
+    {
+        let mut l = unsafe { Self::write_lock_unchecked(this) }.0;
+        if l.phase().intersects(Phase::INITIALIZED) {
+            l.transition(
+                |data: &T| unsafe { data.get().drop_in_place() },
+                Phase::empty(),
+                Phase::empty(),
+            );
+        }
+        #[cfg(feature = "tracing")]
+        let _t = crate::trace::InitTrace::enter(this.seq.info.as_ref());
+        l.transition(
+            |data: &T| {
+                let d = Generator::generate(&this.generator);
+                unsafe { data.init(d) };
+            },
+            Phase::INITIALIZED,
+            Phase::INITIALIZATION_PANICKED | Phase::INITIALIZATION_SKIPED,
+        );
+        WriteGuard(l)
+    }
+
     #[inline(always)]
     /// Attempt to get a write locks then initialize the target if necessary and returns the
     /// writelock.
@@ -1071,23 +1400,19 @@ where
     pub unsafe fn fast_init_then_write_lock_unchecked(
         this: &'a Self,
     ) -> Option<WriteGuard<M::WriteGuard>> {
-        may_debug(
-            || {
-                <M as LazySequentializer<'a, GenericLockedLazySeq<T, M>>>::try_init_then_write_guard(
-                    &this.seq,
-                    S::shall_init,
-                    |data: &T| {
-                        // SAFETY
-                        // This function is called only once within the init function
-                        // Only one thread can ever get this mutable access
-                        let d = Generator::generate(&this.generator);
-                        #[allow(unused_unsafe)]
-                        unsafe { data.init(d) };
-                    },
-                )
+        <M as LazySequentializer<'a, GenericLockedLazySeq<T, M>>>::try_init_then_write_guard(
+            &this.seq,
+            S::shall_init,
+            |data: &T| {
+                // SAFETY
+                // This function is called only once within the init function
+                // Only one thread can ever get this mutable access
+                let d = Generator::generate(&this.generator);
+                #[allow(unused_unsafe)]
+                unsafe {
+                    data.init(d)
+                };
             },
-            #[cfg(debug_mode)]
-            &this._info,
         )
         .map(WriteGuard)
     }
@@ -1114,6 +1439,201 @@ where
     }
 }
 
+impl<'a, T, F, M, S> GenericLockedLazy<T, F, M, S>
+where
+    T: 'a + LazyData,
+    M: 'a,
+    M: FallibleLazySequentializer<'a, GenericLockedLazySeq<T, M>>,
+    F: 'a,
+    S: 'a + LazyPolicy,
+    M::ReadGuard: Phased,
+    M::WriteGuard: Phased,
+{
+    #[inline(always)]
+    /// Initialize if necessary with the fallible generator of the lazy then return a
+    /// read lock.
+    ///
+    /// If the generator fails, the target stays uninitialized, the phase is set to
+    /// INITIALIZATION_SKIPED and the error of the generator is returned.
+    pub fn try_init_then_read_lock<E>(
+        this: &'a Self,
+    ) -> Result<ReadGuard<M::ReadGuard>, InitError<E>>
+    where
+        F: TryGenerator<T::Target, E>,
+    {
+        let mut error = None;
+        let r = <M as FallibleLazySequentializer<'a, GenericLockedLazySeq<T, M>>>::fallible_init_then_read_guard(
+            &this.seq,
+            S::shall_init,
+            |data: &T| try_init(data, &this.generator, &mut error),
+        );
+        if let Some(e) = error {
+            return Err(InitError::Generator(e));
+        }
+        Ok(post_init_checked_access::<ReadGuard<M::ReadGuard>, S>(
+            ReadGuard(r),
+        )?)
+    }
+
+    #[inline(always)]
+    /// Get a write lock, initialize the target if necessary with the fallible generator
+    /// of the lazy then returns the write lock.
+    ///
+    /// If the generator fails, the target stays uninitialized, the phase is set to
+    /// INITIALIZATION_SKIPED and the error of the generator is returned.
+    pub fn try_init_then_write_lock<E>(
+        this: &'a Self,
+    ) -> Result<WriteGuard<M::WriteGuard>, InitError<E>>
+    where
+        F: TryGenerator<T::Target, E>,
+    {
+        let mut error = None;
+        let r = <M as FallibleLazySequentializer<'a, GenericLockedLazySeq<T, M>>>::fallible_init_then_write_guard(
+            &this.seq,
+            S::shall_init,
+            |data: &T| try_init(data, &this.generator, &mut error),
+        );
+        if let Some(e) = error {
+            return Err(InitError::Generator(e));
+        }
+        Ok(post_init_checked_access::<WriteGuard<M::WriteGuard>, S>(
+            WriteGuard(r),
+        )?)
+    }
+}
+
+impl<'a, T, F, M, S> GenericLockedLazy<T, F, M, S>
+where
+    T: 'a + LazyData,
+    M: 'a,
+    M: LazySequentializer<'a, GenericLockedLazySeq<T, M>>,
+    M: UpgradableSequentializer<'a, GenericLockedLazySeq<T, M>>,
+    F: 'a + Generator<T::Target>,
+    S: 'a + LazyPolicy,
+    M::ReadGuard: Phased,
+    M::WriteGuard: Phased,
+{
+    /// Initialize if necessary then return an upgradable read lock
+    ///
+    /// Returns an error if after initialization the return object is not in an accessible
+    /// state.
+    #[inline(always)]
+    pub fn init_then_try_upgradable_read_lock(
+        this: &'a Self,
+    ) -> Result<UpgradableReadGuard<M::UpgradableReadGuard>, AccessError> {
+        let l = <M as UpgradableSequentializer<'a, GenericLockedLazySeq<T, M>>>::upgradable_lock(
+            &this.seq,
+        );
+        let l = if S::shall_init(Phased::phase(&l)) {
+            drop(l);
+            // the write lock is atomically turned into an upgradable lock
+            unsafe { Self::init_then_write_lock_unchecked(this) }
+                .0
+                .into()
+        } else {
+            l
+        };
+        post_init_checked_access::<UpgradableReadGuard<M::UpgradableReadGuard>, S>(
+            UpgradableReadGuard(l),
+        )
+    }
+
+    /// Initialize if necessary then return an upgradable read lock
+    ///
+    /// # Panics
+    ///
+    /// Panics if after initialization the return object is not in an accessible
+    /// state.
+    #[inline(always)]
+    pub fn init_then_upgradable_read_lock(
+        this: &'a Self,
+    ) -> UpgradableReadGuard<M::UpgradableReadGuard> {
+        Self::init_then_try_upgradable_read_lock(this).unwrap()
+    }
+}
+
+#[cfg(feature = "timeout")]
+impl<'a, T, F, M, S> GenericLockedLazy<T, F, M, S>
+where
+    T: 'a + LazyData,
+    M: 'a,
+    M: TimedLazySequentializer<'a, GenericLockedLazySeq<T, M>>,
+    F: 'a + Generator<T::Target>,
+    S: 'a + LazyPolicy,
+    M::ReadGuard: Phased,
+    M::WriteGuard: Phased,
+{
+    /// Initialize if necessary then return a read lock, unless the lock could not be
+    /// obtained before `deadline`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if after initialization the return object is not in an accessible
+    /// state.
+    #[inline(always)]
+    pub fn init_then_read_lock_until(
+        this: &'a Self,
+        deadline: Instant,
+    ) -> Result<ReadGuard<M::ReadGuard>, TimeoutError> {
+        let r = <M as TimedLazySequentializer<'a, GenericLockedLazySeq<T, M>>>::init_then_read_guard_until(
+                    &this.seq,
+                    S::shall_init,
+                    |data: &T| {
+                        // SAFETY
+                        // This function is called only once within the init function
+                        // Only one thread can ever get this mutable access
+                        let d = Generator::generate(&this.generator);
+                        #[allow(unused_unsafe)]
+                        unsafe {
+                            data.init(d)
+                        };
+                    },
+                    deadline,
+                );
+        match r {
+            Ok(l) => {
+                Ok(post_init_checked_access::<ReadGuard<M::ReadGuard>, S>(ReadGuard(l)).unwrap())
+            }
+            Err(p) => Err(TimeoutError::new::<S>(p)),
+        }
+    }
+
+    /// Get a write lock, initialize the target if necessary then return the write lock,
+    /// unless the lock could not be obtained before `deadline`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if after initialization the return object is not in an accessible
+    /// state.
+    #[inline(always)]
+    pub fn init_then_write_lock_until(
+        this: &'a Self,
+        deadline: Instant,
+    ) -> Result<WriteGuard<M::WriteGuard>, TimeoutError> {
+        let r = <M as TimedLazySequentializer<'a, GenericLockedLazySeq<T, M>>>::init_then_write_guard_until(
+                    &this.seq,
+                    S::shall_init,
+                    |data: &T| {
+                        // SAFETY
+                        // This function is called only once within the init function
+                        // Only one thread can ever get this mutable access
+                        let d = Generator::generate(&this.generator);
+                        #[allow(unused_unsafe)]
+                        unsafe {
+                            data.init(d)
+                        };
+                    },
+                    deadline,
+                );
+        match r {
+            Ok(l) => Ok(
+                post_init_checked_access::<WriteGuard<M::WriteGuard>, S>(WriteGuard(l)).unwrap(),
+            ),
+            Err(p) => Err(TimeoutError::new::<S>(p)),
+        }
+    }
+}
+
 impl<T, F, M, S> GenericLockedLazy<T, F, M, S>
 where
     M: UniqueLazySequentializer<GenericLockedLazySeq<T, M>>,
@@ -1181,6 +1701,16 @@ unsafe impl<T: LazyData, M> Sequential for GenericLockedLazySeq<T, M> {
     fn data(this: &Self) -> &Self::Data {
         &this.value
     }
+    #[cfg(any(
+        debug_mode,
+        feature = "tracing",
+        feature = "cycle_detect",
+        feature = "deadlock_detection"
+    ))]
+    #[inline(always)]
+    fn info(this: &Self) -> Option<&StaticInfo> {
+        this.info.as_ref()
+    }
 }
 impl<F, T, M, S> Deref for GenericLockedLazy<T, F, M, S> {
     type Target = T;
@@ -1212,26 +1742,25 @@ impl<F, T, M: Phased, S> Phased for GenericLazy<T, F, M, S> {
     }
 }
 
+/// Initialize `data` with the value returned by `generator`, or store the error
+/// of the generator in `error`. Return true if `data` has been initialized.
 #[inline(always)]
-fn may_debug<R, F: FnOnce() -> R>(f: F, #[cfg(debug_mode)] info: &Option<StaticInfo>) -> R {
-    #[cfg(not(debug_mode))]
-    {
-        f()
-    }
-    #[cfg(debug_mode)]
-    {
-        match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f())) {
-            Ok(r) => r,
-            Err(x) => {
-                if x.is::<CyclicPanic>() {
-                    match info {
-                        Some(info) => panic!("Circular initialization of {:#?}", info),
-                        None => panic!("Circular lazy initialization detected"),
-                    }
-                } else {
-                    std::panic::resume_unwind(x)
-                }
-            }
+fn try_init<T: LazyData, E>(
+    data: &T,
+    generator: &impl TryGenerator<T::Target, E>,
+    error: &mut Option<E>,
+) -> bool {
+    match generator.try_generate() {
+        Ok(d) => {
+            // SAFETY
+            // This function is called only once within the init function
+            // Only one thread can ever get this mutable access
+            unsafe { data.init(d) };
+            true
+        }
+        Err(e) => {
+            *error = Some(e);
+            false
         }
     }
 }
@@ -1,6 +1,6 @@
 use crate::phase_locker::{
-    SyncPhaseGuard, SyncPhaseLocker, SyncReadPhaseGuard, UnSyncPhaseGuard, UnSyncPhaseLocker,
-    UnSyncReadPhaseGuard,
+    PhaseGuard, SyncPhaseGuard, SyncPhaseLocker, SyncReadPhaseGuard, SyncUpgradableReadPhaseGuard,
+    UnSyncPhaseGuard, UnSyncPhaseLocker, UnSyncReadPhaseGuard,
 };
 use crate::{
     generic_lazy::{
@@ -8,19 +8,31 @@ use crate::{
         Primed, UnInited,
     },
     lazy_sequentializer::UnSyncSequentializer,
-    Finaly, Generator, GeneratorTolerance, Phase, Phased, StaticInfo, Uninit,
+    try_lazy::InitError,
+    Finaly, Generator, GeneratorTolerance, OnceGenerator, Phase, Phased, StaticInfo, Uninit,
 };
 
 #[cfg(feature = "thread_local")]
 use crate::exit_sequentializer::ThreadExitSequentializer;
 
-use crate::{exit_sequentializer::ExitSequentializer, lazy_sequentializer::SyncSequentializer};
+#[cfg(feature = "timeout")]
+use crate::TimeoutError;
+#[cfg(feature = "timeout")]
+use std::time::{Duration, Instant};
 
-use core::cell::Cell;
+#[cfg(feature = "stats")]
+use crate::statistics::{Instrumented, Statistics};
+
+use crate::{
+    exit_sequentializer::{finalize_now, ExitSequentializer},
+    lazy_sequentializer::SyncSequentializer,
+};
+
+use core::cell::{Cell, UnsafeCell};
 use core::marker::PhantomData;
 use core::ops::{Deref, DerefMut};
 
-struct InitializedChecker<T>(PhantomData<T>);
+pub(crate) struct InitializedChecker<T>(PhantomData<T>);
 
 impl<Tol: GeneratorTolerance> LazyPolicy for InitializedChecker<Tol> {
     #[inline(always)]
@@ -49,7 +61,7 @@ impl<Tol: GeneratorTolerance> LazyPolicy for InitializedChecker<Tol> {
     }
 }
 
-struct InitializedSoftFinalizedCheckerGeneric<T, const REG_ALWAYS: bool>(PhantomData<T>);
+pub(crate) struct InitializedSoftFinalizedCheckerGeneric<T, const REG_ALWAYS: bool>(PhantomData<T>);
 
 impl<Tol: GeneratorTolerance, const REG_ALWAYS: bool> LazyPolicy
     for InitializedSoftFinalizedCheckerGeneric<Tol, REG_ALWAYS>
@@ -113,7 +125,8 @@ impl<Tol: GeneratorTolerance, const REG_ALWAYS: bool> LazyPolicy
     }
 }
 
-type InitializedSoftFinalizedChecker<T> = InitializedSoftFinalizedCheckerGeneric<T, false>;
+pub(crate) type InitializedSoftFinalizedChecker<T> =
+    InitializedSoftFinalizedCheckerGeneric<T, false>;
 
 type InitializedHardFinalizedChecker<T> = InitializedHardFinalizedCheckerGeneric<T, false>;
 
@@ -154,6 +167,164 @@ pub trait LazyAccess: Sized {
     fn init(this: Self) -> Phase;
 }
 
+/// Helper trait to access locked lazies generically
+pub trait LockedLazyAccess<'a> {
+    type Target;
+    type ReadGuard: Deref<Target = Self::Target> + Phased;
+    type WriteGuard: DerefMut<Target = Self::Target> + Phased;
+    /// A write lock on a part of the target
+    type MappedWriteGuard<U: 'a>: DerefMut<Target = U> + Phased;
+    /// Initialize if necessary and returns a read lock
+    fn read(&'a self) -> Self::ReadGuard;
+    /// Initialize if necessary and returns some read lock if the lazy is not
+    /// already write locked
+    fn fast_read(&'a self) -> Option<Self::ReadGuard>;
+    /// Get a read lock if the lazy is initialized or an [AccessError]
+    fn try_read(&'a self) -> Result<Self::ReadGuard, AccessError>;
+    /// Initialize if necessary and returns a write lock
+    fn write(&'a self) -> Self::WriteGuard;
+    /// Initialize if necessary and returns some write lock if the lazy is not
+    /// already read or write locked
+    fn fast_write(&'a self) -> Option<Self::WriteGuard>;
+    /// Get a write lock if the lazy is initialized or an [AccessError]
+    fn try_write(&'a self) -> Result<Self::WriteGuard, AccessError>;
+    /// The current phase of the static
+    fn phase(&'a self) -> Phase;
+    /// Initialize the lazy if no previous attempt to initialized it where performed
+    fn init(&'a self);
+    /// Make a write lock on a part of the target if `f` returns `Ok`, otherwise
+    /// give back the write lock along with the error.
+    #[allow(clippy::type_complexity)]
+    fn try_map_write<U: 'a, E>(
+        guard: Self::WriteGuard,
+        f: impl FnOnce(&mut Self::Target) -> Result<&mut U, E>,
+    ) -> Result<Self::MappedWriteGuard<U>, (Self::WriteGuard, E)>;
+    /// Set or clear the [Phase::POISONED] bit of the phase the lazy will be in
+    /// when the write lock is released.
+    fn set_poisoned(guard: &mut Self::WriteGuard, poisoned: bool);
+    /// Set or clear the [Phase::POISONED] bit of the phase the lazy will be in
+    /// when the write lock on a part of the target is released.
+    fn set_mapped_poisoned<U: 'a>(guard: &mut Self::MappedWriteGuard<U>, poisoned: bool);
+}
+
+/// Helper trait to access generically locked lazies that provide upgradable read locks
+pub trait UpgradableLockedLazyAccess<'a>: LockedLazyAccess<'a> {
+    type UpgradableReadGuard: Deref<Target = Self::Target> + Phased;
+    /// Initialize if necessary and returns an upgradable read lock
+    fn upgradable_read(&'a self) -> Self::UpgradableReadGuard;
+    /// Turn an upgradable read lock into a write lock
+    fn upgrade(guard: Self::UpgradableReadGuard) -> Self::WriteGuard;
+}
+
+#[cfg(feature = "timeout")]
+/// Helper trait to access generically locked lazies whose locks can time out
+pub trait TimedLockedLazyAccess<'a>: LockedLazyAccess<'a> {
+    /// Initialize if necessary and returns a read lock, or an error if the lock
+    /// could not be obtained before `deadline`
+    fn read_until(&'a self, deadline: Instant) -> Result<Self::ReadGuard, TimeoutError>;
+    /// Initialize if necessary and returns a write lock, or an error if the lock
+    /// could not be obtained before `deadline`
+    fn write_until(&'a self, deadline: Instant) -> Result<Self::WriteGuard, TimeoutError>;
+}
+
+/// Set or clear the [Phase::POISONED] bit of the phase a phase guard
+/// will release the lock with
+#[inline(always)]
+fn set_guard_poisoned<'a, T: ?Sized + 'a>(guard: &mut impl PhaseGuard<'a, T>, poisoned: bool) {
+    let p = PhaseGuard::phase(guard);
+    let p = if poisoned {
+        p | Phase::POISONED
+    } else {
+        p - Phase::POISONED
+    };
+    PhaseGuard::set_phase(guard, p);
+}
+
+macro_rules! impl_locked_lazy_access {
+    ($tp:ident $(,T: $tr: ident)?$(,G: $trg:ident)? $(,$static:lifetime)?) => {
+        impl<'a, T: 'a, G: 'a> LockedLazyAccess<'a> for $tp<T, G>
+        where G: $($static +)? Generator<T>,
+        $('a: $static, T: $static,)?
+        $(G:$trg, T:Send,)?
+        $(T:$tr,)?
+        {
+            type Target = T;
+            type ReadGuard = ReadGuard<'a, T>;
+            type WriteGuard = WriteGuard<'a, T>;
+            type MappedWriteGuard<U: 'a> = MappedWriteGuard<'a, U>;
+            #[inline(always)]
+            fn read(&'a self) -> ReadGuard<'a, T> {
+                $tp::read(self)
+            }
+            #[inline(always)]
+            fn fast_read(&'a self) -> Option<ReadGuard<'a, T>> {
+                $tp::fast_read(self)
+            }
+            #[inline(always)]
+            fn try_read(&'a self) -> Result<ReadGuard<'a, T>, AccessError> {
+                $tp::try_read(self)
+            }
+            #[inline(always)]
+            fn write(&'a self) -> WriteGuard<'a, T> {
+                $tp::write(self)
+            }
+            #[inline(always)]
+            fn fast_write(&'a self) -> Option<WriteGuard<'a, T>> {
+                $tp::fast_write(self)
+            }
+            #[inline(always)]
+            fn try_write(&'a self) -> Result<WriteGuard<'a, T>, AccessError> {
+                $tp::try_write(self)
+            }
+            #[inline(always)]
+            fn phase(&'a self) -> Phase {
+                $tp::phase(self)
+            }
+            #[inline(always)]
+            fn init(&'a self) {
+                let _ = $tp::init(self);
+            }
+            #[inline(always)]
+            fn try_map_write<U: 'a, E>(
+                guard: WriteGuard<'a, T>,
+                f: impl FnOnce(&mut T) -> Result<&mut U, E>,
+            ) -> Result<MappedWriteGuard<'a, U>, (WriteGuard<'a, T>, E)> {
+                WriteGuard::try_map(guard, f)
+            }
+            #[inline(always)]
+            fn set_poisoned(guard: &mut WriteGuard<'a, T>, poisoned: bool) {
+                set_guard_poisoned(&mut (guard.0).0, poisoned)
+            }
+            #[inline(always)]
+            fn set_mapped_poisoned<U: 'a>(guard: &mut MappedWriteGuard<'a, U>, poisoned: bool) {
+                set_guard_poisoned(&mut guard.0, poisoned)
+            }
+        }
+    };
+}
+
+macro_rules! impl_finalize_now {
+    ($tp:ident, $generic:ident, $tsync:ident $(,T: $tr:ident)?) => {
+        impl<T, G> $tp<T, G>
+        where
+            G: 'static + Generator<T> + Sync,
+            T: 'static + $tsync,
+            $(T: $tr,)?
+        {
+            #[inline(always)]
+            /// Finalize the target now if it is initialized and not yet finalized.
+            ///
+            /// The static is removed from the list of statics finalized at program exit
+            /// so that it is not finalized twice.
+            ///
+            /// Returns the phase after the finalization.
+            pub fn finalize_now(&'static self) -> Phase {
+                finalize_now($generic::sequential(&self.__private))
+            }
+        }
+    };
+}
+
 macro_rules! impl_lazy {
     ($tp:ident, $man:ident$(<$x:ident>)?, $checker:ident, $data:path, $locker:ty $(,T: $tr: ident)?$(,G: $trg:ident)?, $doc:literal $(cfg($attr:meta))?) => {
         impl_lazy! {@proc $tp,$man$(<$x>)?,$checker,$data,$locker $(,T:$tr)?$(,G:$trg)?,$doc $(cfg($attr))?}
@@ -573,7 +744,7 @@ macro_rules! impl_lazy {
             pub const $($safe)?  fn from_generator_with_info(f: G, info: StaticInfo) -> Self {
                 #[allow(unused_unsafe)]
                 Self {
-                    __private: unsafe{GenericLazy::new_with_info(f, $man::new(<$locker>::new(Phase::empty())), <$data>::INIT,info)},
+                    __private: unsafe{GenericLazy::new_with_info(f, $man::new_with_info(<$locker>::new(Phase::empty()), info), <$data>::INIT,info)},
                 }
             }
         }
@@ -604,6 +775,9 @@ The method [from_generator](Self::from_generator) is unsafe because this kind of
 can only safely be used through this attribute macros."
 }
 
+impl_finalize_now! {LazyFinalize, GenericLazy, Sync, T: Finaly}
+impl_finalize_now! {LesserLazyFinalize, GenericLazy, Sync, T: Finaly}
+
 impl_lazy! {UnSyncLazy,UnSyncSequentializer<G>,InitializedChecker,UnInited::<T>,UnSyncPhaseLocker,
 "A version of [Lazy] whose reference can not be passed to other thread"
 }
@@ -641,14 +815,14 @@ macro_rules! non_static_debug {
     }
 }
 macro_rules! non_static_impls {
-    ($tp:ident, $data:ty $(,T: $tr:ident)? $(,G: $trg:ident)?) => {
-        impl<T, G> $tp<T, Cell<Option<G>>>
+    ($tp:ident, $gen:ty = $new_gen:expr, $data:ty $(,T: $tr:ident)? $(,G: $trg:ident)?) => {
+        impl<T, G> $tp<T, $gen>
         where
             G: FnOnce() -> T,
         {
             #[inline(always)]
             pub fn new(g: G) -> Self {
-                Self::from_generator(Cell::new(Some(g)))
+                Self::from_generator($new_gen(g))
             }
         }
         impl<T: Default> Default for $tp<T, fn() -> T> {
@@ -659,9 +833,9 @@ macro_rules! non_static_impls {
         }
     };
 }
-non_static_impls! {Lazy,UnInited::<T>}
+non_static_impls! {Lazy, OnceGenerator<G> = OnceGenerator::new, UnInited::<T>}
 non_static_debug! {Lazy,UnInited::<T>}
-non_static_impls! {UnSyncLazy,UnInited::<T>}
+non_static_impls! {UnSyncLazy, Cell<Option<G>> = |g| Cell::new(Some(g)), UnInited::<T>}
 non_static_debug! {UnSyncLazy,UnInited::<T>}
 
 impl<T, G> Drop for Lazy<T, G> {
@@ -678,6 +852,33 @@ impl<T, G> Drop for Lazy<T, G> {
         }
     }
 }
+impl<T, G: GeneratorTolerance> Lazy<T, G> {
+    /// Initialize if necessary with the value returned by `f` instead of the one of the
+    /// generator of the lazy, then return a reference to the target.
+    ///
+    /// If `f` fails, the lazy stays uninitialized and the error is returned. As for a
+    /// [TryLazy](crate::TryLazy), a later initialization is attempted only if the generator
+    /// of the lazy tolerates initialization failures, as does a function:
+    ///
+    /// ```
+    /// use static_init::Lazy;
+    ///
+    /// let v: Lazy<i32> = Lazy::from_generator(|| 0);
+    ///
+    /// assert!(v.try_get_or_init(|| "x".parse::<i32>()).is_err());
+    /// assert_eq!(*v.try_get_or_init(|| "33".parse::<i32>()).unwrap(), 33);
+    /// assert_eq!(*v, 33);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panic if `f` panics.
+    #[inline]
+    pub fn try_get_or_init<E>(&self, f: impl FnOnce() -> Result<T, E>) -> Result<&T, InitError<E>> {
+        GenericLazy::try_init_with_then_try_get(&self.__private, f)
+    }
+}
+
 impl<T, G> Drop for UnSyncLazy<T, G> {
     #[inline(always)]
     fn drop(&mut self) {
@@ -711,10 +912,250 @@ macro_rules! non_static_mut_debug {
     }
 }
 
+macro_rules! impl_reset {
+    ($tp:ident $(,$static:lifetime)?) => {
+        impl<T, G> $tp<T, G>
+        where G: $($static +)? Generator<T>,
+        $(T: $static,)?
+        {
+            #[inline(always)]
+            /// Drop the target if it is initialized and put the lazy back in its uninitialized
+            /// phase, so that the next lock initializes it again.
+            ///
+            /// Returns the phase before the reset.
+            ///
+            /// The generator will be called again, so the generator must support being
+            /// called more than once: this is not the case of the generator of lazies built
+            /// with `new`.
+            pub fn reset(&$($static)? self) -> Phase {
+                GenericLockedLazy::reset(&self.__private)
+            }
+            #[inline(always)]
+            /// Drop the target if it is initialized, initialize it again and returns a write lock
+            ///
+            /// # Panic
+            ///
+            /// Panics if initialization panics or if the generator can not be called more than once.
+            pub fn reinit(&$($static)? self) -> WriteGuard<'_,T> {
+                WriteGuard(GenericLockedLazy::reinit_then_write_lock(&self.__private))
+            }
+        }
+    };
+}
+
+macro_rules! impl_upgradable_read {
+    ($tp:ident, $data:ty $(,T: $tr: ident)?$(,G: $trg:ident)? $(,$static:lifetime)?) => {
+        /// A read lock that can be atomically upgraded to a [WriteGuard].
+        ///
+        /// It can coexist with [ReadGuard]s but not with other upgradable read locks.
+        #[must_use="If unused the upgradable read lock is immediatly released"]
+        #[derive(Debug)]
+        pub struct UpgradableReadGuard<'a,T>(generic_lazy::UpgradableReadGuard<SyncUpgradableReadPhaseGuard::<'a,$data>>);
+
+        impl<'a,T> Deref for UpgradableReadGuard<'a,T>
+        $(where T: $static)?
+        {
+            type Target = T;
+            #[inline(always)]
+            fn deref(&self) -> &T {
+                &*self.0
+            }
+        }
+
+        impl<'a, T> Phased for UpgradableReadGuard<'a,T>
+        $(where T: $static)?
+        {
+            #[inline(always)]
+            fn phase(this: &Self) -> Phase {
+                Phased::phase(&this.0)
+            }
+        }
+
+        impl<'a,T> From<WriteGuard<'a,T>> for UpgradableReadGuard<'a,T> {
+            #[inline(always)]
+            fn from(that:WriteGuard<'a,T>) -> Self {
+                Self(that.0.into())
+            }
+        }
+
+        impl<'a,T> From<UpgradableReadGuard<'a,T>> for ReadGuard<'a,T> {
+            #[inline(always)]
+            fn from(that:UpgradableReadGuard<'a,T>) -> Self {
+                Self(that.0.into())
+            }
+        }
+
+        impl<'a,T> UpgradableReadGuard<'a,T> {
+            #[inline(always)]
+            /// Wait for the other read locks to be released then turn this lock into a
+            /// write lock.
+            ///
+            /// No write lock can be taken by an other thread between the acquisition of the
+            /// upgradable read lock and the return of the write lock, so what was read
+            /// through the upgradable read lock is still valid.
+            ///
+            /// While the upgrade is pending, new read locks wait for the write lock to
+            /// be released, so the thread calling `upgrade` must not hold an other read lock.
+            pub fn upgrade(this: Self) -> WriteGuard<'a,T> {
+                WriteGuard(generic_lazy::WriteGuard((this.0).0.upgrade()))
+            }
+        }
+
+        impl<T, G> $tp<T, G>
+        where G: $($static +)? Generator<T>,
+        $(T: $static,)?
+        $(G:$trg, T:Send,)?
+        $(T:$tr,)?
+        {
+            #[inline(always)]
+            /// Initialize if necessary and returns an upgradable read lock
+            ///
+            /// Only one upgradable read lock can be held at a time, but it does not prevent
+            /// other threads to get read locks. It can be turned into a write lock with
+            /// [UpgradableReadGuard::upgrade].
+            ///
+            /// As for other read locks, at most 2^17-1 read locks can be held at the same time.
+            ///
+            /// # Panic
+            ///
+            /// Panics if initialization panics or if initialization has panicked in a previous attempt to initialize.
+            pub fn upgradable_read(&$($static)? self) -> UpgradableReadGuard<'_,T> {
+                UpgradableReadGuard(GenericLockedLazy::init_then_upgradable_read_lock(&self.__private))
+            }
+        }
+
+        impl<'a, T: 'a, G: 'a> UpgradableLockedLazyAccess<'a> for $tp<T, G>
+        where G: $($static +)? Generator<T>,
+        $('a: $static, T: $static,)?
+        $(G:$trg, T:Send,)?
+        $(T:$tr,)?
+        {
+            type UpgradableReadGuard = UpgradableReadGuard<'a, T>;
+            #[inline(always)]
+            fn upgradable_read(&'a self) -> UpgradableReadGuard<'a, T> {
+                $tp::upgradable_read(self)
+            }
+            #[inline(always)]
+            fn upgrade(guard: UpgradableReadGuard<'a, T>) -> WriteGuard<'a, T> {
+                UpgradableReadGuard::upgrade(guard)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "timeout")]
+macro_rules! impl_timed_lock {
+    ($tp:ident $(,T: $tr: ident)?$(,G: $trg:ident)? $(,$static:lifetime)?) => {
+        impl<T, G> $tp<T, G>
+        where G: $($static +)? Generator<T>,
+        $(T: $static,)?
+        $(G:$trg, T:Send,)?
+        $(T:$tr,)?
+        {
+            #[inline(always)]
+            /// Initialize if necessary and returns a read lock, or an error if the lock
+            /// could not be obtained within `timeout`.
+            ///
+            /// The error tells whether the lazy was still being initialized by an other
+            /// thread or was write locked.
+            ///
+            /// # Panic
+            ///
+            /// Panics if initialization panics or if initialization has panicked in a previous attempt to initialize.
+            pub fn read_for(&$($static)? self, timeout: Duration) -> Result<ReadGuard<'_,T>, TimeoutError> {
+                match Instant::now().checked_add(timeout) {
+                    Some(deadline) => self.read_until(deadline),
+                    None => Ok(self.read()),
+                }
+            }
+            #[inline(always)]
+            /// Initialize if necessary and returns a write lock, or an error if the lock
+            /// could not be obtained within `timeout`.
+            ///
+            /// The error tells whether the lazy was still being initialized by an other
+            /// thread or was locked.
+            ///
+            /// # Panic
+            ///
+            /// Panics if initialization panics or if initialization has panicked in a previous attempt to initialize.
+            pub fn write_for(&$($static)? self, timeout: Duration) -> Result<WriteGuard<'_,T>, TimeoutError> {
+                match Instant::now().checked_add(timeout) {
+                    Some(deadline) => self.write_until(deadline),
+                    None => Ok(self.write()),
+                }
+            }
+            #[inline(always)]
+            /// Initialize if necessary and returns a read lock, or an error if the lock
+            /// could not be obtained before `deadline`.
+            ///
+            /// # Panic
+            ///
+            /// Panics if initialization panics or if initialization has panicked in a previous attempt to initialize.
+            pub fn read_until(&$($static)? self, deadline: Instant) -> Result<ReadGuard<'_,T>, TimeoutError> {
+                GenericLockedLazy::init_then_read_lock_until(&self.__private, deadline).map(ReadGuard)
+            }
+            #[inline(always)]
+            /// Initialize if necessary and returns a write lock, or an error if the lock
+            /// could not be obtained before `deadline`.
+            ///
+            /// # Panic
+            ///
+            /// Panics if initialization panics or if initialization has panicked in a previous attempt to initialize.
+            pub fn write_until(&$($static)? self, deadline: Instant) -> Result<WriteGuard<'_,T>, TimeoutError> {
+                GenericLockedLazy::init_then_write_lock_until(&self.__private, deadline).map(WriteGuard)
+            }
+        }
+
+        impl<'a, T: 'a, G: 'a> TimedLockedLazyAccess<'a> for $tp<T, G>
+        where G: $($static +)? Generator<T>,
+        $('a: $static, T: $static,)?
+        $(G:$trg, T:Send,)?
+        $(T:$tr,)?
+        {
+            #[inline(always)]
+            fn read_until(&'a self, deadline: Instant) -> Result<ReadGuard<'a, T>, TimeoutError> {
+                $tp::read_until(self, deadline)
+            }
+            #[inline(always)]
+            fn write_until(&'a self, deadline: Instant) -> Result<WriteGuard<'a, T>, TimeoutError> {
+                $tp::write_until(self, deadline)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "stats")]
+macro_rules! impl_statistics {
+    ($tp:ident) => {
+        impl<T, G: GeneratorTolerance> $tp<T, G> {
+            #[inline(always)]
+            /// Returns the block in which lock contention on this lazy is recorded,
+            /// if its generator has [one](GeneratorTolerance::STATISTICS).
+            ///
+            /// Statics declared with [#[dynamic(stats)]](macro@crate::dynamic) have one.
+            pub fn statistics(&self) -> Option<&'static Statistics> {
+                G::STATISTICS.map(|statistics| statistics())
+            }
+        }
+        impl<T, G: GeneratorTolerance> Instrumented for $tp<T, G> {
+            #[inline(always)]
+            fn statistics(&self) -> Option<&'static Statistics> {
+                $tp::statistics(self)
+            }
+        }
+    };
+}
+
 macro_rules! extend_locked_lazy {
     () => {
-        non_static_impls! {LockedLazy,UnInited::<T>}
+        non_static_impls! {LockedLazy, OnceGenerator<G> = OnceGenerator::new, UnInited::<T>}
         non_static_mut_debug! {LockedLazy,UnInited::<T>}
+        impl_reset! {LockedLazy}
+        impl_upgradable_read! {LockedLazy, UnInited::<T>}
+        #[cfg(feature = "timeout")]
+        impl_timed_lock! {LockedLazy}
+        #[cfg(feature = "stats")]
+        impl_statistics! {LockedLazy}
         impl<T: Send, G: Generator<T>> LockedLazy<T, G> {
             #[inline(always)]
             /// Initialize and return a mutable reference to the target
@@ -747,8 +1188,9 @@ macro_rules! extend_locked_lazy {
 }
 macro_rules! extend_unsync_locked_lazy {
     () => {
-        non_static_impls! {UnSyncLockedLazy,UnInited::<T>}
+        non_static_impls! {UnSyncLockedLazy, Cell<Option<G>> = |g| Cell::new(Some(g)), UnInited::<T>}
         non_static_mut_debug! {UnSyncLockedLazy,UnInited::<T>}
+        impl_reset! {UnSyncLockedLazy}
 
         impl<T, G: Generator<T>> UnSyncLockedLazy<T, G> {
             #[inline(always)]
@@ -782,6 +1224,85 @@ macro_rules! extend_unsync_locked_lazy {
     };
 }
 
+macro_rules! extend_locked_lazy_finalize {
+    () => {
+        impl_finalize_now! {LockedLazyFinalize, GenericLockedLazy, Send, T: Finaly}
+        impl_upgradable_read! {LockedLazyFinalize, UnInited::<T>, T:Finaly, G:Sync, 'static}
+        #[cfg(feature = "timeout")]
+        impl_timed_lock! {LockedLazyFinalize, T:Finaly, G:Sync, 'static}
+        #[cfg(feature = "stats")]
+        impl_statistics! {LockedLazyFinalize}
+    };
+}
+macro_rules! extend_lesser_locked_lazy_finalize {
+    () => {
+        impl_finalize_now! {LesserLockedLazyFinalize, GenericLockedLazy, Send, T: Finaly}
+        impl_upgradable_read! {LesserLockedLazyFinalize, UnInited::<T>, T:Finaly, G:Sync, 'static}
+        #[cfg(feature = "timeout")]
+        impl_timed_lock! {LesserLockedLazyFinalize, T:Finaly, G:Sync, 'static}
+        #[cfg(feature = "stats")]
+        impl_statistics! {LesserLockedLazyFinalize}
+    };
+}
+macro_rules! extend_locked_lazy_droped {
+    () => {
+        impl_finalize_now! {LockedLazyDroped, GenericLockedLazy, Send}
+        impl_upgradable_read! {LockedLazyDroped, DropedUnInited::<T>, G:Sync, 'static}
+        #[cfg(feature = "timeout")]
+        impl_timed_lock! {LockedLazyDroped, G:Sync, 'static}
+        #[cfg(feature = "stats")]
+        impl_statistics! {LockedLazyDroped}
+    };
+}
+macro_rules! extend_lesser_locked_lazy_droped {
+    () => {
+        impl_finalize_now! {LesserLockedLazyDroped, GenericLockedLazy, Send}
+        impl_upgradable_read! {LesserLockedLazyDroped, DropedUnInited::<T>, G:Sync, 'static}
+        #[cfg(feature = "timeout")]
+        impl_timed_lock! {LesserLockedLazyDroped, G:Sync, 'static}
+        #[cfg(feature = "stats")]
+        impl_statistics! {LesserLockedLazyDroped}
+    };
+}
+macro_rules! extend_primed_locked_lazy_droped {
+    () => {
+        impl_finalize_now! {PrimedLockedLazyDroped, GenericLockedLazy, Send, T: Uninit}
+        #[cfg(feature = "stats")]
+        impl_statistics! {PrimedLockedLazyDroped}
+    };
+}
+macro_rules! extend_primed_lesser_locked_lazy_droped {
+    () => {
+        impl_finalize_now! {PrimedLesserLockedLazyDroped, GenericLockedLazy, Send, T: Uninit}
+        #[cfg(feature = "stats")]
+        impl_statistics! {PrimedLesserLockedLazyDroped}
+    };
+}
+
+macro_rules! extend_primed_locked_lazy {
+    () => {
+        #[cfg(feature = "stats")]
+        impl_statistics! {PrimedLockedLazy}
+    };
+}
+macro_rules! extend_primed_lesser_locked_lazy {
+    () => {
+        #[cfg(feature = "stats")]
+        impl_statistics! {PrimedLesserLockedLazy}
+    };
+}
+
+macro_rules! extend_lesser_locked_lazy {
+    () => {
+        impl_reset! {LesserLockedLazy, 'static}
+        impl_upgradable_read! {LesserLockedLazy, UnInited::<T>, 'static}
+        #[cfg(feature = "timeout")]
+        impl_timed_lock! {LesserLockedLazy, 'static}
+        #[cfg(feature = "stats")]
+        impl_statistics! {LesserLockedLazy}
+    };
+}
+
 macro_rules! impl_mut_lazy {
     ($mod: ident $(:$extension:ident)?, $tp:ident, $man:ident$(<$x:ident>)?, $checker:ident, $data:ty, $locker:ty, $gdw: ident, $gd: ident $(,T: $tr: ident)?$(,G: $trg:ident)?, $doc:literal $(cfg($attr:meta))?) => {
         pub mod $mod {
@@ -794,12 +1315,13 @@ macro_rules! impl_mut_lazy {
         #[doc(inline)]
         pub use $mod::$tp;
     };
-    (static $mod: ident, $tp:ident, $man:ident$(<$x:ident>)?, $checker:ident, $data:ty, $locker: ty, $gdw: ident,$gd:ident  $(,T: $tr: ident)?$(,G: $trg:ident)?, $doc:literal $(cfg($attr:meta))?) => {
+    (static $mod: ident $(:$extension:ident)?, $tp:ident, $man:ident$(<$x:ident>)?, $checker:ident, $data:ty, $locker: ty, $gdw: ident,$gd:ident  $(,T: $tr: ident)?$(,G: $trg:ident)?, $doc:literal $(cfg($attr:meta))?) => {
         pub mod $mod {
             use super::*;
         impl_mut_lazy! {@proc $tp,$man$(<$x>)?,$checker,$data,$locker,$gdw,$gd$(,T:$tr)?$(,G:$trg)?,$doc $(cfg($attr))?, 'static}
         impl_mut_lazy! {@lock $tp,$data,$gdw,$gd$(,T:$tr)?$(,G:$trg)? , 'static}
         impl_mut_lazy! {@uninited $tp, $man$(<$x>)?, $data, $locker}
+            $($extension!{})?
         }
         #[doc(inline)]
         pub use $mod::$tp;
@@ -825,34 +1347,37 @@ macro_rules! impl_mut_lazy {
         #[doc(inline)]
         pub use $mod::$tp;
     };
-    (global $mod: ident, $tp:ident, $man:ident$(<$x:ident>)?, $checker:ident, $data:ty,$locker: ty,  $gdw: ident,$gd:ident$(,T: $tr: ident)?$(,G: $trg:ident)?, $doc:literal $(cfg($attr:meta))?) => {
+    (global $mod: ident $(:$extension:ident)?, $tp:ident, $man:ident$(<$x:ident>)?, $checker:ident, $data:ty,$locker: ty,  $gdw: ident,$gd:ident$(,T: $tr: ident)?$(,G: $trg:ident)?, $doc:literal $(cfg($attr:meta))?) => {
         pub mod $mod {
             use super::*;
         impl_mut_lazy! {@proc $tp,$man$(<$x>)?,$checker,$data,$locker,$gdw,$gd$(,T:$tr)?$(,G:$trg)?,$doc $(cfg($attr))?, unsafe, 'static}
         impl_mut_lazy! {@lock_global $tp,$checker,$data,$gdw,$gd$(,T:$tr)?$(,G:$trg)?}
         impl_mut_lazy! {@uninited $tp, $man$(<$x>)?, $data, $locker, unsafe}
+            $($extension!{})?
         }
         #[doc(inline)]
         pub use $mod::$tp;
     };
-    (primed_static $mod: ident, $tp:ident, $man:ident$(<$x:ident>)?, $checker:ident, $data:ty, $locker:ty, $gdw: ident, $gd: ident $(,T: $tr: ident)?$(,G: $trg:ident)?, $doc:literal $(cfg($attr:meta))?) => {
+    (primed_static $mod: ident $(:$extension:ident)?, $tp:ident, $man:ident$(<$x:ident>)?, $checker:ident, $data:ty, $locker:ty, $gdw: ident, $gd: ident $(,T: $tr: ident)?$(,G: $trg:ident)?, $doc:literal $(cfg($attr:meta))?) => {
         pub mod $mod {
             use super::*;
         impl_mut_lazy! {@proc $tp,$man$(<$x>)?,$checker,$data,$locker,$gdw,$gd$(,T:$tr)?$(,G:$trg)?,$doc $(cfg($attr))?, 'static}
         impl_mut_lazy! {@lock $tp,$data,$gdw,$gd$(,T:$tr)?$(,G:$trg)?, 'static}
         impl_mut_lazy! {@prime $tp, $man$(<$x>)?, $data, $locker}
         impl_mut_lazy! {@prime_static $tp, $checker, $data, $gdw, $gd$(,T:$tr)?$(,G:$trg)?}
+            $($extension!{})?
         }
         #[doc(inline)]
         pub use $mod::$tp;
     };
-    (global_primed_static $mod: ident, $tp:ident, $man:ident$(<$x:ident>)?, $checker:ident, $data:ty, $locker:ty, $gdw: ident, $gd: ident $(,T: $tr: ident)?$(,G: $trg:ident)?, $doc:literal $(cfg($attr:meta))?) => {
+    (global_primed_static $mod: ident $(:$extension:ident)?, $tp:ident, $man:ident$(<$x:ident>)?, $checker:ident, $data:ty, $locker:ty, $gdw: ident, $gd: ident $(,T: $tr: ident)?$(,G: $trg:ident)?, $doc:literal $(cfg($attr:meta))?) => {
         pub mod $mod {
             use super::*;
         impl_mut_lazy! {@proc $tp,$man$(<$x>)?,$checker,$data,$locker,$gdw,$gd$(,T:$tr)?$(,G:$trg)?,$doc $(cfg($attr))?, 'static}
         impl_mut_lazy! {@lock_global $tp,$checker,$data,$gdw,$gd$(,T:$tr)?$(,G:$trg)?}
         impl_mut_lazy! {@prime $tp, $man$(<$x>)?, $data, $locker}
         impl_mut_lazy! {@prime_global $tp, $checker, $data, $gdw, $gd$(,T:$tr)?$(,G:$trg)?}
+            $($extension!{})?
         }
         #[doc(inline)]
         pub use $mod::$tp;
@@ -941,7 +1466,7 @@ macro_rules! impl_mut_lazy {
                 let _ = GenericLockedLazy::init_then_write_lock(&self.__private);
             }
         }
-
+        impl_locked_lazy_access! {$tp$(,T:$tr)?$(,G:$trg)?$(,$static)?}
     };
     (@const_lock $tp:ident, $checker: ident, $data:ty, $gdw: ident, $gd:ident$(,T: $tr: ident)?$(,G: $trg:ident)? $(,$static:lifetime)?) => {
         impl<T, G> $tp<T, G>
@@ -1096,11 +1621,14 @@ macro_rules! impl_mut_lazy {
             pub fn read(&'static self) -> ReadGuard<'_,T> {
                 if inited::global_inited_hint() {
                     let l = unsafe{GenericLockedLazy::read_lock_unchecked(&self.__private)};
-                    assert!(<$checker::<G>>::initialized_is_accessible(Phased::phase(&l)));
-                    ReadGuard(l)
-                } else {
-                    ReadGuard(GenericLockedLazy::init_then_read_lock(&self.__private))
+                    let p = Phased::phase(&l);
+                    if p.intersects(Phase::INITIALIZED) {
+                        assert!(<$checker::<G>>::initialized_is_accessible(p));
+                        return ReadGuard(l)
+                    }
                 }
+                //not yet initialized or reset
+                ReadGuard(GenericLockedLazy::init_then_read_lock(&self.__private))
             }
             /// Initialize if necessary and returns some read lock if the lazy is not
             /// already write locked. If the lazy is already write locked it returns `None`
@@ -1111,14 +1639,18 @@ macro_rules! impl_mut_lazy {
             #[inline(always)]
             pub fn fast_read(&'static self) -> Option<ReadGuard<'_,T>> {
                 if inited::global_inited_hint() {
-                    let l = unsafe{GenericLockedLazy::fast_read_lock_unchecked(&self.__private)};
-                    if let Some(l) = &l {
-                        assert!(<$checker::<G>>::initialized_is_accessible(Phased::phase(l)));
+                    match unsafe{GenericLockedLazy::fast_read_lock_unchecked(&self.__private)} {
+                        Some(l) if !Phased::phase(&l).intersects(Phase::INITIALIZED) => (),
+                        l => {
+                            if let Some(l) = &l {
+                                assert!(<$checker::<G>>::initialized_is_accessible(Phased::phase(l)));
+                            }
+                            return l.map(ReadGuard)
+                        }
                     }
-                    l
-                } else {
-                    GenericLockedLazy::fast_init_then_read_lock(&self.__private)
-                }.map(ReadGuard)
+                }
+                //not yet initialized or reset
+                GenericLockedLazy::fast_init_then_read_lock(&self.__private).map(ReadGuard)
             }
             #[inline(always)]
             /// Get a read lock if the lazy is initialized or an [AccessError]
@@ -1126,7 +1658,7 @@ macro_rules! impl_mut_lazy {
                 if inited::global_inited_hint() {
                     let l = unsafe{GenericLockedLazy::read_lock_unchecked(&self.__private)};
                     let p = Phased::phase(&l);
-                    if <$checker::<G>>::initialized_is_accessible(p) {
+                    if p.intersects(Phase::INITIALIZED) && <$checker::<G>>::initialized_is_accessible(p) {
                         Ok(l)
                     } else {
                         Err(AccessError{phase:p})
@@ -1143,7 +1675,7 @@ macro_rules! impl_mut_lazy {
                     let l = unsafe{GenericLockedLazy::fast_read_lock_unchecked(&self.__private)};
                     l.map(|l| {
                         let p = Phased::phase(&l);
-                        if <$checker::<G>>::initialized_is_accessible(p) {
+                        if p.intersects(Phase::INITIALIZED) && <$checker::<G>>::initialized_is_accessible(p) {
                             Ok(l)
                         } else {
                             Err(AccessError{phase:p})
@@ -1161,13 +1693,16 @@ macro_rules! impl_mut_lazy {
             /// attempt to initialize.
             #[inline(always)]
             pub fn write(&'static self) -> WriteGuard<'_,T> {
-                WriteGuard(if inited::global_inited_hint() {
+                if inited::global_inited_hint() {
                     let l = unsafe{GenericLockedLazy::write_lock_unchecked(&self.__private)};
-                    assert!(<$checker::<G>>::initialized_is_accessible(Phased::phase(&l)));
-                    l
-                } else {
-                    GenericLockedLazy::init_then_write_lock(&self.__private)
-                })
+                    let p = Phased::phase(&l);
+                    if p.intersects(Phase::INITIALIZED) {
+                        assert!(<$checker::<G>>::initialized_is_accessible(p));
+                        return WriteGuard(l)
+                    }
+                }
+                //not yet initialized or reset
+                WriteGuard(GenericLockedLazy::init_then_write_lock(&self.__private))
             }
             /// Initialize if necessary and returns some write lock if the lazy is not
             /// already write locked. If the lazy is already read or write locked it returns `None`
@@ -1179,14 +1714,18 @@ macro_rules! impl_mut_lazy {
             #[inline(always)]
             pub fn fast_write(&'static self) -> Option<WriteGuard<'_,T>> {
                 if inited::global_inited_hint() {
-                    let l = unsafe{GenericLockedLazy::fast_write_lock_unchecked(&self.__private)};
-                    if let Some(l) = &l {
-                        assert!(<$checker::<G>>::initialized_is_accessible(Phased::phase(l)));
+                    match unsafe{GenericLockedLazy::fast_write_lock_unchecked(&self.__private)} {
+                        Some(l) if !Phased::phase(&l).intersects(Phase::INITIALIZED) => (),
+                        l => {
+                            if let Some(l) = &l {
+                                assert!(<$checker::<G>>::initialized_is_accessible(Phased::phase(l)));
+                            }
+                            return l.map(WriteGuard)
+                        }
                     }
-                    l
-                } else {
-                    GenericLockedLazy::fast_init_then_write_lock(&self.__private)
-                }.map(WriteGuard)
+                }
+                //not yet initialized or reset
+                GenericLockedLazy::fast_init_then_write_lock(&self.__private).map(WriteGuard)
             }
             /// Get a read lock if the lazy is initialized or an [AccessError]
             #[inline(always)]
@@ -1194,7 +1733,7 @@ macro_rules! impl_mut_lazy {
                 if inited::global_inited_hint() {
                     let l = unsafe{GenericLockedLazy::write_lock_unchecked(&self.__private)};
                     let p = Phased::phase(&l);
-                    if <$checker::<G>>::initialized_is_accessible(p) {
+                    if p.intersects(Phase::INITIALIZED) && <$checker::<G>>::initialized_is_accessible(p) {
                         Ok(l)
                     } else {
                         Err(AccessError{phase:p})
@@ -1211,7 +1750,7 @@ macro_rules! impl_mut_lazy {
                     let l = unsafe{GenericLockedLazy::fast_write_lock_unchecked(&self.__private)};
                     l.map(|l| {
                         let p = Phased::phase(&l);
-                        if <$checker::<G>>::initialized_is_accessible(p) {
+                        if p.intersects(Phase::INITIALIZED) && <$checker::<G>>::initialized_is_accessible(p) {
                             Ok(l)
                         } else {
                             Err(AccessError{phase:p})
@@ -1228,7 +1767,7 @@ macro_rules! impl_mut_lazy {
                 Phased::phase(&l)
             }
         }
-
+        impl_locked_lazy_access! {$tp$(,T:$tr)?$(,G:$trg)?, 'static}
     };
     (@prime_static $tp:ident,$checker:ident, $data:ty, $gdw: ident, $gd:ident$(,T: $tr: ident)?$(,G: $trg:ident)?) => {
         impl<T, G> $tp<T, G>
@@ -1466,7 +2005,7 @@ macro_rules! impl_mut_lazy {
             pub const $($safe)?  fn from_generator_with_info(f: G, info: StaticInfo) -> Self {
                 #[allow(unused_unsafe)]
                 Self {
-                    __private: unsafe{GenericLockedLazy::new_with_info(f, $man::new(<$locker>::new(Phase::empty())), <$data>::INIT,info)},
+                    __private: unsafe{GenericLockedLazy::new_with_info(f, $man::new_with_info(<$locker>::new(Phase::empty()), info), <$data>::INIT,info)},
                 }
             }
         }
@@ -1497,7 +2036,7 @@ macro_rules! impl_mut_lazy {
             pub const $($safe)?  fn from_generator_with_info(v: T, f: G, info: StaticInfo) -> Self {
                 #[allow(unused_unsafe)]
                 Self {
-                    __private: unsafe{GenericLockedLazy::new_with_info(f, $man::new(<$locker>::new(Phase::empty())), <$data>::prime(v),info)},
+                    __private: unsafe{GenericLockedLazy::new_with_info(f, $man::new_with_info(<$locker>::new(Phase::empty()), info), <$data>::prime(v),info)},
                 }
             }
         }
@@ -1518,6 +2057,21 @@ macro_rules! impl_mut_lazy {
         #[derive(Debug)]
         pub struct ReadGuard<'a,T>(generic_lazy::ReadGuard<$gd::<'a,$data>>);
 
+        #[allow(dead_code)]
+        impl<'a,T> WriteGuard<'a,T> {
+            #[inline(always)]
+            pub(crate) fn new(l: generic_lazy::WriteGuard<$gdw::<'a,$data>>) -> Self {
+                Self(l)
+            }
+        }
+        #[allow(dead_code)]
+        impl<'a,T> ReadGuard<'a,T> {
+            #[inline(always)]
+            pub(crate) fn new(l: generic_lazy::ReadGuard<$gd::<'a,$data>>) -> Self {
+                Self(l)
+            }
+        }
+
         impl<'a,T> Clone for ReadGuard<'a,T> {
             #[inline(always)]
             fn clone(&self) -> Self {
@@ -1578,6 +2132,145 @@ macro_rules! impl_mut_lazy {
             }
         }
 
+        /// A read lock on a part of the target, obtained with [ReadGuard::map],
+        /// [ReadGuard::filter_map] or [ReadGuard::try_map].
+        #[must_use="If unused the read lock is immediatly released"]
+        pub struct MappedReadGuard<'a,U>($gd::<'a,U>);
+
+        /// A write lock on a part of the target, obtained with [WriteGuard::map],
+        /// [WriteGuard::filter_map] or [WriteGuard::try_map].
+        #[must_use="If unused the write lock is immediatly released"]
+        pub struct MappedWriteGuard<'a,U>($gdw::<'a,UnsafeCell<U>>);
+
+        impl<'a,T> ReadGuard<'a,T>
+        $(where T: $static)?
+        {
+            #[inline(always)]
+            /// Make a [MappedReadGuard] on a part of the target. The read lock is kept
+            /// until the returned guard is dropped.
+            ///
+            /// This is an associated function that needs to be used as `ReadGuard::map(guard, f)`,
+            /// so that it does not conflict with a method of the target.
+            pub fn map<U>(this: Self, f: impl FnOnce(&T) -> &U) -> MappedReadGuard<'a,U> {
+                MappedReadGuard((this.0).0.map(|d| f(unsafe { &*d.get() })))
+            }
+            #[inline(always)]
+            /// Make a [MappedReadGuard] on a part of the target if `f` returns `Some`,
+            /// otherwise give back the original guard.
+            pub fn filter_map<U>(this: Self, f: impl FnOnce(&T) -> Option<&U>) -> Result<MappedReadGuard<'a,U>,Self> {
+                // the target outlives the read lock that is moved into the mapped guard
+                let target: *const T = &*this;
+                match f(unsafe { &*target }) {
+                    Some(u) => Ok(MappedReadGuard((this.0).0.map(|_| u))),
+                    None => Err(this),
+                }
+            }
+            #[inline(always)]
+            /// Make a [MappedReadGuard] on a part of the target if `f` returns `Ok`,
+            /// otherwise give back the original guard along with the error.
+            pub fn try_map<U,E>(this: Self, f: impl FnOnce(&T) -> Result<&U,E>) -> Result<MappedReadGuard<'a,U>,(Self,E)> {
+                let target: *const T = &*this;
+                match f(unsafe { &*target }) {
+                    Ok(u) => Ok(MappedReadGuard((this.0).0.map(|_| u))),
+                    Err(e) => Err((this, e)),
+                }
+            }
+        }
+
+        impl<'a,T> WriteGuard<'a,T>
+        $(where T: $static)?
+        {
+            #[inline(always)]
+            /// Make a [MappedWriteGuard] on a part of the target. The write lock is kept
+            /// until the returned guard is dropped.
+            ///
+            /// This is an associated function that needs to be used as `WriteGuard::map(guard, f)`,
+            /// so that it does not conflict with a method of the target.
+            pub fn map<U>(mut this: Self, f: impl FnOnce(&mut T) -> &mut U) -> MappedWriteGuard<'a,U> {
+                let u: *mut U = f(&mut *this);
+                MappedWriteGuard((this.0).0.map(|_| unsafe { &*(u as *const UnsafeCell<U>) }))
+            }
+            #[inline(always)]
+            /// Make a [MappedWriteGuard] on a part of the target if `f` returns `Some`,
+            /// otherwise give back the original guard.
+            pub fn filter_map<U>(mut this: Self, f: impl FnOnce(&mut T) -> Option<&mut U>) -> Result<MappedWriteGuard<'a,U>,Self> {
+                match f(&mut *this) {
+                    Some(u) => {
+                        let u: *mut U = u;
+                        Ok(MappedWriteGuard((this.0).0.map(|_| unsafe { &*(u as *const UnsafeCell<U>) })))
+                    }
+                    None => Err(this),
+                }
+            }
+            #[inline(always)]
+            /// Make a [MappedWriteGuard] on a part of the target if `f` returns `Ok`,
+            /// otherwise give back the original guard along with the error.
+            pub fn try_map<U,E>(mut this: Self, f: impl FnOnce(&mut T) -> Result<&mut U,E>) -> Result<MappedWriteGuard<'a,U>,(Self,E)> {
+                match f(&mut *this) {
+                    Ok(u) => {
+                        let u: *mut U = u;
+                        Ok(MappedWriteGuard((this.0).0.map(|_| unsafe { &*(u as *const UnsafeCell<U>) })))
+                    }
+                    Err(e) => Err((this, e)),
+                }
+            }
+        }
+
+        impl<'a,U> Clone for MappedReadGuard<'a,U> {
+            #[inline(always)]
+            fn clone(&self) -> Self {
+                Self(self.0.clone())
+            }
+        }
+        impl<'a,U> From<MappedWriteGuard<'a,U>> for MappedReadGuard<'a,U> {
+            #[inline(always)]
+            fn from(that:MappedWriteGuard<'a,U>) -> Self {
+                Self(<$gd::<'a,UnsafeCell<U>>>::from(that.0).map(|u| unsafe { &*u.get() }))
+            }
+        }
+        impl<'a,U> Deref for MappedReadGuard<'a,U> {
+            type Target = U;
+            #[inline(always)]
+            fn deref(&self) -> &U {
+                &*self.0
+            }
+        }
+        impl<'a,U> Deref for MappedWriteGuard<'a,U> {
+            type Target = U;
+            #[inline(always)]
+            fn deref(&self) -> &U {
+                unsafe { &*self.0.get() }
+            }
+        }
+        impl<'a,U> DerefMut for MappedWriteGuard<'a,U> {
+            #[inline(always)]
+            fn deref_mut(&mut self) -> &mut U {
+                unsafe { &mut *self.0.get() }
+            }
+        }
+        impl<'a,U> Phased for MappedReadGuard<'a,U> {
+            #[inline(always)]
+            fn phase(this: &Self) -> Phase {
+                Phased::phase(&this.0)
+            }
+        }
+        impl<'a,U> Phased for MappedWriteGuard<'a,U> {
+            #[inline(always)]
+            fn phase(this: &Self) -> Phase {
+                Phased::phase(&this.0)
+            }
+        }
+        impl<'a,U: Debug> Debug for MappedReadGuard<'a,U> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.debug_tuple("MappedReadGuard").field(&**self).finish()
+            }
+        }
+        impl<'a,U: Debug> Debug for MappedWriteGuard<'a,U> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.debug_tuple("MappedWriteGuard").field(&**self).finish()
+            }
+        }
+
         impl<T, G> Phased for $tp<T, G>
         where
         $(T: $static ,)?
@@ -1609,45 +2302,45 @@ macro_rules! impl_mut_lazy {
 impl_mut_lazy! {locked_lazy:extend_locked_lazy, LockedLazy,SyncSequentializer<G>,InitializedChecker,UnInited::<T>, SyncPhaseLocker, SyncPhaseGuard, SyncReadPhaseGuard,
 "A mutable locked lazy that initialize its content on the first lock"}
 
-impl_mut_lazy! {global lesser_locked_lazy, LesserLockedLazy,SyncSequentializer<G>,InitializedChecker,UnInited::<T>, SyncPhaseLocker, SyncPhaseGuard, SyncReadPhaseGuard,
+impl_mut_lazy! {global lesser_locked_lazy:extend_lesser_locked_lazy, LesserLockedLazy,SyncSequentializer<G>,InitializedChecker,UnInited::<T>, SyncPhaseLocker, SyncPhaseGuard, SyncReadPhaseGuard,
 "The actual type of mutable statics attributed with [#[dynamic]](macro@crate::dynamic) \
 \
 The method [from_generator](Self::from_generator) is unsafe because this kind of static \
 can only safely be used through this attribute macros."
 }
 
-impl_mut_lazy! {primed_static primed_locked_lazy, PrimedLockedLazy,SyncSequentializer<G>,InitializedChecker,Primed::<T>, SyncPhaseLocker, SyncPhaseGuard, SyncReadPhaseGuard,
+impl_mut_lazy! {primed_static primed_locked_lazy:extend_primed_locked_lazy, PrimedLockedLazy,SyncSequentializer<G>,InitializedChecker,Primed::<T>, SyncPhaseLocker, SyncPhaseGuard, SyncReadPhaseGuard,
 "The actual type of mutable statics attributed with [#[dynamic(primed)]](macro@crate::dynamic)"}
 
-impl_mut_lazy! {global_primed_static primed_lesser_locked_lazy, PrimedLesserLockedLazy,SyncSequentializer<G>,InitializedChecker,Primed::<T>, SyncPhaseLocker, SyncPhaseGuard, SyncReadPhaseGuard,
+impl_mut_lazy! {global_primed_static primed_lesser_locked_lazy:extend_primed_lesser_locked_lazy, PrimedLesserLockedLazy,SyncSequentializer<G>,InitializedChecker,Primed::<T>, SyncPhaseLocker, SyncPhaseGuard, SyncReadPhaseGuard,
 "The actual type of mutable statics attributed with [#[dynamic(primed)]](macro@crate::dynamic)"}
 
-impl_mut_lazy! {static locked_lazy_finalize,LockedLazyFinalize,ExitSequentializer<G>,InitializedSoftFinalizedChecker,UnInited::<T>,SyncPhaseLocker, SyncPhaseGuard, SyncReadPhaseGuard, T:Finaly,G:Sync,
+impl_mut_lazy! {static locked_lazy_finalize:extend_locked_lazy_finalize,LockedLazyFinalize,ExitSequentializer<G>,InitializedSoftFinalizedChecker,UnInited::<T>,SyncPhaseLocker, SyncPhaseGuard, SyncReadPhaseGuard, T:Finaly,G:Sync,
 "The actual type of mutable statics attributed with [#[dynamic(lazy,finalize)]](macro@crate::dynamic)"
 }
 
-impl_mut_lazy! {global lesser_locked_lazy_finalize,LesserLockedLazyFinalize,ExitSequentializer<G>,InitializedSoftFinalizedCheckerLesser,UnInited::<T>,SyncPhaseLocker, SyncPhaseGuard, SyncReadPhaseGuard,T:Finaly, G:Sync,
+impl_mut_lazy! {global lesser_locked_lazy_finalize:extend_lesser_locked_lazy_finalize,LesserLockedLazyFinalize,ExitSequentializer<G>,InitializedSoftFinalizedCheckerLesser,UnInited::<T>,SyncPhaseLocker, SyncPhaseGuard, SyncReadPhaseGuard,T:Finaly, G:Sync,
 "The actual type of mutable statics attributed with [#[dynamic(finalize)]](macro@crate::dynamic) \
 \
 The method [from_generator](Self::from_generator) is unsafe because this kind of static \
 can only safely be used through this attribute macros."
 }
-impl_mut_lazy! {static locked_lazy_droped,LockedLazyDroped,ExitSequentializer<G>,InitializedHardFinalizedChecker,DropedUnInited::<T>, SyncPhaseLocker, SyncPhaseGuard, SyncReadPhaseGuard,G:Sync,
+impl_mut_lazy! {static locked_lazy_droped:extend_locked_lazy_droped,LockedLazyDroped,ExitSequentializer<G>,InitializedHardFinalizedChecker,DropedUnInited::<T>, SyncPhaseLocker, SyncPhaseGuard, SyncReadPhaseGuard,G:Sync,
 "The actual type of statics attributed with [#[dynamic(lazy,finalize)]](macro@crate::dynamic)"
 }
 
-impl_mut_lazy! {global lesser_locked_lazy_droped,LesserLockedLazyDroped,ExitSequentializer<G>,InitializedHardFinalizedCheckerLesser,DropedUnInited::<T>, SyncPhaseLocker, SyncPhaseGuard, SyncReadPhaseGuard,G:Sync,
+impl_mut_lazy! {global lesser_locked_lazy_droped:extend_lesser_locked_lazy_droped,LesserLockedLazyDroped,ExitSequentializer<G>,InitializedHardFinalizedCheckerLesser,DropedUnInited::<T>, SyncPhaseLocker, SyncPhaseGuard, SyncReadPhaseGuard,G:Sync,
 "The actual type of mutable statics attributed with #[dynamic(drop)] \
 \
 The method (new)[Self::from_generator] is unsafe because this kind of static \
 can only safely be used through this attribute macros."
 }
 
-impl_mut_lazy! {primed_static primed_locked_lazy_droped,PrimedLockedLazyDroped,ExitSequentializer<G>,InitializedHardFinalizedChecker,Primed::<T>, SyncPhaseLocker, SyncPhaseGuard, SyncReadPhaseGuard,T:Uninit, G:Sync,
+impl_mut_lazy! {primed_static primed_locked_lazy_droped:extend_primed_locked_lazy_droped,PrimedLockedLazyDroped,ExitSequentializer<G>,InitializedHardFinalizedChecker,Primed::<T>, SyncPhaseLocker, SyncPhaseGuard, SyncReadPhaseGuard,T:Uninit, G:Sync,
 "The actual type of mutable statics attributed with [#[dynamic(primed,drop)]](macro@crate::dynamic)"
 }
 
-impl_mut_lazy! {global_primed_static global_primed_locked_lazy_droped,PrimedLesserLockedLazyDroped,ExitSequentializer<G>,InitializedHardFinalizedChecker,Primed::<T>, SyncPhaseLocker, SyncPhaseGuard, SyncReadPhaseGuard,T:Uninit, G:Sync,
+impl_mut_lazy! {global_primed_static global_primed_locked_lazy_droped:extend_primed_lesser_locked_lazy_droped,PrimedLesserLockedLazyDroped,ExitSequentializer<G>,InitializedHardFinalizedChecker,Primed::<T>, SyncPhaseLocker, SyncPhaseGuard, SyncReadPhaseGuard,T:Uninit, G:Sync,
 "The actual type of mutable statics attributed with [#[dynamic(primed,drop)]](macro@crate::dynamic)"
 }
 
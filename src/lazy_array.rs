@@ -0,0 +1,250 @@
+use crate::generic_lazy::{AccessError, GenericLazy, GenericLockedLazy, LazyData, UnInited};
+use crate::lazy::locked_lazy::{ReadGuard, WriteGuard};
+use crate::lazy::InitializedChecker;
+use crate::lazy_sequentializer::SyncSequentializer;
+use crate::phase_locker::SyncPhaseLocker;
+use crate::{Phase, Phased};
+
+/// The entries are initialized by the generator of their owner, and as for a [Lazy](crate::Lazy)
+/// whose generator is a closure, initialization is attempted again after a panic.
+pub(crate) type Tolerance<V> = fn() -> V;
+
+type EntryLazy<V, Tol> =
+    GenericLazy<UnInited<V>, (), SyncSequentializer<Tol>, InitializedChecker<Tol>>;
+
+/// A lazy whose value is generated by its owner, and that drops its value
+///
+/// `Tol` is the [GeneratorTolerance](crate::GeneratorTolerance) of the generator of the owner.
+pub(crate) struct Entry<V, Tol = Tolerance<V>>(pub(crate) EntryLazy<V, Tol>);
+
+impl<V, Tol> Entry<V, Tol> {
+    #[allow(clippy::declare_interior_mutable_const)]
+    pub(crate) const INIT: Self = Self(unsafe {
+        GenericLazy::new(
+            (),
+            SyncSequentializer::new(SyncPhaseLocker::new(Phase::empty())),
+            UnInited::INIT,
+        )
+    });
+}
+
+impl<V, Tol> Drop for Entry<V, Tol> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        if Phased::phase(GenericLazy::sequentializer(&self.0)).intersects(Phase::INITIALIZED) {
+            unsafe { GenericLazy::get_raw_data(&self.0).get().drop_in_place() }
+        }
+    }
+}
+
+/// An array of lazies, each one initialized independently by the generator of the
+/// array called with the index of the element.
+///
+/// Each element has its own phase and lock, so that elements can be initialized
+/// concurrently. The array can be declared as a static:
+///
+/// ```
+/// use static_init::LazyArray;
+///
+/// static SHARDS: LazyArray<Vec<usize>, 4> = LazyArray::new(|i| vec![i; 1024]);
+///
+/// assert!(SHARDS.try_get(1).is_err());
+/// assert_eq!(SHARDS.get(1)[0], 1);
+/// assert!(SHARDS.try_get(1).is_ok());
+/// assert!(SHARDS.try_get(2).is_err());
+/// ```
+///
+/// Initialized elements are dropped with the array. A static declared with
+/// [#[dynamic(lazy,drop)]](macro@crate::dynamic) is registered once for finalization at
+/// program exit, and all its initialized elements are then dropped together.
+///
+/// If the generator panics, the element stays uninitialized and initialization is
+/// attempted again on the next access.
+pub struct LazyArray<T, const N: usize, G = fn(usize) -> T> {
+    generator: G,
+    slots: [Entry<T>; N],
+}
+
+impl<T, const N: usize, G> LazyArray<T, N, G> {
+    #[allow(clippy::declare_interior_mutable_const)]
+    const SLOT: Entry<T> = Entry::INIT;
+
+    /// Build an array whose elements will be initialized by `generator`.
+    #[inline(always)]
+    pub const fn new(generator: G) -> Self {
+        Self {
+            generator,
+            slots: [Self::SLOT; N],
+        }
+    }
+}
+
+impl<T, const N: usize, G: Fn(usize) -> T> LazyArray<T, N, G> {
+    /// Initialize if necessary the element `i` and returns a reference to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds or if the generator panics.
+    #[inline]
+    pub fn get(&self, i: usize) -> &T {
+        match self.slots[i]
+            .0
+            .init_with_then_try_get(|| (self.generator)(i))
+        {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a reference to the element `i` if it is initialized, otherwise an
+    /// [AccessError] holding the phase of the element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    #[inline]
+    pub fn try_get(&self, i: usize) -> Result<&T, AccessError> {
+        self.slots[i].0.try_get()
+    }
+
+    /// Returns the phase of the element `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    #[inline]
+    pub fn phase(&self, i: usize) -> Phase {
+        Phased::phase(GenericLazy::sequentializer(&self.slots[i].0))
+    }
+}
+
+type LockedSlotLazy<T> = GenericLockedLazy<
+    UnInited<T>,
+    (),
+    SyncSequentializer<Tolerance<T>>,
+    InitializedChecker<Tolerance<T>>,
+>;
+
+/// A locked lazy whose value is generated by its owner, and that drops its value
+struct LockedSlot<T>(LockedSlotLazy<T>);
+
+impl<T> LockedSlot<T> {
+    #[allow(clippy::declare_interior_mutable_const)]
+    const INIT: Self = Self(unsafe {
+        GenericLockedLazy::new(
+            (),
+            SyncSequentializer::new(SyncPhaseLocker::new(Phase::empty())),
+            UnInited::INIT,
+        )
+    });
+}
+
+impl<T> Drop for LockedSlot<T> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        if Phased::phase(GenericLockedLazy::sequentializer(&self.0)).intersects(Phase::INITIALIZED)
+        {
+            unsafe { (*self.0).get().drop_in_place() }
+        }
+    }
+}
+
+/// An array of locked lazies, each one initialized independently by the generator of the
+/// array called with the index of the element.
+///
+/// Each element has its own phase and read-write lock: elements are accessed through
+/// the guards returned by [read](Self::read) and [write](Self::write).
+///
+/// ```
+/// use static_init::LockedLazyArray;
+///
+/// static COUNTERS: LockedLazyArray<u64, 8> = LockedLazyArray::new(|_| 0);
+///
+/// *COUNTERS.write(3) += 1;
+/// assert_eq!(*COUNTERS.read(3), 1);
+/// assert!(COUNTERS.try_read(4).is_err());
+/// ```
+///
+/// As for [LazyArray], initialized elements are dropped with the array.
+pub struct LockedLazyArray<T, const N: usize, G = fn(usize) -> T> {
+    generator: G,
+    slots: [LockedSlot<T>; N],
+}
+
+impl<T, const N: usize, G> LockedLazyArray<T, N, G> {
+    #[allow(clippy::declare_interior_mutable_const)]
+    const SLOT: LockedSlot<T> = LockedSlot::INIT;
+
+    /// Build an array whose elements will be initialized by `generator`.
+    #[inline(always)]
+    pub const fn new(generator: G) -> Self {
+        Self {
+            generator,
+            slots: [Self::SLOT; N],
+        }
+    }
+}
+
+impl<T: Send, const N: usize, G: Fn(usize) -> T> LockedLazyArray<T, N, G> {
+    /// Initialize if necessary the element `i` and returns a read lock on it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds or if the generator panics.
+    #[inline]
+    pub fn read(&self, i: usize) -> ReadGuard<'_, T> {
+        match GenericLockedLazy::init_with_then_try_read_lock(&self.slots[i].0, || {
+            (self.generator)(i)
+        }) {
+            Ok(l) => ReadGuard::new(l),
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Initialize if necessary the element `i` and returns a write lock on it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds or if the generator panics.
+    #[inline]
+    pub fn write(&self, i: usize) -> WriteGuard<'_, T> {
+        match GenericLockedLazy::init_with_then_try_write_lock(&self.slots[i].0, || {
+            (self.generator)(i)
+        }) {
+            Ok(l) => WriteGuard::new(l),
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a read lock on the element `i` if it is initialized, otherwise an
+    /// [AccessError] holding the phase of the element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    #[inline]
+    pub fn try_read(&self, i: usize) -> Result<ReadGuard<'_, T>, AccessError> {
+        GenericLockedLazy::try_read_lock(&self.slots[i].0).map(ReadGuard::new)
+    }
+
+    /// Returns a write lock on the element `i` if it is initialized, otherwise an
+    /// [AccessError] holding the phase of the element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    #[inline]
+    pub fn try_write(&self, i: usize) -> Result<WriteGuard<'_, T>, AccessError> {
+        GenericLockedLazy::try_write_lock(&self.slots[i].0).map(WriteGuard::new)
+    }
+
+    /// Returns the phase of the element `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    #[inline]
+    pub fn phase(&self, i: usize) -> Phase {
+        Phased::phase(GenericLockedLazy::sequentializer(&self.slots[i].0))
+    }
+}
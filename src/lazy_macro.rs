@@ -0,0 +1,155 @@
+use crate::{FinalyMode, InitMode, Lazy, LockedLazy, StaticInfo};
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use core::any::{Any, TypeId};
+
+type Entries = BTreeMap<TypeId, &'static (dyn Any + Send + Sync)>;
+
+#[doc(hidden)]
+/// The lazies of a call site of the [lazy!](crate::lazy) or [locked_lazy!](crate::locked_lazy)
+/// macros, one per instantiation of the function that evaluates the macro.
+pub struct __CallSiteLazies(LockedLazy<Entries, fn() -> Entries>);
+
+impl __CallSiteLazies {
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self(LockedLazy::from_generator(BTreeMap::new as fn() -> Entries))
+    }
+
+    fn entry<L: Any + Send + Sync>(&'static self, new: impl FnOnce() -> L) -> &'static L {
+        let id = TypeId::of::<L>();
+        let found = self.0.read().get(&id).copied();
+        let entry = match found {
+            Some(entry) => entry,
+            None => *self
+                .0
+                .write()
+                .entry(id)
+                .or_insert_with(|| Box::leak(Box::new(new()))),
+        };
+        match entry.downcast_ref() {
+            Some(l) => l,
+            None => unreachable!(),
+        }
+    }
+
+    /// The type of the generator is different for each instantiation of the generic
+    /// function that evaluates the macro, so that each one gets its own lazy.
+    #[inline]
+    pub fn lazy<T, G>(&'static self, generator: G, info: StaticInfo) -> &'static Lazy<T, G>
+    where
+        T: Any + Send + Sync,
+        G: Fn() -> T + Any + Send + Sync,
+    {
+        self.entry(|| Lazy::from_generator_with_info(generator, info))
+    }
+
+    #[inline]
+    pub fn locked_lazy<T, G>(
+        &'static self,
+        generator: G,
+        info: StaticInfo,
+    ) -> &'static LockedLazy<T, G>
+    where
+        T: Any + Send,
+        G: Fn() -> T + Any + Send + Sync,
+    {
+        self.entry(|| LockedLazy::from_generator_with_info(generator, info))
+    }
+}
+
+impl Default for __CallSiteLazies {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[doc(hidden)]
+pub const fn __call_site_info(
+    variable_name: &'static str,
+    file_name: &'static str,
+    line: u32,
+    column: u32,
+) -> StaticInfo {
+    StaticInfo {
+        variable_name,
+        file_name,
+        line,
+        column,
+        init_mode: InitMode::Lazy,
+        drop_mode: FinalyMode::None,
+    }
+}
+
+/// Returns a `&'static T` to a value lazily initialized by the expression, the first time the
+/// macro is evaluated.
+///
+/// This is the expression counterpart of a static attributed with
+/// [#[dynamic(lazy)]](macro@crate::dynamic), that can be used where an item is not
+/// welcome, as in a method of a trait implementation. The expression can not capture local
+/// variables.
+///
+/// Inside a generic function, one value is created per instantiation of the function. So the
+/// expression can depend on the generic parameters of the function, which a static can not:
+///
+/// ```
+/// use static_init::lazy;
+///
+/// fn type_name_len<T>() -> &'static usize {
+///     lazy!(std::any::type_name::<T>().len())
+/// }
+///
+/// let v: &'static Vec<i32> = lazy!(vec![1, 2, 3]);
+/// assert_eq!(v.len(), 3);
+///
+/// assert_eq!(*type_name_len::<u8>(), 2);
+/// assert_eq!(*type_name_len::<u16>(), 3);
+/// assert!(core::ptr::eq(type_name_len::<u8>(), type_name_len::<u8>()));
+/// ```
+///
+/// Values are never dropped. The type of the value must be `'static`, [Send] and [Sync],
+/// and each evaluation of the macro costs a look up among the lazies of the call site.
+#[macro_export]
+macro_rules! lazy {
+    ($e:expr) => {{
+        static __STATIC_INIT_LAZIES: $crate::__CallSiteLazies = $crate::__CallSiteLazies::new();
+        &**__STATIC_INIT_LAZIES.lazy(
+            || $e,
+            $crate::__call_site_info("lazy!", file!(), line!(), column!()),
+        )
+    }};
+}
+
+/// Returns a `&'static` [LockedLazy] whose value is initialized by the expression.
+///
+/// This is the expression counterpart of a mutable static attributed with
+/// [#[dynamic(lazy)]](macro@crate::dynamic): the value is accessed through the guards returned
+/// by the `read` and `write` methods. As with [lazy!](crate::lazy), inside a generic function
+/// one locked lazy is created per instantiation of the function:
+///
+/// ```
+/// use static_init::locked_lazy;
+///
+/// fn count<T>() -> usize {
+///     let mut count = locked_lazy!(0).write();
+///     *count += 1;
+///     *count
+/// }
+///
+/// assert_eq!(count::<u8>(), 1);
+/// assert_eq!(count::<u8>(), 2);
+/// assert_eq!(count::<u16>(), 1);
+/// ```
+///
+/// Values are never dropped. The type of the value must be `'static` and [Send].
+#[macro_export]
+macro_rules! locked_lazy {
+    ($e:expr) => {{
+        static __STATIC_INIT_LAZIES: $crate::__CallSiteLazies = $crate::__CallSiteLazies::new();
+        __STATIC_INIT_LAZIES.locked_lazy(
+            || $e,
+            $crate::__call_site_info("locked_lazy!", file!(), line!(), column!()),
+        )
+    }};
+}
@@ -0,0 +1,272 @@
+use crate::generic_lazy::{AccessError, GenericLazy};
+use crate::lazy_array::Entry;
+use crate::{LockedLazy, Phase, Phased};
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+
+#[cfg(any(elf, mach_o, coff))]
+use crate::exit_sequentializer::ExitSequentializer;
+#[cfg(any(elf, mach_o, coff))]
+use crate::generic_lazy::UnInited;
+#[cfg(any(elf, mach_o, coff))]
+use crate::lazy::InitializedSoftFinalizedChecker;
+#[cfg(any(elf, mach_o, coff))]
+use crate::lazy_array::Tolerance;
+#[cfg(any(elf, mach_o, coff))]
+use crate::phase_locker::SyncPhaseLocker;
+#[cfg(any(elf, mach_o, coff))]
+use crate::Finaly;
+
+/// A boxed entry, so that references to it stay valid when the map is modified
+struct EntryPtr<V>(*mut Entry<V>);
+
+impl<V> Clone for EntryPtr<V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<V> Copy for EntryPtr<V> {}
+
+// SAFETY: the pointer is owned by the map
+unsafe impl<V: Send> Send for EntryPtr<V> {}
+
+type Entries<K, V> = BTreeMap<K, EntryPtr<V>>;
+
+type EntriesGenerator<K, V> = fn() -> Entries<K, V>;
+
+/// A map whose values are lazily initialized, one per key, by the generator of the map.
+///
+/// Each entry is a lazy with its own phase and lock: distinct keys can be initialized
+/// concurrently, and the initialization of one key does not block access to the
+/// others. Only the insertion of a new entry takes a short write lock on the map.
+///
+/// ```
+/// use static_init::LazyMap;
+///
+/// struct Client {
+///     tenant: String,
+/// }
+///
+/// static CLIENTS: LazyMap<String, Client> = LazyMap::new(|tenant: &String| Client {
+///     tenant: tenant.clone(),
+/// });
+///
+/// let acme = String::from("acme");
+/// assert!(CLIENTS.try_get(&acme).is_err());
+/// assert_eq!(CLIENTS.get(&acme).tenant, "acme");
+/// assert!(CLIENTS.try_get(&acme).is_ok());
+/// ```
+///
+/// Entries are never removed from the map. If the generator panics, the entry stays
+/// uninitialized and initialization is attempted again on the next access.
+pub struct LazyMap<K, V, G = fn(&K) -> V> {
+    generator: G,
+    entries: LockedLazy<Entries<K, V>, EntriesGenerator<K, V>>,
+}
+
+// SAFETY: references to the values and to the keys are shared between threads,
+// values may be initialized by any thread
+unsafe impl<K: Send + Sync, V: Send + Sync, G: Sync> Sync for LazyMap<K, V, G> {}
+
+impl<K, V, G> LazyMap<K, V, G> {
+    /// Build a map whose values will be initialized by `generator`.
+    #[inline(always)]
+    pub const fn new(generator: G) -> Self {
+        Self {
+            generator,
+            entries: LockedLazy::from_generator(BTreeMap::new as EntriesGenerator<K, V>),
+        }
+    }
+}
+
+impl<K, V, G> Drop for LazyMap<K, V, G> {
+    fn drop(&mut self) {
+        for entry in self.entries.write().values() {
+            // SAFETY: entries are only freed here
+            drop(unsafe { Box::from_raw(entry.0) })
+        }
+    }
+}
+
+impl<K: Ord + Clone, V, G: Fn(&K) -> V> LazyMap<K, V, G> {
+    fn entry(&self, key: &K) -> &Entry<V> {
+        let found = self.entries.read().get(key).copied();
+        let entry = match found {
+            Some(entry) => entry,
+            None => *self
+                .entries
+                .write()
+                .entry(key.clone())
+                .or_insert_with(|| EntryPtr(Box::into_raw(Box::new(Entry::INIT)))),
+        };
+        // SAFETY: entries are freed only when the map is dropped
+        unsafe { &*entry.0 }
+    }
+
+    /// Initialize if necessary the value associated to `key` and returns a reference to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generator panics.
+    #[inline]
+    pub fn get(&self, key: &K) -> &V {
+        match self
+            .entry(key)
+            .0
+            .init_with_then_try_get(|| (self.generator)(key))
+        {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a reference to the value associated to `key` if it is initialized,
+    /// otherwise an [AccessError] holding the phase of the entry.
+    #[inline]
+    pub fn try_get(&self, key: &K) -> Result<&V, AccessError> {
+        let found = self.entries.read().get(key).copied();
+        match found {
+            // SAFETY: entries are freed only when the map is dropped
+            Some(entry) => unsafe { &*entry.0 }.0.try_get(),
+            None => Err(AccessError {
+                phase: Phase::empty(),
+            }),
+        }
+    }
+
+    /// Returns the phase of the entry of `key`, empty if there is no such entry.
+    #[inline]
+    pub fn phase(&self, key: &K) -> Phase {
+        self.entries.read().get(key).map_or(Phase::empty(), |e| {
+            // SAFETY: entries are freed only when the map is dropped
+            Phased::phase(GenericLazy::sequentializer(unsafe { &(*e.0).0 }))
+        })
+    }
+
+    /// The number of entries, initialized or not.
+    pub fn len(&self) -> usize {
+        self.entries.read().len()
+    }
+
+    /// Returns true if the map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(any(elf, mach_o, coff))]
+type FinalizedEntry<V> = GenericLazy<
+    UnInited<V>,
+    (),
+    ExitSequentializer<Tolerance<V>>,
+    InitializedSoftFinalizedChecker<Tolerance<V>>,
+>;
+
+#[cfg(any(elf, mach_o, coff))]
+type FinalizedEntries<K, V> = BTreeMap<K, &'static FinalizedEntry<V>>;
+
+#[cfg(any(elf, mach_o, coff))]
+type FinalizedEntriesGenerator<K, V> = fn() -> FinalizedEntries<K, V>;
+
+#[cfg(any(elf, mach_o, coff))]
+/// A [LazyMap] whose values are finalized at program exit.
+///
+/// Each value is registered for finalization when it is initialized, and [Finaly::finaly]
+/// is called on it at program exit, as for a static declared with
+/// [#[dynamic(lazy,finalize)]](macro@crate::dynamic). Values are finalized in the reverse
+/// order of their initialization and stay accessible after their finalization.
+///
+/// The map must be a static.
+pub struct LazyMapFinalize<K: 'static, V: 'static, G = fn(&K) -> V> {
+    generator: G,
+    entries: LockedLazy<FinalizedEntries<K, V>, FinalizedEntriesGenerator<K, V>>,
+}
+
+#[cfg(any(elf, mach_o, coff))]
+// SAFETY: references to the values and to the keys are shared between threads,
+// values may be initialized and finalized by any thread
+unsafe impl<K: Send + Sync, V: Send + Sync, G: Sync> Sync for LazyMapFinalize<K, V, G> {}
+
+#[cfg(any(elf, mach_o, coff))]
+impl<K, V, G> LazyMapFinalize<K, V, G> {
+    /// Build a map whose values will be initialized by `generator`.
+    #[inline(always)]
+    pub const fn new(generator: G) -> Self {
+        Self {
+            generator,
+            entries: LockedLazy::from_generator(BTreeMap::new as FinalizedEntriesGenerator<K, V>),
+        }
+    }
+}
+
+#[cfg(any(elf, mach_o, coff))]
+impl<K: Ord + Clone, V: Finaly + Sync, G: Fn(&K) -> V> LazyMapFinalize<K, V, G> {
+    fn entry(&'static self, key: &K) -> &'static FinalizedEntry<V> {
+        let found = self.entries.read().get(key).copied();
+        match found {
+            Some(entry) => entry,
+            None => {
+                let mut entries = self.entries.write();
+                let entry = entries.entry(key.clone()).or_insert_with(|| {
+                    Box::leak(Box::new(unsafe {
+                        GenericLazy::new(
+                            (),
+                            ExitSequentializer::new(SyncPhaseLocker::new(Phase::empty())),
+                            UnInited::INIT,
+                        )
+                    }))
+                });
+                entry
+            }
+        }
+    }
+
+    /// Initialize if necessary the value associated to `key` and returns a reference to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generator panics or if the value is accessed for the first time
+    /// while the program is exiting.
+    #[inline]
+    pub fn get(&'static self, key: &K) -> &'static V {
+        match self
+            .entry(key)
+            .init_with_then_try_get(|| (self.generator)(key))
+        {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a reference to the value associated to `key` if it is initialized,
+    /// otherwise an [AccessError] holding the phase of the entry.
+    #[inline]
+    pub fn try_get(&'static self, key: &K) -> Result<&'static V, AccessError> {
+        let found = self.entries.read().get(key).copied();
+        match found {
+            Some(entry) => entry.try_get(),
+            None => Err(AccessError {
+                phase: Phase::empty(),
+            }),
+        }
+    }
+
+    /// Returns the phase of the entry of `key`, empty if there is no such entry.
+    #[inline]
+    pub fn phase(&'static self, key: &K) -> Phase {
+        self.entries.read().get(key).map_or(Phase::empty(), |e| {
+            Phased::phase(GenericLazy::sequentializer(*e))
+        })
+    }
+
+    /// The number of entries, initialized or not.
+    pub fn len(&'static self) -> usize {
+        self.entries.read().len()
+    }
+
+    /// Returns true if the map has no entries.
+    pub fn is_empty(&'static self) -> bool {
+        self.len() == 0
+    }
+}
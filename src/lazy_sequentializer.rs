@@ -24,6 +24,25 @@ fn lazy_initialization_only<'a, T: 'a, P: PhaseGuard<'a, T>>(
     phase_guard
 }
 
+#[inline]
+#[cold]
+fn lazy_fallible_initialization_only<'a, T: 'a, P: PhaseGuard<'a, T>>(
+    mut phase_guard: P,
+    init: impl FnOnce(&'a T) -> bool,
+) -> P {
+    let cur = Phase::empty();
+
+    let initialized = cur | Phase::INITIALIZED;
+
+    let initialization_panic = cur | Phase::INITIALIZATION_PANICKED | Phase::INITIALIZATION_SKIPED;
+
+    if !phase_guard.transition(init, initialized, initialization_panic) {
+        phase_guard.set_phase(cur | Phase::INITIALIZATION_SKIPED);
+    }
+
+    phase_guard
+}
+
 #[inline]
 #[cold]
 fn mut_lazy_initialization_only<P: MutPhaseLocker>(locker: &mut P, init: impl FnOnce()) {
@@ -72,6 +91,8 @@ where
             registration_finished = Phase::REGISTERED;
         } else {
             registration_finished = Phase::REGISTRATION_REFUSED;
+            #[cfg(feature = "tracing")]
+            crate::trace::registration_refused();
         }
     } else {
         registration_finished = cur;
@@ -137,23 +158,35 @@ fn lazy_finalization<'a, T: 'a, P: PhaseGuard<'a, T>>(mut phase_guard: P, f: imp
 
 mod generic {
     use super::{
-        lazy_finalization, lazy_initialization, lazy_initialization_only,
-        mut_lazy_initialization_only, Unit,
+        lazy_fallible_initialization_only, lazy_finalization, lazy_initialization,
+        lazy_initialization_only, mut_lazy_initialization_only, Unit,
     };
     use crate::phase_locker::{
-        LockNature, LockResult, Mappable, MutPhaseLocker, PhaseGuard, PhaseLocker,
+        LockNature, LockResult, Mappable, MutPhaseLocker, PhaseGuard, PhaseLocker, SyncPhaseLocker,
+        SyncUpgradableReadPhaseGuard,
     };
     use crate::{
-        FinalizableLazySequentializer, GeneratorTolerance,
+        FallibleLazySequentializer, FinalizableLazySequentializer, GeneratorTolerance,
         LazySequentializer as LazySequentializerTrait, Phase, Phased, Sequential, Sequentializer,
-        UniqueLazySequentializer,
+        StaticInfo, UniqueLazySequentializer, UpgradableSequentializer,
+    };
+
+    #[cfg(feature = "timeout")]
+    use crate::{
+        phase_locker::{SyncPhaseGuard, SyncReadPhaseGuard},
+        TimedLazySequentializer,
     };
+    #[cfg(any(feature = "timeout", feature = "stats"))]
+    use std::time::Instant;
 
-    #[cfg(debug_mode)]
-    use crate::CyclicPanic;
+    #[cfg(feature = "tracing")]
+    use crate::trace::{FinalizeTrace, InitTrace, WaitTrace};
+
+    #[cfg(any(debug_mode, feature = "cycle_detect"))]
+    use crate::cycle::{thread_id, InitFrame};
     use core::hint::unreachable_unchecked;
     use core::marker::PhantomData;
-    #[cfg(debug_mode)]
+    #[cfg(any(debug_mode, feature = "cycle_detect"))]
     use core::sync::atomic::{AtomicUsize, Ordering};
 
     /// Ensure sequentialization.
@@ -222,7 +255,7 @@ mod generic {
     pub struct LazySequentializer<Locker, G>(
         Locker,
         PhantomData<G>,
-        #[cfg(debug_mode)] AtomicUsize,
+        #[cfg(any(debug_mode, feature = "cycle_detect"))] AtomicUsize,
     );
 
     impl<L, G> Phased for LazySequentializer<L, G>
@@ -240,10 +273,76 @@ mod generic {
             Self(
                 locker,
                 PhantomData,
-                #[cfg(debug_mode)]
+                #[cfg(any(debug_mode, feature = "cycle_detect"))]
                 AtomicUsize::new(0),
             )
         }
+        #[inline(always)]
+        /// Same as [new](Self::new): this sequentializer does not keep
+        /// the informations about the static
+        pub const fn new_with_info(locker: L, _info: StaticInfo) -> Self {
+            Self::new(locker)
+        }
+    }
+
+    impl<L, G: GeneratorTolerance> LazySequentializer<L, G> {
+        #[inline(always)]
+        /// Lock the phase through the locker.
+        ///
+        /// If the generator has a [statistics](GeneratorTolerance::STATISTICS) block, the
+        /// lock attempts that can not be satisfied immediately are timed and recorded in it.
+        fn lock_phase<'a, D: 'a>(
+            &'a self,
+            data: &'a D,
+            lock_nature: impl Fn(Phase) -> LockNature,
+            on_wake_nature: impl Fn(Phase) -> LockNature,
+            hint: Phase,
+        ) -> LockResult<L::ReadGuard, L::WriteGuard>
+        where
+            L: PhaseLocker<'a, D>,
+        {
+            #[cfg(feature = "stats")]
+            if let Some(statistics) = G::STATISTICS {
+                if let Some(l) = self.0.try_lock(data, &lock_nature, hint) {
+                    return l;
+                }
+                let start = Instant::now();
+                let l = self.0.lock(data, lock_nature, on_wake_nature, hint);
+                statistics().record(Some(&l), start.elapsed());
+                return l;
+            }
+            self.0.lock(data, lock_nature, on_wake_nature, hint)
+        }
+    }
+
+    #[cfg(feature = "timeout")]
+    impl<G: GeneratorTolerance> LazySequentializer<SyncPhaseLocker, G> {
+        #[inline(always)]
+        /// Similar to [lock_phase](Self::lock_phase) but gives up if the lock could not
+        /// be obtained before `deadline`.
+        fn lock_phase_until<'a, D: 'a>(
+            &'a self,
+            data: &'a D,
+            lock_nature: impl Fn(Phase) -> LockNature,
+            on_wake_nature: impl Fn(Phase) -> LockNature,
+            hint: Phase,
+            deadline: Instant,
+        ) -> Result<LockResult<SyncReadPhaseGuard<'a, D>, SyncPhaseGuard<'a, D>>, Phase> {
+            #[cfg(feature = "stats")]
+            if let Some(statistics) = G::STATISTICS {
+                if let Some(l) = self.0.try_lock(data, &lock_nature, hint) {
+                    return Ok(l);
+                }
+                let start = Instant::now();
+                let l = self
+                    .0
+                    .lock_until(data, lock_nature, on_wake_nature, hint, deadline);
+                statistics().record(l.as_ref().ok(), start.elapsed());
+                return l;
+            }
+            self.0
+                .lock_until(data, lock_nature, on_wake_nature, hint, deadline)
+        }
     }
 
     // SAFETY: it is safe because it does implement synchronized locks
@@ -268,7 +367,13 @@ mod generic {
 
             let data = Sequential::data(s);
 
-            this.0.lock(data, &lock_nature, &lock_nature, hint)
+            let wait = trace_wait(s);
+            wait.report(this.lock_phase(
+                data,
+                &lock_nature,
+                wait.on_wait(describe_wait(s, &lock_nature)),
+                hint,
+            ))
         }
 
         #[inline(always)]
@@ -292,8 +397,186 @@ mod generic {
         }
     }
 
+    // SAFETY: it is safe because it does implement synchronized locks
+    unsafe impl<'a, T: Sequential + 'a, G: 'a + GeneratorTolerance> UpgradableSequentializer<'a, T>
+        for LazySequentializer<SyncPhaseLocker, G>
+    where
+        T::Sequentializer: AsRef<LazySequentializer<SyncPhaseLocker, G>>,
+        T::Sequentializer: AsMut<LazySequentializer<SyncPhaseLocker, G>>,
+    {
+        type UpgradableReadGuard = SyncUpgradableReadPhaseGuard<'a, T::Data>;
+
+        #[inline(always)]
+        fn upgradable_lock(s: &'a T) -> Self::UpgradableReadGuard {
+            let this = Sequential::sequentializer(s).as_ref();
+
+            let data = Sequential::data(s);
+
+            this.0.upgradable_lock(data)
+        }
+    }
+
+    // SAFETY: it is safe because it does implement synchronized locks
+    #[cfg(feature = "timeout")]
+    unsafe impl<'a, T: Sequential + 'a, G: 'a + GeneratorTolerance> TimedLazySequentializer<'a, T>
+        for LazySequentializer<SyncPhaseLocker, G>
+    where
+        T::Sequentializer: AsRef<LazySequentializer<SyncPhaseLocker, G>>,
+        T::Sequentializer: AsMut<LazySequentializer<SyncPhaseLocker, G>>,
+    {
+        #[inline(always)]
+        fn init_then_read_guard_until(
+            s: &'a T,
+            shall_init: impl Fn(Phase) -> bool,
+            init: impl FnOnce(&'a <T as Sequential>::Data),
+            deadline: Instant,
+        ) -> Result<Self::ReadGuard, Phase> {
+            let this = Sequential::sequentializer(s).as_ref();
+
+            let wait = trace_wait(s);
+            match wait.report(this.lock_phase_until(
+                Sequential::data(s),
+                |p| {
+                    if shall_init(p) {
+                        debug_test(s);
+                        LockNature::Write
+                    } else {
+                        LockNature::Read
+                    }
+                },
+                wait.on_wait(describe_wait(s, |_| LockNature::Read)),
+                Phase::INITIALIZED,
+                deadline,
+            ))? {
+                LockResult::Read(l) => Ok(l),
+                LockResult::Write(l) => {
+                    let _g = debug_save_thread(s);
+                    let _t = trace_init(s);
+                    let l = lazy_initialization_only(l, init);
+                    Ok(l.into())
+                }
+                LockResult::None(_) => unsafe { unreachable_unchecked() },
+            }
+        }
+        #[inline(always)]
+        fn init_then_write_guard_until(
+            s: &'a T,
+            shall_init: impl Fn(Phase) -> bool,
+            init: impl FnOnce(&'a <T as Sequential>::Data),
+            deadline: Instant,
+        ) -> Result<Self::WriteGuard, Phase> {
+            let this = Sequential::sequentializer(s).as_ref();
+
+            let wait = trace_wait(s);
+            match wait.report(this.lock_phase_until(
+                Sequential::data(s),
+                |_| LockNature::Write,
+                wait.on_wait(describe_wait(s, |_| LockNature::Write)),
+                Phase::INITIALIZED,
+                deadline,
+            ))? {
+                LockResult::Write(l) => {
+                    if shall_init(l.phase()) {
+                        debug_test(s);
+                        let _g = debug_save_thread(s);
+                        let _t = trace_init(s);
+                        Ok(lazy_initialization_only(l, init))
+                    } else {
+                        Ok(l)
+                    }
+                }
+                LockResult::Read(_) => unsafe { unreachable_unchecked() },
+                LockResult::None(_) => unsafe { unreachable_unchecked() },
+            }
+        }
+    }
+
+    #[cfg(feature = "timeout")]
+    impl<G: GeneratorTolerance> LazySequentializer<SyncPhaseLocker, G> {
+        #[inline(always)]
+        /// Similar to [FinalizableLazySequentializer::init_then_read_guard] but returns the
+        /// phase of the object if the lock could not be obtained before `deadline`.
+        pub(crate) fn finalizable_init_then_read_guard_until<'a, T: Sequential + 'a>(
+            s: &'a T,
+            shall_init: impl Fn(Phase) -> bool,
+            init: impl FnOnce(&'a <T as Sequential>::Data),
+            reg: impl FnOnce(&'a T) -> bool,
+            deadline: Instant,
+        ) -> Result<SyncReadPhaseGuard<'a, T::Data>, Phase>
+        where
+            G: 'a,
+            T::Sequentializer: AsRef<Self>,
+        {
+            let this = Sequential::sequentializer(s).as_ref();
+
+            let wait = trace_wait(s);
+            match wait.report(this.lock_phase_until(
+                s,
+                |p| {
+                    if shall_init(p) {
+                        debug_test(s);
+                        LockNature::Write
+                    } else {
+                        LockNature::Read
+                    }
+                },
+                wait.on_wait(describe_wait(s, |_| LockNature::Read)),
+                Phase::INITIALIZED | Phase::REGISTERED,
+                deadline,
+            ))? {
+                LockResult::Read(l) => Ok(l.map(|s| Sequential::data(s))),
+                LockResult::Write(l) => {
+                    let _g = debug_save_thread(s);
+                    let _t = trace_init(s);
+                    let l = lazy_initialization(l, init, reg, Unit::<G>::new());
+                    Ok(l.map(|s| Sequential::data(s)).into())
+                }
+                LockResult::None(_) => unsafe { unreachable_unchecked() },
+            }
+        }
+        #[inline(always)]
+        /// Similar to [FinalizableLazySequentializer::init_then_write_guard] but returns the
+        /// phase of the object if the lock could not be obtained before `deadline`.
+        pub(crate) fn finalizable_init_then_write_guard_until<'a, T: Sequential + 'a>(
+            s: &'a T,
+            shall_init: impl Fn(Phase) -> bool,
+            init: impl FnOnce(&'a <T as Sequential>::Data),
+            reg: impl FnOnce(&'a T) -> bool,
+            deadline: Instant,
+        ) -> Result<SyncPhaseGuard<'a, T::Data>, Phase>
+        where
+            G: 'a,
+            T::Sequentializer: AsRef<Self>,
+        {
+            let this = Sequential::sequentializer(s).as_ref();
+
+            let wait = trace_wait(s);
+            match wait.report(this.lock_phase_until(
+                s,
+                |_| LockNature::Write,
+                wait.on_wait(describe_wait(s, |_| LockNature::Write)),
+                Phase::INITIALIZED,
+                deadline,
+            ))? {
+                LockResult::Write(l) => {
+                    if shall_init(l.phase()) {
+                        debug_test(s);
+                        let _g = debug_save_thread(s);
+                        let _t = trace_init(s);
+                        let l = lazy_initialization(l, init, reg, Unit::<G>::new());
+                        Ok(l.map(|s| Sequential::data(s)))
+                    } else {
+                        Ok(l.map(|s| Sequential::data(s)))
+                    }
+                }
+                LockResult::Read(_) => unsafe { unreachable_unchecked() },
+                LockResult::None(_) => unsafe { unreachable_unchecked() },
+            }
+        }
+    }
+
     #[inline(always)]
-    fn whole_lock<'a, T: Sequential + 'a, L: 'a, G: 'a>(
+    fn whole_lock<'a, T: Sequential + 'a, L: 'a, G: 'a + GeneratorTolerance>(
         s: &'a T,
         lock_nature: impl Fn(Phase) -> LockNature,
         hint: Phase,
@@ -305,7 +588,13 @@ mod generic {
     {
         let this = Sequential::sequentializer(s).as_ref();
 
-        this.0.lock(s, &lock_nature, &lock_nature, hint)
+        let wait = trace_wait(s);
+        wait.report(this.lock_phase(
+            s,
+            &lock_nature,
+            wait.on_wait(describe_wait(s, &lock_nature)),
+            hint,
+        ))
     }
 
     #[inline(always)]
@@ -324,30 +613,96 @@ mod generic {
         this.0.try_lock(s, &lock_nature, hint)
     }
 
-    #[cfg(not(debug_mode))]
+    #[cfg(not(feature = "tracing"))]
+    fn trace_init<T>(_: &T) {}
+
+    #[cfg(feature = "tracing")]
+    fn trace_init<T: Sequential>(s: &T) -> InitTrace {
+        InitTrace::enter(Sequential::info(s))
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    fn trace_finalize<T>(_: &T) {}
+
+    #[cfg(feature = "tracing")]
+    fn trace_finalize<T: Sequential>(s: &T) -> FinalizeTrace {
+        FinalizeTrace::enter(Sequential::info(s))
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    #[inline(always)]
+    fn trace_wait<T>(_: &T) -> NoWaitTrace {
+        NoWaitTrace
+    }
+
+    #[cfg(feature = "tracing")]
+    #[inline(always)]
+    fn trace_wait<T: Sequential>(s: &T) -> WaitTrace<'_> {
+        WaitTrace::new(Sequential::info(s))
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    struct NoWaitTrace;
+
+    #[cfg(not(feature = "tracing"))]
+    impl NoWaitTrace {
+        #[inline(always)]
+        fn on_wait<F>(&self, f: F) -> F {
+            f
+        }
+        #[inline(always)]
+        fn report<R>(&self, r: R) -> R {
+            r
+        }
+    }
+
+    #[cfg(not(feature = "deadlock_detection"))]
+    #[inline(always)]
+    fn describe_wait<T, F>(_: &T, f: F) -> F {
+        f
+    }
+
+    #[cfg(feature = "deadlock_detection")]
+    #[inline(always)]
+    fn describe_wait<'a, T: Sequential, R>(
+        s: &'a T,
+        f: impl Fn(Phase) -> R + 'a,
+    ) -> impl Fn(Phase) -> R + 'a {
+        move |p| {
+            crate::deadlock::describe_wait(Sequential::info(s));
+            f(p)
+        }
+    }
+
+    #[cfg(not(any(debug_mode, feature = "cycle_detect")))]
     fn debug_save_thread<T>(_: &T) {}
 
-    #[cfg(debug_mode)]
+    #[cfg(any(debug_mode, feature = "cycle_detect"))]
     fn debug_save_thread<T: Sequential, L, G>(s: &T) -> DebugGuard<'_, T, L, G>
     where
         T::Sequentializer: AsRef<LazySequentializer<L, G>>,
     {
         let this = Sequential::sequentializer(s).as_ref();
-        use parking_lot::lock_api::GetThreadId;
-        this.2.store(
-            parking_lot::RawThreadId.nonzero_thread_id().into(),
-            Ordering::Relaxed,
-        );
-        DebugGuard(s, PhantomData)
+        this.2.store(thread_id(), Ordering::Relaxed);
+        DebugGuard(
+            s,
+            PhantomData,
+            InitFrame::enter(this as *const _ as usize, Sequential::info(s)),
+        )
     }
 
     #[must_use]
-    #[cfg(debug_mode)]
-    struct DebugGuard<'a, T: Sequential, L, G>(&'a T, PhantomData<(L, G)>)
+    #[cfg(any(debug_mode, feature = "cycle_detect"))]
+    struct DebugGuard<'a, T: Sequential, L, G>(
+        &'a T,
+        PhantomData<(L, G)>,
+        // only held to pop the frame on drop
+        #[allow(dead_code)] InitFrame,
+    )
     where
         T::Sequentializer: AsRef<LazySequentializer<L, G>>;
 
-    #[cfg(debug_mode)]
+    #[cfg(any(debug_mode, feature = "cycle_detect"))]
     impl<'a, T: Sequential, L, G> Drop for DebugGuard<'a, T, L, G>
     where
         T::Sequentializer: AsRef<LazySequentializer<L, G>>,
@@ -363,15 +718,12 @@ mod generic {
     where
         T::Sequentializer: AsRef<LazySequentializer<L, G>>,
     {
-        #[cfg(debug_mode)]
+        #[cfg(any(debug_mode, feature = "cycle_detect"))]
         {
             let this = Sequential::sequentializer(_s).as_ref();
             let id = this.2.load(Ordering::Relaxed);
-            if id != 0 {
-                use parking_lot::lock_api::GetThreadId;
-                if id == parking_lot::RawThreadId.nonzero_thread_id().into() {
-                    std::panic::panic_any(CyclicPanic);
-                }
+            if id != 0 && id == thread_id() {
+                crate::cycle::cycle_panic(this as *const _ as usize, Sequential::info(_s));
             }
         }
     }
@@ -401,7 +753,8 @@ mod generic {
         ) -> Phase {
             let this = Sequential::sequentializer(s).as_ref();
 
-            let phase_guard = match this.0.lock(
+            let wait = trace_wait(s);
+            let phase_guard = match wait.report(this.lock_phase(
                 s,
                 |p| {
                     if shall_init(p) {
@@ -411,15 +764,16 @@ mod generic {
                         LockNature::None
                     }
                 },
-                |_| LockNature::Read,
+                wait.on_wait(describe_wait(s, |_| LockNature::Read)),
                 Phase::INITIALIZED | Phase::REGISTERED,
-            ) {
+            )) {
                 LockResult::None(p) => return p,
                 LockResult::Write(l) => l,
                 LockResult::Read(l) => return Phased::phase(&l),
             };
 
             let _g = debug_save_thread(s);
+            let _t = trace_init(s);
             let ph = lazy_initialization(phase_guard, init, reg, Unit::<G>::new());
             ph.phase()
         }
@@ -433,7 +787,8 @@ mod generic {
         ) -> Self::ReadGuard {
             let this = Sequential::sequentializer(s).as_ref();
 
-            match this.0.lock(
+            let wait = trace_wait(s);
+            match wait.report(this.lock_phase(
                 s,
                 |p| {
                     if shall_init(p) {
@@ -443,12 +798,13 @@ mod generic {
                         LockNature::Read
                     }
                 },
-                |_| LockNature::Read,
+                wait.on_wait(describe_wait(s, |_| LockNature::Read)),
                 Phase::INITIALIZED | Phase::REGISTERED,
-            ) {
+            )) {
                 LockResult::Read(l) => l.map(|s| Sequential::data(s)),
                 LockResult::Write(l) => {
                     let _g = debug_save_thread(s);
+                    let _t = trace_init(s);
                     let l = lazy_initialization(l, init, reg, Unit::<G>::new());
                     l.map(|s| Sequential::data(s)).into()
                 }
@@ -467,6 +823,7 @@ mod generic {
                     if shall_init(l.phase()) {
                         debug_test(s);
                         let _g = debug_save_thread(s);
+                        let _t = trace_init(s);
                         let l = lazy_initialization(l, init, reg, Unit::<G>::new());
                         l.map(|s| Sequential::data(s))
                     } else {
@@ -504,6 +861,7 @@ mod generic {
                     LockResult::Read(l) => l.map(|s| Sequential::data(s)),
                     LockResult::Write(l) => {
                         let _g = debug_save_thread(s);
+                        let _t = trace_init(s);
                         let l = lazy_initialization(l, init, reg, Unit::<G>::new());
                         l.map(|s| Sequential::data(s)).into()
                     }
@@ -527,6 +885,7 @@ mod generic {
                     if shall_init(l.phase()) {
                         debug_test(s);
                         let _g = debug_save_thread(s);
+                        let _t = trace_init(s);
                         let l = lazy_initialization(l, init, reg, Unit::<G>::new());
                         l.map(|s| Sequential::data(s))
                     } else {
@@ -543,19 +902,22 @@ mod generic {
             let this = Sequential::sequentializer(s).as_ref();
 
             let how = |p: Phase| {
-                if p.intersects(Phase::INITIALIZED) {
+                if p.intersects(Phase::INITIALIZED)
+                    && !p.intersects(Phase::FINALIZED | Phase::FINALIZATION_PANICKED)
+                {
                     LockNature::Write
                 } else {
                     LockNature::None
                 }
             };
 
-            let phase_guard = match this.0.lock(
+            let wait = trace_wait(s);
+            let phase_guard = match wait.report(this.lock_phase(
                 Sequential::data(s),
                 how,
-                how,
+                wait.on_wait(describe_wait(s, how)),
                 Phase::INITIALIZED | Phase::REGISTERED,
-            ) {
+            )) {
                 LockResult::None(_) => return,
                 LockResult::Write(l) => l,
                 LockResult::Read(_) => unsafe { unreachable_unchecked() },
@@ -565,6 +927,7 @@ mod generic {
                 & (Phase::FINALIZED | Phase::FINALIZATION_PANICKED))
                 .is_empty());
 
+            let _t = trace_finalize(s);
             lazy_finalization(phase_guard, f);
         }
     }
@@ -608,7 +971,8 @@ mod generic {
         ) -> Phase {
             let this = Sequential::sequentializer(s).as_ref();
 
-            let phase_guard = match this.0.lock(
+            let wait = trace_wait(s);
+            let phase_guard = match wait.report(this.lock_phase(
                 Sequential::data(s),
                 |p| {
                     if shall_init(p) {
@@ -618,15 +982,16 @@ mod generic {
                         LockNature::None
                     }
                 },
-                |_| LockNature::Read,
+                wait.on_wait(describe_wait(s, |_| LockNature::Read)),
                 Phase::INITIALIZED,
-            ) {
+            )) {
                 LockResult::None(p) => return p,
                 LockResult::Write(l) => l,
                 LockResult::Read(l) => return Phased::phase(&l),
             };
 
             let _g = debug_save_thread(s);
+            let _t = trace_init(s);
             let ph = lazy_initialization_only(phase_guard, init);
             ph.phase()
         }
@@ -639,7 +1004,8 @@ mod generic {
         ) -> Self::ReadGuard {
             let this = Sequential::sequentializer(s).as_ref();
 
-            match this.0.lock(
+            let wait = trace_wait(s);
+            match wait.report(this.lock_phase(
                 Sequential::data(s),
                 |p| {
                     if shall_init(p) {
@@ -649,12 +1015,13 @@ mod generic {
                         LockNature::Read
                     }
                 },
-                |_| LockNature::Read,
+                wait.on_wait(describe_wait(s, |_| LockNature::Read)),
                 Phase::INITIALIZED,
-            ) {
+            )) {
                 LockResult::Read(l) => l,
                 LockResult::Write(l) => {
                     let _g = debug_save_thread(s);
+                    let _t = trace_init(s);
                     let l = lazy_initialization_only(l, init);
                     l.into()
                 }
@@ -676,6 +1043,7 @@ mod generic {
                     if shall_init(l.phase()) {
                         debug_test(s);
                         let _g = debug_save_thread(s);
+                        let _t = trace_init(s);
                         lazy_initialization_only(l, init)
                     } else {
                         l
@@ -711,6 +1079,7 @@ mod generic {
                     LockResult::Read(l) => l,
                     LockResult::Write(l) => {
                         let _g = debug_save_thread(s);
+                        let _t = trace_init(s);
                         let l = lazy_initialization_only(l, init);
                         l.into()
                     }
@@ -729,6 +1098,7 @@ mod generic {
                         if shall_init(l.phase()) {
                             debug_test(s);
                             let _g = debug_save_thread(s);
+                            let _t = trace_init(s);
                             lazy_initialization_only(l, init)
                         } else {
                             l
@@ -740,6 +1110,116 @@ mod generic {
         }
     }
 
+    // SAFETY: it is safe because it does implement synchronized locks
+    unsafe impl<'a, T: Sequential + 'a, L: 'a, G: 'a> FallibleLazySequentializer<'a, T>
+        for LazySequentializer<L, G>
+    where
+        T::Sequentializer: AsRef<LazySequentializer<L, G>>,
+        T::Sequentializer: AsMut<LazySequentializer<L, G>>,
+        L: PhaseLocker<'a, T>,
+        L: PhaseLocker<'a, T::Data>,
+        L: Phased,
+        <L as PhaseLocker<'a, T>>::ReadGuard:
+            Mappable<T, T::Data, <L as PhaseLocker<'a, T::Data>>::ReadGuard>,
+        <L as PhaseLocker<'a, T>>::WriteGuard:
+            Mappable<T, T::Data, <L as PhaseLocker<'a, T::Data>>::WriteGuard>,
+        <L as PhaseLocker<'a, T::Data>>::ReadGuard:
+            From<<L as PhaseLocker<'a, T::Data>>::WriteGuard>,
+        G: GeneratorTolerance,
+    {
+        #[inline(always)]
+        fn fallible_init(
+            s: &'a T,
+            shall_init: impl Fn(Phase) -> bool,
+            init: impl FnOnce(&'a <T as Sequential>::Data) -> bool,
+        ) -> Phase {
+            let this = Sequential::sequentializer(s).as_ref();
+
+            let wait = trace_wait(s);
+            let phase_guard = match wait.report(this.lock_phase(
+                Sequential::data(s),
+                |p| {
+                    if shall_init(p) {
+                        debug_test(s);
+                        LockNature::Write
+                    } else {
+                        LockNature::None
+                    }
+                },
+                wait.on_wait(describe_wait(s, |_| LockNature::Read)),
+                Phase::INITIALIZED,
+            )) {
+                LockResult::None(p) => return p,
+                LockResult::Write(l) => l,
+                LockResult::Read(l) => return Phased::phase(&l),
+            };
+
+            let _g = debug_save_thread(s);
+            let _t = trace_init(s);
+            let ph = lazy_fallible_initialization_only(phase_guard, init);
+            ph.phase()
+        }
+
+        #[inline(always)]
+        fn fallible_init_then_read_guard(
+            s: &'a T,
+            shall_init: impl Fn(Phase) -> bool,
+            init: impl FnOnce(&'a <T as Sequential>::Data) -> bool,
+        ) -> Self::ReadGuard {
+            let this = Sequential::sequentializer(s).as_ref();
+
+            let wait = trace_wait(s);
+            match wait.report(this.lock_phase(
+                Sequential::data(s),
+                |p| {
+                    if shall_init(p) {
+                        debug_test(s);
+                        LockNature::Write
+                    } else {
+                        LockNature::Read
+                    }
+                },
+                wait.on_wait(describe_wait(s, |_| LockNature::Read)),
+                Phase::INITIALIZED,
+            )) {
+                LockResult::Read(l) => l,
+                LockResult::Write(l) => {
+                    let _g = debug_save_thread(s);
+                    let _t = trace_init(s);
+                    let l = lazy_fallible_initialization_only(l, init);
+                    l.into()
+                }
+                LockResult::None(_) => unsafe { unreachable_unchecked() },
+            }
+        }
+
+        #[inline(always)]
+        fn fallible_init_then_write_guard(
+            s: &'a T,
+            shall_init: impl Fn(Phase) -> bool,
+            init: impl FnOnce(&'a <T as Sequential>::Data) -> bool,
+        ) -> Self::WriteGuard {
+            match <Self as Sequentializer<'a, T>>::lock(
+                s,
+                |_| LockNature::Write,
+                Phase::INITIALIZED,
+            ) {
+                LockResult::Write(l) => {
+                    if shall_init(l.phase()) {
+                        debug_test(s);
+                        let _g = debug_save_thread(s);
+                        let _t = trace_init(s);
+                        lazy_fallible_initialization_only(l, init)
+                    } else {
+                        l
+                    }
+                }
+                LockResult::Read(_) => unsafe { unreachable_unchecked() },
+                LockResult::None(_) => unsafe { unreachable_unchecked() },
+            }
+        }
+    }
+
     impl<L, T: Sequential<Sequentializer = Self>, G: GeneratorTolerance> UniqueLazySequentializer<T>
         for LazySequentializer<L, G>
     where
@@ -750,6 +1230,8 @@ mod generic {
             shall_init: impl Fn(Phase) -> bool,
             init: impl FnOnce(&mut <T as Sequential>::Data),
         ) -> Phase {
+            let _t = trace_init(&*target);
+
             let (that, data) = Sequential::sequentializer_data_mut(target);
 
             //let phase_guard = that.0.lock_mut(data);
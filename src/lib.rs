@@ -65,6 +65,98 @@
 //!
 //! Those statics use an *apdaptative phase locker* that gives them surprising performance.
 //!
+//! A read lock that may have to be turned into a write lock can be obtained with
+//! `upgradable_read`. It does not prevent other threads from reading, and no other
+//! thread can write between the read and the upgrade:
+//!
+//! ```
+//! use static_init::{dynamic, lazy::lesser_locked_lazy::UpgradableReadGuard};
+//!
+//! #[dynamic]
+//! static mut ROUTES: Vec<u32> = vec![];
+//!
+//! let routes = ROUTES.upgradable_read();
+//! if !routes.contains(&42) {
+//!     UpgradableReadGuard::upgrade(routes).push(42);
+//! }
+//! ```
+//!
+//! With the `timeout` feature, `read_for`, `write_for`, `read_until` and `write_until` give up
+//! if the lock cannot be obtained in time. The returned `TimeoutError` tells whether the static
+//! was still being initialized by an other thread or was locked:
+//!
+//! ```
+//! # #[cfg(feature = "timeout")]
+//! # {
+//! use static_init::{dynamic, TimeoutError};
+//! use std::time::Duration;
+//!
+//! #[dynamic(lazy)]
+//! static mut CONFIG: Vec<u32> = vec![];
+//!
+//! match CONFIG.read_for(Duration::from_millis(10)) {
+//!     Ok(config) => assert!(config.is_empty()),
+//!     Err(TimeoutError::Initialization) => eprintln!("configuration still loading"),
+//!     Err(TimeoutError::Contention) => eprintln!("configuration being updated"),
+//! }
+//! # }
+//! ```
+//!
+//! Guards can be narrowed to a part of the target with `map`, `filter_map` and `try_map`.
+//! The lock is kept by the mapped guard, so a library can expose only a part of a static:
+//!
+//! ```
+//! use static_init::dynamic;
+//! use static_init::lazy::lesser_locked_lazy::{MappedReadGuard, ReadGuard};
+//!
+//! pub struct Settings {
+//!     pub verbose: bool,
+//! }
+//! struct AppState {
+//!     settings: Settings,
+//!     sessions: Vec<u64>,
+//! }
+//!
+//! #[dynamic]
+//! static mut APP: AppState = AppState {
+//!     settings: Settings { verbose: false },
+//!     sessions: vec![],
+//! };
+//!
+//! pub fn settings() -> MappedReadGuard<'static, Settings> {
+//!     ReadGuard::map(APP.read(), |app| &app.settings)
+//! }
+//! # assert!(!settings().verbose);
+//! # assert!(APP.read().sessions.is_empty());
+//! ```
+//!
+//! With the `stats` feature, the `stats` attribute argument attaches a
+//! `Statistics` block to a mutable static. Contended lock attempts are counted and timed
+//! there, and `registry::statistics` lists the blocks of all statics:
+//!
+//! ```
+//! # #[cfg(feature = "stats")]
+//! # {
+//! use static_init::dynamic;
+//!
+//! #[dynamic(lazy, stats)]
+//! static mut CACHE: Vec<u32> = vec![];
+//!
+//! CACHE.write().push(1);
+//! let stats = CACHE.statistics().unwrap();
+//! println!("{} contended writes", stats.write_contentions());
+//! # }
+//! ```
+//!
+//! With the `tracing` feature, the initialization and finalization of lazy statics run inside
+//! [tracing](https://docs.rs/tracing) spans that carry the name and location of the static, so
+//! a heavy initializer shows up in any tracing subscriber. Initialization panics, refused
+//! registrations and long lock waits are reported as events. See the `trace` module.
+//!
+//! With the `startup_profile` feature, the initialization of each static and each
+//! [constructor](macro@crate::constructor) run before `main` is timed. `startup_report`
+//! returns these timings, as a list or in the Chrome trace event format.
+//!
 //! # Classical Lazy statics
 //!
 //! By default, initialization of statics declared with the `dynamic` is forced before main
@@ -94,6 +186,13 @@
 //! On other plateform `no_std` support can be gain by using the `spin_loop` feature. NB that lock strategies
 //! based on spin loop are not system-fair and cause entire system slow-down.
 //!
+//! The `poison`, `timeout`, `stats`, `tracing`, `startup_profile`, `cycle_detect` and
+//! `deadlock_detection` features require the standard library.
+//!
+//! The types that allocate on the heap, `AsyncLazy`, `LazyMap`, `TypeMap`, the `lazy!` and
+//! `locked_lazy!` macros and `shutdown`, are only available with the `alloc` feature, which
+//! requires a global allocator. `shutdown_with` is the non allocating version of `shutdown`.
+//!
 //! # Performant
 //!
 //! ## Under the hood
@@ -109,6 +208,9 @@
 //! performed and a thread may attempt to get a write lock but decides to be waked
 //! as the owner of a read lock if it is about to be placed in a wait queue.
 //!
+//! A phase locker accepts at most 2^17-1 simultaneous read locks, upgradable read locks
+//! included. Further read lock attempts wait for a read lock to be released.
+//!
 //! Statics and thread locals that need to register themselve for destruction at
 //! program or thread exit are implemented as members of an intrusive list. This
 //! implementation avoid heap memory allocation caused by system library support
@@ -204,6 +306,19 @@
 //!
 //! The feature `debug_order` can be activated to detect trouble with initialization order of raw
 //! statics or dead locks due to lazy initialization depending on itself.
+//!
+//! The feature `cycle_detect` detects lazy initialization depending on itself in release
+//! builds too, at the cost of a thread local access each time a lazy is initialized. Instead
+//! of a dead lock, the thread panics with a [CycleError] payload that gives access to the
+//! chain of statics involved, and whose display lists it:
+//!
+//! ```text
+//! Circular lazy initialization: A (src/main.rs:4) -> B (src/main.rs:7) -> A
+//! ```
+//!
+//! The feature `deadlock_detection` makes threads record the locks of lazies they hold and wait
+//! on. [check_deadlocks] then reports cycles of threads each waiting on a lock held by the next one,
+//! for example locked lazies write locked in conflicting orders by different threads.
 
 // TODO:
 //          - bencher les thread locals
@@ -246,13 +361,7 @@
 // args always accessbile
 // the same as unix with thread_info
 //
-#![cfg_attr(
-    all(
-        not(any(feature = "parking_lot_core", debug_mode)),
-        any(target_os = "linux", target_os = "android")
-    ),
-    no_std
-)]
+#![cfg_attr(not(has_std), no_std)]
 #![cfg_attr(all(elf, feature = "thread_local"), feature(linkage))]
 #![cfg_attr(
     feature = "thread_local",
@@ -325,7 +434,11 @@
 ///
 mod details {}
 
-use core::cell::Cell;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::cell::{Cell, UnsafeCell};
+use core::sync::atomic::{AtomicBool, Ordering};
 
 /// A trait for objects that are intinded to transition between phasis.
 ///
@@ -344,6 +457,14 @@ unsafe trait Sequential {
     fn sequentializer(this: &Self) -> &Self::Sequentializer;
     fn data(this: &Self) -> &Self::Data;
     fn sequentializer_data_mut(this: &mut Self) -> (&mut Self::Sequentializer, &mut Self::Data);
+    #[cfg(any(
+        debug_mode,
+        feature = "tracing",
+        feature = "cycle_detect",
+        feature = "deadlock_detection"
+    ))]
+    /// Informations about the static that owns the data, if any
+    fn info(this: &Self) -> Option<&StaticInfo>;
 }
 
 /// Trait for objects that know in which [phase](Phase) they are.
@@ -455,6 +576,79 @@ unsafe trait LazySequentializer<'a, T: Sequential + 'a>: Sequentializer<'a, T> {
     ) -> Option<Self::WriteGuard>;
 }
 
+/// A [`Sequentializer`] that can also lock the [phase](Phase) of an object for reading
+/// such that the lock can later be atomically turned into a write lock.
+///
+/// # Safety
+///
+/// The trait is unsafe because the implementor must ensure that only one upgradable lock
+/// can be held at a time, that it can coexist with read locks and that while an upgradable
+/// lock is held no write lock can be taken.
+unsafe trait UpgradableSequentializer<'a, T: Sequential + 'a>: Sequentializer<'a, T> {
+    type UpgradableReadGuard: Phased + From<Self::WriteGuard>;
+    /// Lock the phase of an object for reading with the possibility to upgrade the lock.
+    fn upgradable_lock(target: &'a T) -> Self::UpgradableReadGuard;
+}
+
+/// A [`LazySequentializer`] whose lock attempts can be abandoned at a deadline.
+///
+/// # Safety
+///
+/// The trait is unsafe because the implementor must ensure the same lock semantic
+/// as the one of [`LazySequentializer`].
+#[cfg(feature = "timeout")]
+unsafe trait TimedLazySequentializer<'a, T: Sequential + 'a>: LazySequentializer<'a, T> {
+    /// Similar to [init_then_read_guard](LazySequentializer::init_then_read_guard) but
+    /// returns the phase of the object if the lock could not be obtained before `deadline`.
+    fn init_then_read_guard_until(
+        target: &'a T,
+        shall_init: impl Fn(Phase) -> bool,
+        init: impl FnOnce(&'a <T as Sequential>::Data),
+        deadline: std::time::Instant,
+    ) -> Result<Self::ReadGuard, Phase>;
+    /// Similar to [init_then_write_guard](LazySequentializer::init_then_write_guard) but
+    /// returns the phase of the object if the lock could not be obtained before `deadline`.
+    fn init_then_write_guard_until(
+        target: &'a T,
+        shall_init: impl Fn(Phase) -> bool,
+        init: impl FnOnce(&'a <T as Sequential>::Data),
+        deadline: std::time::Instant,
+    ) -> Result<Self::WriteGuard, Phase>;
+}
+
+/// A [`LazySequentializer`] whose initialization may fail.
+///
+/// # Safety
+///
+/// The trait is unsafe because the implementor must ensure the same lock semantic
+/// as the one of [`LazySequentializer`].
+unsafe trait FallibleLazySequentializer<'a, T: Sequential + 'a>:
+    LazySequentializer<'a, T>
+{
+    /// Similar to [init](LazySequentializer::init) but `init` may fail by returning `false`,
+    /// in which case the data is left uninitialized and the phase of the object is set
+    /// to INITIALIZATION_SKIPED.
+    fn fallible_init(
+        target: &'a T,
+        shall_init: impl Fn(Phase) -> bool,
+        init: impl FnOnce(&'a <T as Sequential>::Data) -> bool,
+    ) -> Phase;
+    /// Similar to [init_then_read_guard](LazySequentializer::init_then_read_guard) but
+    /// `init` may fail as for [fallible_init](Self::fallible_init).
+    fn fallible_init_then_read_guard(
+        target: &'a T,
+        shall_init: impl Fn(Phase) -> bool,
+        init: impl FnOnce(&'a <T as Sequential>::Data) -> bool,
+    ) -> Self::ReadGuard;
+    /// Similar to [init_then_write_guard](LazySequentializer::init_then_write_guard) but
+    /// `init` may fail as for [fallible_init](Self::fallible_init).
+    fn fallible_init_then_write_guard(
+        target: &'a T,
+        shall_init: impl Fn(Phase) -> bool,
+        init: impl FnOnce(&'a <T as Sequential>::Data) -> bool,
+    ) -> Self::WriteGuard;
+}
+
 trait UniqueLazySequentializer<T: Sequential> {
     /// if `shall_init` return true for the target [`Sequential`] object, it initialize
     /// the data of the target object using `init`
@@ -538,6 +732,18 @@ unsafe trait FinalizableLazySequentializer<'a, T: 'a + Sequential>:
 pub trait GeneratorTolerance {
     const INIT_FAILURE: bool;
     const FINAL_REGISTRATION_FAILURE: bool;
+    /// Priority of the finalization at program exit of the statics initialized by
+    /// this generator. Statics with lower priority are finalized first.
+    const FINALIZATION_PRIORITY: u16 = 0;
+    #[doc(hidden)]
+    /// How to initialize the lazy static initialized by this generator when it is
+    /// named in `#[dynamic(after(..))]`.
+    const DEPENDENCY: Option<dependency::Dependency> = None;
+    #[cfg(feature = "stats")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+    /// Returns the block in which contended lock attempts on the locked lazies
+    /// initialized by this generator are recorded.
+    const STATISTICS: Option<fn() -> &'static statistics::Statistics> = None;
 }
 
 /// Generates a value of type `T`
@@ -570,6 +776,63 @@ impl<U, T: FnOnce() -> U> GeneratorTolerance for Cell<Option<T>> {
     const FINAL_REGISTRATION_FAILURE: bool = false;
 }
 
+/// A generator that wraps a [FnOnce], the generator of lazies created with [Lazy::new]
+/// and [LockedLazy::new].
+///
+/// Contrary to `Cell<Option<G>>`, it is [Sync] if `G` is [Send], so that the lazy can be
+/// shared between threads, for example as a field of a structure shared through an `Arc`.
+/// The sequentializer of the lazy ensures that the generator is called only once, a
+/// second call panics.
+pub struct OnceGenerator<G> {
+    taken: AtomicBool,
+    generator: UnsafeCell<Option<G>>,
+}
+
+// SAFETY: the generator is only accessed by the thread that set the taken flag
+unsafe impl<G: Send> Sync for OnceGenerator<G> {}
+
+impl<G> OnceGenerator<G> {
+    #[inline(always)]
+    pub const fn new(generator: G) -> Self {
+        Self {
+            taken: AtomicBool::new(false),
+            generator: UnsafeCell::new(Some(generator)),
+        }
+    }
+}
+
+impl<U, T: FnOnce() -> U> Generator<U> for OnceGenerator<T> {
+    fn generate(&self) -> U {
+        if self.taken.swap(true, Ordering::Acquire) {
+            panic!("Cannot call this generator twice")
+        }
+        // SAFETY: this thread is the only one that set the taken flag
+        match unsafe { (*self.generator.get()).take() } {
+            Some(v) => v(),
+            None => unsafe { core::hint::unreachable_unchecked() },
+        }
+    }
+}
+
+impl<U, T: FnOnce() -> U> GeneratorTolerance for OnceGenerator<T> {
+    const INIT_FAILURE: bool = false;
+    const FINAL_REGISTRATION_FAILURE: bool = false;
+}
+
+/// Generates a value of type `T` or fails with an error of type `E`
+///
+/// If [INIT_FAILURE](GeneratorTolerance::INIT_FAILURE) is true, a new
+/// attempt to generate the value will be made after a failure.
+pub trait TryGenerator<T, E>: GeneratorTolerance {
+    fn try_generate(&self) -> Result<T, E>;
+}
+
+impl<U, E, T: Fn() -> Result<U, E>> TryGenerator<U, E> for T {
+    fn try_generate(&self) -> Result<U, E> {
+        self()
+    }
+}
+
 /// Trait that must be implemented by #[dynamic(finalize)] statics.
 pub trait Finaly {
     /// This method is called when program or thread exit and the lazy
@@ -587,13 +850,6 @@ pub trait Uninit {
     fn uninit(&mut self);
 }
 
-#[cfg_attr(docsrs, doc(cfg(debug_mode)))]
-#[cfg(debug_mode)]
-#[doc(hidden)]
-#[derive(Debug)]
-/// Used to passe errors
-pub struct CyclicPanic;
-
 /// phases and bits to manipulate them;
 pub mod phase {
 
@@ -605,9 +861,14 @@ pub mod phase {
     #[cfg(not(feature = "spin_loop"))]
     pub(crate) const READ_WAITER_BIT: u32 = 0b0100_0000_0000_0000_0000_0000_0000_0000;
     pub(crate) const LOCKED_BIT: u32 = 0b0010_0000_0000_0000_0000_0000_0000_0000;
-    pub(crate) const READER_BITS: u32 = 0b0000_1111_1111_1111_1111_1111_0000_0000;
+    pub(crate) const READER_BITS: u32 = 0b0000_1111_1111_1111_1111_1000_0000_0000;
     pub(crate) const READER_OVERF: u32 = 0b0001_0000_0000_0000_0000_0000_0000_0000;
-    pub(crate) const READER_UNITY: u32 = 0b0000_0000_0000_0000_0000_0001_0000_0000;
+    pub(crate) const READER_UNITY: u32 = 0b0000_0000_0000_0000_0000_1000_0000_0000;
+    // The two upgrade bits are taken from the reader count: a phase locker accepts
+    // at most 2^17-1 simultaneous read locks (instead of 2^19-1), further readers
+    // wait for a read lock to be released.
+    pub(crate) const UPGRADABLE_BIT: u32 = 0b0000_0000_0000_0000_0000_0010_0000_0000;
+    pub(crate) const UPGRADE_WAITER_BIT: u32 = 0b0000_0000_0000_0000_0000_0100_0000_0000;
     #[cfg(not(feature = "spin_loop"))]
     pub(crate) const MAX_WAKED_READERS: usize = (READER_OVERF / READER_UNITY) as usize;
     // Although some flags exclude others, Phase is represented by
@@ -633,6 +894,8 @@ pub mod phase {
             const FINALIZED                 = 0b0000_0000_0000_0000_0000_0000_0100_0000;
             const FINALIZATION_PANICKED     = 0b0000_0000_0000_0000_0000_0000_1000_0000;
 
+            const POISONED                  = 0b0000_0000_0000_0000_0000_0001_0000_0000;
+
             const INITIALIZED_AND_REGISTERED     = Self::INITIALIZED.bits | Self::REGISTERED.bits;
         }
     }
@@ -676,6 +939,9 @@ pub mod phase {
                 if self.intersects(Phase::FINALIZATION_PANICKED) {
                     write("Finalization panicked")?;
                 }
+                if self.intersects(Phase::POISONED) {
+                    write("Poisoned")?;
+                }
                 write!(ft, ")")?
             }
             Ok(())
@@ -879,6 +1145,29 @@ pub use static_init_macro::destructor;
 /// assert_eq!(*V, vec![1,2]);
 /// ```
 ///
+/// ## Dependencies between lazy statics
+///
+/// A lazy or lesser lazy static can be declared to be initialized after other lazy statics
+/// with the `after(<paths>)` attribute argument. The statics listed are initialized before the
+/// initialization expression of the static is evaluated, so lesser lazy statics are initialized
+/// before main in a topological order.
+///
+/// ```
+/// # use static_init::dynamic;
+/// #[dynamic(after(CONFIG, crate::LOGGER))]
+/// static SERVICE: Vec<i32> = vec![1,2];
+///
+/// #[dynamic]
+/// static CONFIG: i32 = 1;
+///
+/// #[dynamic]
+/// static LOGGER: i32 = 2;
+/// # fn main() {}
+/// ```
+///
+/// On plateforms that support constructor priorities, dependency cycles are reported at program
+/// startup by a panic whose message lists the statics participating to the cycle.
+///
 /// ## Finalized statics
 ///
 /// The attribute argument `finalize` can be used if the declared type of
@@ -951,6 +1240,22 @@ pub use static_init_macro::destructor;
 /// //the call to finaly at program exit
 /// ```
 ///
+/// ## Fallible lazy statics
+///
+/// With the attribute argument `fallible`, a lazy static whose declared type is of the form
+/// `Result<T, E>` is a [TryLazy] (or [TryLockedLazy] if it is mutable). Its `get` method
+/// returns the error returned by the initialization expression instead of panicking. The
+/// initialization is attempted again on the next access unless the static is also declared
+/// with `try_init_once`.
+///
+/// ```
+/// # use static_init::dynamic;
+/// #[dynamic(lazy, fallible)]
+/// static X: Result<i32, std::num::ParseIntError> = "33".parse();
+///
+/// assert_eq!(*X.get().unwrap(), 33);
+/// ```
+///
 /// # Locked lazy statics
 ///
 /// Those statics are mutable statics, initialized on the first acces and protected behind
@@ -1004,6 +1309,51 @@ pub use static_init_macro::destructor;
 /// static mut V2: Vec<i32> = vec![1,2];
 /// ```
 ///
+/// ## Poisoned locked lazy statics
+///
+/// With the `poison` feature, mutable statics declared with the `poison` attribute argument
+/// are marked [poisoned](Phase::POISONED) if a panic happens while they are write locked. Their
+/// `read` and `write` methods then return an error, see [poison::Poison].
+///
+/// ## Lock statistics
+///
+/// With the `stats` feature, mutable lazy statics declared with the `stats` attribute argument
+/// record their contended lock attempts in a statistics block that is returned by their
+/// `statistics` method and listed by `registry::statistics`.
+///
+/// ## Seqlocked lazy statics
+///
+/// Mutable lazy statics of a `Copy` type declared with the `seqlock` attribute argument are
+/// read without lock: their `load` method returns a copy of the value, and is retried if the
+/// value was concurrently modified through their `store` or `update` methods. See [SeqLockLazy].
+///
+/// ```
+/// # use static_init::dynamic;
+/// #[dynamic(seqlock)]
+/// static mut TIMESTAMP: u64 = 0;
+///
+/// TIMESTAMP.store(12);
+/// assert_eq!(TIMESTAMP.load(), 12);
+/// ```
+///
+/// ## Finalization priority of lazy statics
+///
+/// By default lazy statics are finalized or dropped at program exit in the reverse order
+/// of their registration, that happens during their initialization. A finalization priority
+/// can be specified with the `drop=<priority>` or `finalize=<priority>` syntax. Statics with
+/// a smaller priority are finalized sooner. The default priority is 0.
+///
+/// ```
+/// # use static_init::dynamic;
+/// #[dynamic(lazy,drop=10)] //dropped after V4
+/// static mut V3: Vec<i32> = vec![1,2];
+///
+/// #[dynamic(lazy,drop)]
+/// static mut V4: Vec<i32> = vec![1,2];
+/// ```
+///
+/// This is not supported by thread locals.
+///
 /// ## Primed locked lazy statics
 ///
 /// Those statics model the case where an object should have a
@@ -1171,11 +1521,23 @@ mod lazy_sequentializer;
 /// the other at thread exit.
 mod exit_sequentializer;
 
+#[cfg(any(elf, mach_o, coff))]
+#[doc(inline)]
+pub use exit_sequentializer::shutdown_with;
+
+#[cfg(all(feature = "alloc", any(elf, mach_o, coff)))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[doc(inline)]
+pub use exit_sequentializer::{shutdown, FinalizedStatic, ShutdownReport};
+
 /// Provides policy types for implementation of various lazily initialized types.
 mod generic_lazy;
 
 #[doc(inline)]
 pub use generic_lazy::AccessError;
+#[cfg(feature = "timeout")]
+#[doc(inline)]
+pub use generic_lazy::TimeoutError;
 
 /// Provides various implementation of lazily initialized types
 pub mod lazy;
@@ -1184,12 +1546,115 @@ pub use lazy::{Lazy, LazyAccess, LockedLazy};
 #[doc(inline)]
 pub use lazy::{UnSyncLazy, UnSyncLockedLazy};
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+/// Provides lazily initialized types whose initializer is a future.
+pub mod async_lazy;
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use async_lazy::{AsyncLazy, AsyncLockedLazy};
+
+/// Provides lazily initialized types whose initialization may fail.
+pub mod try_lazy;
+#[doc(inline)]
+pub use try_lazy::{InitError, TryLazy, TryLockedLazy};
+
+#[cfg(feature = "poison")]
+#[cfg_attr(docsrs, doc(cfg(feature = "poison")))]
+/// Locked lazies that are poisoned by a panic happening while they are write locked.
+pub mod poison;
+#[cfg(feature = "poison")]
+#[doc(inline)]
+pub use poison::{Poison, PoisonError};
+
+#[cfg(feature = "stats")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+/// Lock contention statistics of locked lazies.
+pub mod statistics;
+#[cfg(feature = "stats")]
+#[doc(inline)]
+pub use statistics::{Instrumented, Statistics};
+
+#[cfg(feature = "tracing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tracing")))]
+pub mod trace;
+
+#[cfg(any(debug_mode, feature = "cycle_detect"))]
+mod cycle;
+#[cfg(any(debug_mode, feature = "cycle_detect"))]
+#[cfg_attr(docsrs, doc(cfg(any(debug_mode, feature = "cycle_detect"))))]
+pub use cycle::CycleError;
+
+#[cfg(feature = "alloc")]
+mod lazy_macro;
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub use lazy_macro::{__CallSiteLazies, __call_site_info};
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+/// Provides maps whose values are lazily initialized, one per key.
+pub mod lazy_map;
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use lazy_map::LazyMap;
+#[cfg(all(feature = "alloc", any(elf, mach_o, coff)))]
+#[doc(inline)]
+pub use lazy_map::LazyMapFinalize;
+
+/// Provides fixed size arrays whose elements are lazily initialized.
+pub mod lazy_array;
+#[doc(inline)]
+pub use lazy_array::{LazyArray, LockedLazyArray};
+
+/// Provides a mutable lazy whose lock is sharded to reduce read contention.
+pub mod sharded_lazy;
+#[doc(inline)]
+pub use sharded_lazy::ShardedLockedLazy;
+
+/// Provides a mutable lazy whose value is read without lock.
+pub mod seqlock_lazy;
+#[doc(inline)]
+pub use seqlock_lazy::SeqLockLazy;
+
+#[cfg(all(feature = "alloc", any(elf, mach_o, coff)))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+/// Provides a family of lazy statics indexed by type, that stands for generic statics.
+pub mod type_map;
+#[cfg(all(feature = "alloc", any(elf, mach_o, coff)))]
+#[doc(inline)]
+pub use type_map::TypeMap;
+
+#[cfg(feature = "deadlock_detection")]
+#[cfg_attr(docsrs, doc(cfg(feature = "deadlock_detection")))]
+pub mod deadlock;
+#[cfg(feature = "deadlock_detection")]
+#[doc(inline)]
+pub use deadlock::check_deadlocks;
+
+#[cfg(all(feature = "startup_profile", any(elf, mach_o, coff)))]
+#[cfg_attr(docsrs, doc(cfg(feature = "startup_profile")))]
+/// Timings of the initializations run before `main`.
+pub mod startup_profile;
+#[cfg(all(feature = "startup_profile", any(elf, mach_o, coff)))]
+#[doc(inline)]
+pub use startup_profile::startup_report;
+
 #[cfg(any(elf, mach_o, coff))]
 /// Provides types for statics that are meant to run code before main start or after it exit.
 pub mod raw_static;
 
-#[derive(Debug)]
+#[cfg(any(elf, mach_o, coff))]
+/// Provides introspection of the statics declared with the [dynamic](macro@crate::dynamic)
+/// attribute.
+pub mod registry;
+
 #[doc(hidden)]
+/// Dependency metadata of lazy statics declared with `#[dynamic(after(..))]`.
+pub mod dependency;
+
+/// How a static declared with the [dynamic](macro@crate::dynamic) attribute is initialized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum InitMode {
     Const,
     Lazy,
@@ -1197,8 +1662,8 @@ pub enum InitMode {
     ProgramConstructor(u16),
 }
 
-#[derive(Debug)]
-#[doc(hidden)]
+/// How a static declared with the [dynamic](macro@crate::dynamic) attribute is finalized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FinalyMode {
     None,
     Drop,
@@ -1206,8 +1671,10 @@ pub enum FinalyMode {
     ProgramDestructor(u16),
 }
 
-#[derive(Debug)]
-#[doc(hidden)]
+/// Informations about a static declared with the [dynamic](macro@crate::dynamic) attribute.
+///
+/// See [registry::iter].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct StaticInfo {
     pub variable_name: &'static str,
     pub file_name: &'static str,
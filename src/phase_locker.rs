@@ -11,7 +11,9 @@ mod sync;
 mod sync;
 
 pub(crate) use sync::Mutex;
-pub(crate) use sync::{SyncPhaseGuard, SyncPhaseLocker, SyncReadPhaseGuard};
+pub(crate) use sync::{
+    SyncPhaseGuard, SyncPhaseLocker, SyncReadPhaseGuard, SyncUpgradableReadPhaseGuard,
+};
 
 mod unsync;
 pub(crate) use unsync::UnSyncPhaseLocker;
@@ -19,6 +21,27 @@ pub(crate) use unsync::{UnSyncPhaseGuard, UnSyncReadPhaseGuard};
 
 use super::{Phase, Phased};
 
+/// The instant after which an attempt to lock is abandoned.
+#[cfg(feature = "timeout")]
+pub(crate) use std::time::Instant as Deadline;
+
+/// Without the `timeout` feature no deadline can be given to a lock attempt.
+#[cfg(not(feature = "timeout"))]
+#[derive(Copy, Clone)]
+pub(crate) enum Deadline {}
+
+/// Return true if the deadline is passed
+#[inline]
+fn deadline_elapsed(deadline: Option<Deadline>) -> bool {
+    match deadline {
+        None => false,
+        #[cfg(feature = "timeout")]
+        Some(d) => std::time::Instant::now() >= d,
+        #[cfg(not(feature = "timeout"))]
+        Some(d) => match d {},
+    }
+}
+
 /// A phase guard ensure that the target object will
 /// performed atomic phase transition
 ///
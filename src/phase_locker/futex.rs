@@ -18,10 +18,13 @@ const READ_FAIRNESS_PERIOD: u16 = 32;
 mod linux {
     use super::READ_FAIRNESS_PERIOD;
     use crate::phase::*;
+    use crate::phase_locker::Deadline;
     use core::ops::{Deref, DerefMut};
     use core::ptr;
     use core::sync::atomic::{compiler_fence, AtomicU16, AtomicU32, Ordering};
-    use libc::{syscall, SYS_futex, FUTEX_PRIVATE_FLAG, FUTEX_WAIT_BITSET, FUTEX_WAKE_BITSET};
+    use libc::{
+        syscall, timespec, SYS_futex, FUTEX_PRIVATE_FLAG, FUTEX_WAIT_BITSET, FUTEX_WAKE_BITSET,
+    };
 
     pub(crate) struct Futex {
         futex: AtomicU32,
@@ -29,8 +32,35 @@ mod linux {
         fairness: AtomicU16,
     }
 
-    const READER_BIT: u32 = 0b01;
-    const WRITER_BIT: u32 = 0b10;
+    const READER_BIT: u32 = 0b001;
+    const WRITER_BIT: u32 = 0b010;
+    const UPGRADER_BIT: u32 = 0b100;
+
+    /// FUTEX_WAIT_BITSET timeouts are absolute CLOCK_MONOTONIC times
+    #[cfg(feature = "timeout")]
+    fn to_timespec(deadline: Deadline) -> timespec {
+        use libc::{clock_gettime, time_t, CLOCK_MONOTONIC};
+        let mut now = timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        unsafe { clock_gettime(CLOCK_MONOTONIC, &mut now) };
+        let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+        let nsec = now.tv_nsec as u32 + remaining.subsec_nanos();
+        let sec = remaining
+            .as_secs()
+            .saturating_add((nsec / 1_000_000_000) as u64);
+        timespec {
+            tv_sec: now
+                .tv_sec
+                .saturating_add(time_t::try_from(sec).unwrap_or(time_t::MAX)),
+            tv_nsec: (nsec % 1_000_000_000) as _,
+        }
+    }
+    #[cfg(not(feature = "timeout"))]
+    fn to_timespec(deadline: Deadline) -> timespec {
+        match deadline {}
+    }
 
     impl Futex {
         pub(crate) const fn new(value: u32) -> Self {
@@ -48,20 +78,32 @@ mod linux {
             self.fairness.load(Ordering::Relaxed) % READ_FAIRNESS_PERIOD != 0
         }
 
-        pub(crate) fn compare_and_wait_as_reader(&self, value: u32) -> bool {
+        pub(crate) fn compare_and_wait_as_reader(
+            &self,
+            value: u32,
+            deadline: Option<Deadline>,
+        ) -> bool {
+            let timeout = deadline.map(to_timespec);
             unsafe {
                 syscall(
                     SYS_futex,
                     &self.futex as *const _ as *const _,
                     FUTEX_WAIT_BITSET | FUTEX_PRIVATE_FLAG,
                     value,
-                    ptr::null::<u32>(),
+                    timeout
+                        .as_ref()
+                        .map_or(ptr::null(), |t| t as *const timespec),
                     ptr::null::<u32>(),
                     READER_BIT,
                 ) == 0
             }
         }
-        pub(crate) fn compare_and_wait_as_writer(&self, value: u32) -> bool {
+        pub(crate) fn compare_and_wait_as_writer(
+            &self,
+            value: u32,
+            deadline: Option<Deadline>,
+        ) -> bool {
+            let timeout = deadline.map(to_timespec);
             assert_ne!(self.writer_count.fetch_add(1, Ordering::Relaxed), u32::MAX);
             compiler_fence(Ordering::AcqRel);
             let res = unsafe {
@@ -70,7 +112,9 @@ mod linux {
                     &self.futex as *const _ as *const _,
                     FUTEX_WAIT_BITSET | FUTEX_PRIVATE_FLAG,
                     value,
-                    ptr::null::<u32>(),
+                    timeout
+                        .as_ref()
+                        .map_or(ptr::null(), |t| t as *const timespec),
                     ptr::null::<u32>(),
                     WRITER_BIT,
                 ) == 0
@@ -119,6 +163,32 @@ mod linux {
                 ) == 1
             }
         }
+        pub(crate) fn compare_and_wait_as_upgrader(&self, value: u32) -> bool {
+            unsafe {
+                syscall(
+                    SYS_futex,
+                    &self.futex as *const _ as *const _,
+                    FUTEX_WAIT_BITSET | FUTEX_PRIVATE_FLAG,
+                    value,
+                    ptr::null::<u32>(),
+                    ptr::null::<u32>(),
+                    UPGRADER_BIT,
+                ) == 0
+            }
+        }
+        pub(crate) fn wake_upgrader(&self) -> bool {
+            unsafe {
+                syscall(
+                    SYS_futex,
+                    &self.futex as *const _ as *const _,
+                    FUTEX_WAKE_BITSET | FUTEX_PRIVATE_FLAG,
+                    1,
+                    ptr::null::<u32>(),
+                    ptr::null::<u32>(),
+                    UPGRADER_BIT,
+                ) == 1
+            }
+        }
     }
 
     impl Deref for Futex {
@@ -146,12 +216,14 @@ pub(crate) use linux::Futex;
 mod other {
     use super::READ_FAIRNESS_PERIOD;
     use crate::phase::*;
+    use crate::phase_locker::Deadline;
     use core::ops::{Deref, DerefMut};
     use core::sync::atomic::{compiler_fence, AtomicU16, AtomicU32, Ordering};
     use parking_lot_core::{
         park, unpark_filter, unpark_one, FilterOp, ParkResult, DEFAULT_PARK_TOKEN,
         DEFAULT_UNPARK_TOKEN,
     };
+    use std::time::Instant;
 
     pub(crate) struct Futex {
         futex: AtomicU32,
@@ -159,6 +231,15 @@ mod other {
         fairness: AtomicU16,
     }
 
+    #[cfg(feature = "timeout")]
+    fn to_instant(deadline: Deadline) -> Instant {
+        deadline
+    }
+    #[cfg(not(feature = "timeout"))]
+    fn to_instant(deadline: Deadline) -> Instant {
+        match deadline {}
+    }
+
     impl Futex {
         pub(crate) const fn new(value: u32) -> Self {
             Self {
@@ -172,7 +253,11 @@ mod other {
             self.fairness.load(Ordering::Relaxed) % READ_FAIRNESS_PERIOD == 0
         }
 
-        pub(crate) fn compare_and_wait_as_reader(&self, value: u32) -> bool {
+        pub(crate) fn compare_and_wait_as_reader(
+            &self,
+            value: u32,
+            deadline: Option<Deadline>,
+        ) -> bool {
             unsafe {
                 matches!(
                     park(
@@ -181,13 +266,17 @@ mod other {
                         || {},
                         |_, _| {},
                         DEFAULT_PARK_TOKEN,
-                        None,
+                        deadline.map(to_instant),
                     ),
                     ParkResult::Unparked(_)
                 )
             }
         }
-        pub(crate) fn compare_and_wait_as_writer(&self, value: u32) -> bool {
+        pub(crate) fn compare_and_wait_as_writer(
+            &self,
+            value: u32,
+            deadline: Option<Deadline>,
+        ) -> bool {
             assert_ne!(self.writer_count.fetch_add(1, Ordering::Relaxed), u32::MAX);
             compiler_fence(Ordering::AcqRel);
             let res = unsafe {
@@ -198,7 +287,7 @@ mod other {
                         || {},
                         |_, _| {},
                         DEFAULT_PARK_TOKEN,
-                        None,
+                        deadline.map(to_instant),
                     ),
                     ParkResult::Unparked(_)
                 )
@@ -244,6 +333,25 @@ mod other {
             let r = unsafe { unpark_one(self.writer_key(), |_| DEFAULT_UNPARK_TOKEN) };
             r.unparked_threads == 1
         }
+        pub(crate) fn compare_and_wait_as_upgrader(&self, value: u32) -> bool {
+            unsafe {
+                matches!(
+                    park(
+                        self.upgrader_key(),
+                        || self.futex.load(Ordering::Relaxed) == value,
+                        || {},
+                        |_, _| {},
+                        DEFAULT_PARK_TOKEN,
+                        None,
+                    ),
+                    ParkResult::Unparked(_)
+                )
+            }
+        }
+        pub(crate) fn wake_upgrader(&self) -> bool {
+            let r = unsafe { unpark_one(self.upgrader_key(), |_| DEFAULT_UNPARK_TOKEN) };
+            r.unparked_threads == 1
+        }
 
         fn reader_key(&self) -> usize {
             &self.futex as *const _ as usize
@@ -251,6 +359,9 @@ mod other {
         fn writer_key(&self) -> usize {
             (&self.futex as *const _ as usize) + 1
         }
+        fn upgrader_key(&self) -> usize {
+            (&self.futex as *const _ as usize) + 2
+        }
     }
 
     impl Deref for Futex {
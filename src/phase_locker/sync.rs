@@ -1,9 +1,11 @@
 use super::futex::Futex;
 use super::spin_wait::SpinWait;
+use super::{deadline_elapsed, Deadline};
 use super::{LockNature, LockResult, Mappable, MutPhaseLocker, PhaseGuard, PhaseLocker};
 use crate::phase::*;
 use crate::{Phase, Phased};
 use core::cell::UnsafeCell;
+use core::hint::unreachable_unchecked;
 use core::mem::forget;
 use core::ops::{Deref, DerefMut};
 use core::sync::atomic::{fence, Ordering};
@@ -14,6 +16,9 @@ use core::sync::atomic::AtomicUsize;
 #[cfg(feature = "lock_statistics")]
 use core::fmt::{self, Display, Formatter};
 
+#[cfg(feature = "deadlock_detection")]
+use crate::deadlock::{Held, Waiting};
+
 /// A synchronised phase locker.
 pub(crate) struct SyncPhaseLocker(Futex);
 
@@ -25,7 +30,11 @@ pub(crate) struct Lock<'a> {
 
 /// A phase guard that allow atomic phase transition that
 /// can be turned fastly into a [SyncReadPhaseGuard].
-pub(crate) struct SyncPhaseGuard<'a, T: ?Sized>(&'a T, Lock<'a>);
+pub(crate) struct SyncPhaseGuard<'a, T: ?Sized>(
+    &'a T,
+    Lock<'a>,
+    #[cfg(feature = "deadlock_detection")] Held,
+);
 
 pub(crate) struct ReadLock<'a> {
     futex: &'a Futex,
@@ -33,7 +42,24 @@ pub(crate) struct ReadLock<'a> {
 }
 
 /// A kind of read lock.
-pub(crate) struct SyncReadPhaseGuard<'a, T: ?Sized>(&'a T, ReadLock<'a>);
+pub(crate) struct SyncReadPhaseGuard<'a, T: ?Sized>(
+    &'a T,
+    ReadLock<'a>,
+    #[cfg(feature = "deadlock_detection")] Held,
+);
+
+pub(crate) struct UpgradableReadLock<'a> {
+    futex: &'a Futex,
+    init_phase: Phase,
+}
+
+/// A read lock that is exclusive with other upgradable read locks
+/// and that can be atomically turned into a [SyncPhaseGuard].
+pub(crate) struct SyncUpgradableReadPhaseGuard<'a, T: ?Sized>(
+    &'a T,
+    UpgradableReadLock<'a>,
+    #[cfg(feature = "deadlock_detection")] Held,
+);
 
 pub(crate) struct Mutex<T>(UnsafeCell<T>, SyncPhaseLocker);
 
@@ -125,12 +151,24 @@ impl<'a, T> Deref for SyncPhaseGuard<'a, T> {
 impl<'a, T: ?Sized> SyncPhaseGuard<'a, T> {
     #[inline(always)]
     fn new(r: &'a T, lock: Lock<'a>) -> Self {
-        Self(r, lock)
+        #[cfg(feature = "deadlock_detection")]
+        let held = Held::new(lock.futex as *const _ as usize);
+        Self(
+            r,
+            lock,
+            #[cfg(feature = "deadlock_detection")]
+            held,
+        )
     }
 
     #[inline(always)]
     pub fn map<S: ?Sized>(self, f: impl FnOnce(&'a T) -> &'a S) -> SyncPhaseGuard<'a, S> {
-        SyncPhaseGuard(f(self.0), self.1)
+        SyncPhaseGuard(
+            f(self.0),
+            self.1,
+            #[cfg(feature = "deadlock_detection")]
+            self.2,
+        )
     }
 }
 impl<'a, T: 'a, U: 'a> Mappable<T, U, SyncPhaseGuard<'a, U>> for SyncPhaseGuard<'a, T> {
@@ -190,12 +228,24 @@ impl<'a, T> Deref for SyncReadPhaseGuard<'a, T> {
 impl<'a, T: ?Sized> SyncReadPhaseGuard<'a, T> {
     #[inline(always)]
     fn new(r: &'a T, lock: ReadLock<'a>) -> Self {
-        Self(r, lock)
+        #[cfg(feature = "deadlock_detection")]
+        let held = Held::new(lock.futex as *const _ as usize);
+        Self(
+            r,
+            lock,
+            #[cfg(feature = "deadlock_detection")]
+            held,
+        )
     }
 
     #[inline(always)]
     pub fn map<S: ?Sized>(self, f: impl FnOnce(&'a T) -> &'a S) -> SyncReadPhaseGuard<'a, S> {
-        SyncReadPhaseGuard(f(self.0), self.1)
+        SyncReadPhaseGuard(
+            f(self.0),
+            self.1,
+            #[cfg(feature = "deadlock_detection")]
+            self.2,
+        )
     }
 }
 impl<'a, T: 'a, U: 'a> Mappable<T, U, SyncReadPhaseGuard<'a, U>> for SyncReadPhaseGuard<'a, T> {
@@ -207,7 +257,12 @@ impl<'a, T: 'a, U: 'a> Mappable<T, U, SyncReadPhaseGuard<'a, U>> for SyncReadPha
 impl<'a, T> From<SyncPhaseGuard<'a, T>> for SyncReadPhaseGuard<'a, T> {
     #[inline(always)]
     fn from(this: SyncPhaseGuard<'a, T>) -> SyncReadPhaseGuard<'a, T> {
-        SyncReadPhaseGuard(this.0, this.1.into())
+        SyncReadPhaseGuard(
+            this.0,
+            this.1.into(),
+            #[cfg(feature = "deadlock_detection")]
+            this.2,
+        )
     }
 }
 
@@ -220,7 +275,79 @@ impl<'a, T> Phased for SyncReadPhaseGuard<'a, T> {
 
 impl<'a, T> Clone for SyncReadPhaseGuard<'a, T> {
     fn clone(&self) -> Self {
-        SyncReadPhaseGuard(self.0, self.1.clone())
+        SyncReadPhaseGuard(
+            self.0,
+            self.1.clone(),
+            #[cfg(feature = "deadlock_detection")]
+            self.2.clone(),
+        )
+    }
+}
+
+// SyncUpgradableReadPhaseGuard
+//-------------------
+//
+impl<'a, T> Deref for SyncUpgradableReadPhaseGuard<'a, T> {
+    type Target = T;
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        self.0
+    }
+}
+
+impl<'a, T: ?Sized> SyncUpgradableReadPhaseGuard<'a, T> {
+    #[inline(always)]
+    fn new(r: &'a T, lock: UpgradableReadLock<'a>) -> Self {
+        #[cfg(feature = "deadlock_detection")]
+        let held = Held::new(lock.futex as *const _ as usize);
+        Self(
+            r,
+            lock,
+            #[cfg(feature = "deadlock_detection")]
+            held,
+        )
+    }
+    #[inline(always)]
+    /// Wait for other readers to release their locks and turn
+    /// this lock into a write lock.
+    pub fn upgrade(self) -> SyncPhaseGuard<'a, T> {
+        #[cfg(feature = "deadlock_detection")]
+        let _waiting = Waiting::enter(self.1.futex as *const _ as usize, true);
+        SyncPhaseGuard(
+            self.0,
+            self.1.upgrade(),
+            #[cfg(feature = "deadlock_detection")]
+            self.2,
+        )
+    }
+}
+impl<'a, T> From<SyncPhaseGuard<'a, T>> for SyncUpgradableReadPhaseGuard<'a, T> {
+    #[inline(always)]
+    fn from(this: SyncPhaseGuard<'a, T>) -> SyncUpgradableReadPhaseGuard<'a, T> {
+        SyncUpgradableReadPhaseGuard(
+            this.0,
+            this.1.into(),
+            #[cfg(feature = "deadlock_detection")]
+            this.2,
+        )
+    }
+}
+impl<'a, T> From<SyncUpgradableReadPhaseGuard<'a, T>> for SyncReadPhaseGuard<'a, T> {
+    #[inline(always)]
+    fn from(this: SyncUpgradableReadPhaseGuard<'a, T>) -> SyncReadPhaseGuard<'a, T> {
+        SyncReadPhaseGuard(
+            this.0,
+            this.1.into(),
+            #[cfg(feature = "deadlock_detection")]
+            this.2,
+        )
+    }
+}
+
+impl<'a, T> Phased for SyncUpgradableReadPhaseGuard<'a, T> {
+    #[inline(always)]
+    fn phase(this: &Self) -> Phase {
+        this.1.init_phase
     }
 }
 
@@ -273,6 +400,10 @@ impl<'a, T> DerefMut for MutexGuard<'a, T> {
 // STATES:
 // LOCKED_BIT | <READ_WAITER_BIT|WRITE_WAITER_BIT> => Write lock held
 // any READER_BIT | <READ_WAITER_BIT|WRITE_WAITER_BIT> => Read lock held
+// any READER_BIT | UPGRADABLE_BIT | <UPGRADE_WAITER_BIT>
+//       => Read locks held, one of which is upgradable
+//       => the upgradable lock is waiting for other readers to leave
+//          if UPGRADE_WAITER_BIT
 // LOCKED_BIT|any READER_BIT | <READ_WAITER_BIT|WRITE_WAITER_BIT>
 //       => wlock or rlock is being transfered to rlock
 //       => rlock are taken right now
@@ -439,6 +570,28 @@ impl<'a> From<Lock<'a>> for ReadLock<'a> {
     }
 }
 
+impl<'a> From<Lock<'a>> for UpgradableReadLock<'a> {
+    #[inline(always)]
+    fn from(this: Lock<'a>) -> UpgradableReadLock<'a> {
+        //state: old_phase | LOCKED_BIT | <0:READ_WAITER_BIT|0:WRITE_WAITER_BIT>
+        let xor =
+            (this.init_phase ^ this.on_unlock).bits() | LOCKED_BIT | READER_UNITY | UPGRADABLE_BIT;
+        //state: phase | READER_UNITY | UPGRADABLE_BIT | <0:READ_WAITER_BIT|0:WRITE_WAITER_BIT>
+        let prev = this.futex.fetch_xor(xor, Ordering::Release);
+
+        if prev & READ_WAITER_BIT != 0 {
+            // the returned read lock is the one held by the upgradable lock
+            forget(wake_readers(&this.futex, 0, true));
+        }
+
+        let r = UpgradableReadLock::new(this.futex, this.on_unlock.bits());
+
+        forget(this);
+
+        r
+    }
+}
+
 // ReadLock
 // --------
 impl<'a> ReadLock<'a> {
@@ -486,6 +639,9 @@ impl<'a> Drop for ReadLock<'a> {
             //state: phase | READ_WAITER_BIT <|> WRITE_WAITER_BIT
             let cur = prev - READER_UNITY;
             transfer_lock(&self.futex, cur);
+        } else if prev & UPGRADE_WAITER_BIT != 0 && has_two_readers(prev) {
+            //state: phase | READER_UNITY | UPGRADABLE_BIT | UPGRADE_WAITER_BIT
+            self.futex.wake_upgrader();
         }
     }
 }
@@ -522,7 +678,7 @@ impl<'a> Clone for ReadLock<'a> {
                 }
             }
 
-            if self.futex.compare_and_wait_as_reader(cur) {
+            if self.futex.compare_and_wait_as_reader(cur, None) {
                 let cur = self.futex.load(Ordering::Relaxed);
 
                 assert_ne!(cur & (READER_BITS | READER_OVERF), 0);
@@ -536,6 +692,102 @@ impl<'a> Clone for ReadLock<'a> {
     }
 }
 
+// UpgradableReadLock
+// ------------------
+impl<'a> UpgradableReadLock<'a> {
+    #[inline(always)]
+    fn new(futex: &'a Futex, current: u32) -> Self {
+        let p = Phase::from_bits_truncate(current);
+        Self {
+            futex,
+            init_phase: p,
+        }
+    }
+
+    fn upgrade(self) -> Lock<'a> {
+        let mut spin_wait = SpinWait::new();
+        let mut cur = self.futex.load(Ordering::Relaxed);
+        loop {
+            //state: phase | READER_UNITY | UPGRADABLE_BIT | <UPGRADE_WAITER_BIT>
+            if has_one_reader(cur) && is_not_write_locked(cur) {
+                match self.futex.compare_exchange_weak(
+                    cur,
+                    ((cur - READER_UNITY) & !(UPGRADABLE_BIT | UPGRADE_WAITER_BIT)) | LOCKED_BIT,
+                    Ordering::Acquire,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        let l = Lock::new(self.futex, cur);
+                        forget(self);
+                        return l;
+                    }
+                    Err(x) => {
+                        cur = x;
+                        continue;
+                    }
+                }
+            }
+
+            if spin_wait.spin() {
+                cur = self.futex.load(Ordering::Relaxed);
+                continue;
+            }
+
+            if cur & UPGRADE_WAITER_BIT == 0 {
+                match self.futex.compare_exchange_weak(
+                    cur,
+                    cur | UPGRADE_WAITER_BIT,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Err(x) => {
+                        cur = x;
+                        continue;
+                    }
+                    Ok(_) => cur |= UPGRADE_WAITER_BIT,
+                }
+            }
+
+            //the last other reader to release its lock wakes us, or if
+            //we are the only reader, the writer that transiently holds
+            //the LOCKED_BIT while waiting for readers wakes us when it gives up
+            self.futex.compare_and_wait_as_upgrader(cur);
+
+            spin_wait.reset();
+            cur = self.futex.load(Ordering::Relaxed);
+        }
+    }
+}
+
+impl<'a> From<UpgradableReadLock<'a>> for ReadLock<'a> {
+    #[inline(always)]
+    fn from(this: UpgradableReadLock<'a>) -> ReadLock<'a> {
+        this.futex.fetch_and(!UPGRADABLE_BIT, Ordering::Relaxed);
+        let r = ReadLock {
+            futex: this.futex,
+            init_phase: this.init_phase,
+        };
+        forget(this);
+        r
+    }
+}
+
+impl<'a> Drop for UpgradableReadLock<'a> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        //state: phase | <LOCKED_BIT> | READER_UNITY*n | UPGRADABLE_BIT | <0:READ_WAITER_BIT> |<0:WRITE_WAITER_BIT>
+        let prev = self
+            .futex
+            .fetch_sub(READER_UNITY | UPGRADABLE_BIT, Ordering::Release);
+        //state: phase | <LOCKED_BIT> | READER_UNITY*(n-1) | <1:READ_WAITER_BIT> |<1:WRITE_WAITER_BIT>
+        if has_one_reader(prev) && is_not_write_locked(prev) && has_waiters(prev) {
+            //state: phase | READ_WAITER_BIT <|> WRITE_WAITER_BIT
+            let cur = prev - (READER_UNITY | UPGRADABLE_BIT);
+            transfer_lock(&self.futex, cur);
+        }
+    }
+}
+
 #[inline(always)]
 fn has_no_readers(v: u32) -> bool {
     v & (READER_OVERF | READER_BITS) == 0
@@ -551,6 +803,11 @@ fn has_one_reader(v: u32) -> bool {
     v & (READER_OVERF | READER_BITS) == READER_UNITY
 }
 
+#[inline(always)]
+fn has_two_readers(v: u32) -> bool {
+    v & (READER_OVERF | READER_BITS) == 2 * READER_UNITY
+}
+
 #[inline(always)]
 fn has_readers_max(v: u32) -> bool {
     //can actualy happen in two condition:
@@ -582,7 +839,14 @@ fn is_write_lockable(v: u32) -> bool {
 }
 #[inline(always)]
 fn is_read_lockable(v: u32) -> bool {
-    (has_readers(v) || (has_no_waiters(v) && is_not_write_locked(v))) && !has_readers_max(v)
+    //a pending upgrade only waits for the current readers to leave
+    (has_readers(v) || (has_no_waiters(v) && is_not_write_locked(v)))
+        && v & UPGRADE_WAITER_BIT == 0
+        && !has_readers_max(v)
+}
+#[inline(always)]
+fn is_upgradable_lockable(v: u32) -> bool {
+    v & UPGRADABLE_BIT == 0 && is_read_lockable(v)
 }
 
 #[inline(always)]
@@ -719,6 +983,43 @@ impl SyncPhaseLocker {
             LockResult::None(p) => LockResult::None(p),
         }
     }
+    #[cfg(feature = "timeout")]
+    #[inline(always)]
+    /// lock the phase, giving up if the lock could not be obtained before
+    /// `deadline`.
+    ///
+    /// The lock semantic is the same as the one of [lock](Self::lock). If the
+    /// lock attempt is abandoned, the returned error is the phase at the time
+    /// the deadline was reached.
+    pub fn lock_until<'a, T: ?Sized>(
+        &'a self,
+        v: &'a T,
+        how: impl Fn(Phase) -> LockNature,
+        on_waiting_how: impl Fn(Phase) -> LockNature,
+        hint: Phase,
+        deadline: Deadline,
+    ) -> Result<LockResult<SyncReadPhaseGuard<'_, T>, SyncPhaseGuard<'_, T>>, Phase> {
+        self.raw_lock_until(how, on_waiting_how, hint, Some(deadline))
+            .map(|l| match l {
+                LockResult::Write(l) => LockResult::Write(SyncPhaseGuard::new(v, l)),
+                LockResult::Read(l) => LockResult::Read(SyncReadPhaseGuard::new(v, l)),
+                LockResult::None(p) => LockResult::None(p),
+            })
+    }
+    #[inline(always)]
+    /// lock the phase for reading, with the possibility to atomically upgrade
+    /// the lock to a write lock.
+    ///
+    /// Other threads may hold read locks at the same time but only one thread
+    /// can hold an upgradable read lock. This lock call synchronize with the
+    /// phase transition that leads to the current phase and the phase will
+    /// not change while this lock is held.
+    pub fn upgradable_lock<'a, T: ?Sized>(
+        &'a self,
+        v: &'a T,
+    ) -> SyncUpgradableReadPhaseGuard<'_, T> {
+        SyncUpgradableReadPhaseGuard::new(v, self.raw_upgradable_lock())
+    }
     #[inline(always)]
     /// try to lock the phase.
     ///
@@ -823,8 +1124,23 @@ impl SyncPhaseLocker {
         on_waiting_how: impl Fn(Phase) -> LockNature,
         hint: Phase,
     ) -> LockResult<ReadLock<'_>, Lock<'_>> {
+        match self.raw_lock_until(how, on_waiting_how, hint, None) {
+            Ok(l) => l,
+            // without deadline the lock attempt is never abandoned
+            Err(_) => unsafe { unreachable_unchecked() },
+        }
+    }
+
+    #[inline(always)]
+    fn raw_lock_until(
+        &self,
+        how: impl Fn(Phase) -> LockNature,
+        on_waiting_how: impl Fn(Phase) -> LockNature,
+        hint: Phase,
+        deadline: Option<Deadline>,
+    ) -> Result<LockResult<ReadLock<'_>, Lock<'_>>, Phase> {
         let cur = match self.optimistic_lock(&how, hint) {
-            Ok(x) => return x,
+            Ok(x) => return Ok(x),
             Err(cur) => cur,
         };
 
@@ -849,7 +1165,7 @@ impl SyncPhaseLocker {
                         )
                         .is_ok()
                 {
-                    return LockResult::Write(Lock::new(&self.0, cur));
+                    return Ok(LockResult::Write(Lock::new(&self.0, cur)));
                 }
             }
             LockNature::Read => {
@@ -862,13 +1178,13 @@ impl SyncPhaseLocker {
                         },
                         cur,
                     ) {
-                        return LockResult::Read(r);
+                        return Ok(LockResult::Read(r));
                     }
                 }
             }
             LockNature::None => {
                 fence(Ordering::Acquire);
-                return LockResult::None(p);
+                return Ok(LockResult::None(p));
             }
         }
         #[cfg(feature = "lock_statistics")]
@@ -876,14 +1192,18 @@ impl SyncPhaseLocker {
             SECOND_ATTEMPT_FAILURES.fetch_add(1, Ordering::Relaxed);
         }
 
-        self.raw_lock_slow(how, on_waiting_how)
+        self.raw_lock_slow(how, on_waiting_how, deadline)
     }
     #[cold]
     fn raw_lock_slow(
         &self,
         how: impl Fn(Phase) -> LockNature,
         on_waiting_how: impl Fn(Phase) -> LockNature,
-    ) -> LockResult<ReadLock<'_>, Lock<'_>> {
+        deadline: Option<Deadline>,
+    ) -> Result<LockResult<ReadLock<'_>, Lock<'_>>, Phase> {
+        #[cfg(feature = "deadlock_detection")]
+        let _waiting = Waiting::enter(&self.0 as *const _ as usize, false);
+
         let mut spin_wait = SpinWait::new();
 
         let mut cur = self.0.load(Ordering::Relaxed);
@@ -892,7 +1212,7 @@ impl SyncPhaseLocker {
             match how(Phase::from_bits_truncate(cur)) {
                 LockNature::None => {
                     fence(Ordering::Acquire);
-                    return LockResult::None(Phase::from_bits_truncate(cur));
+                    return Ok(LockResult::None(Phase::from_bits_truncate(cur)));
                 }
                 LockNature::Write => {
                     if is_write_lockable(cur) {
@@ -904,7 +1224,7 @@ impl SyncPhaseLocker {
                                 Ordering::Relaxed,
                             ) {
                                 Ok(_) => {
-                                    return LockResult::Write(Lock::new(&self.0, cur));
+                                    return Ok(LockResult::Write(Lock::new(&self.0, cur)));
                                 }
                                 Err(x) => {
                                     cur = x;
@@ -927,7 +1247,7 @@ impl SyncPhaseLocker {
                             }
 
                             cur = match wait_for_readers(&self.0, cur) {
-                                Ok(l) => return LockResult::Write(l),
+                                Ok(l) => return Ok(LockResult::Write(l)),
                                 Err(cur) => cur,
                             };
                             #[cfg(feature = "lock_statistics")]
@@ -951,7 +1271,7 @@ impl SyncPhaseLocker {
                             },
                             cur,
                         ) {
-                            Ok(r) => return LockResult::Read(r),
+                            Ok(r) => return Ok(LockResult::Read(r)),
                             Err(cur) => cur,
                         };
                     }
@@ -966,7 +1286,7 @@ impl SyncPhaseLocker {
             match on_waiting_how(Phase::from_bits_truncate(cur)) {
                 LockNature::None => {
                     fence(Ordering::Acquire);
-                    return LockResult::None(Phase::from_bits_truncate(cur));
+                    return Ok(LockResult::None(Phase::from_bits_truncate(cur)));
                 }
 
                 LockNature::Write => {
@@ -985,7 +1305,9 @@ impl SyncPhaseLocker {
                         }
                     }
 
-                    if let Some(lock) = wait_as_writer_then_wake_with_lock(&self.0, cur, &how) {
+                    if let Some(lock) =
+                        wait_as_writer_then_wake_with_lock(&self.0, cur, &how, deadline)
+                    {
                         #[cfg(feature = "lock_statistics")]
                         {
                             WRITE_WAIT_SUCCESSES.fetch_add(1, Ordering::Relaxed);
@@ -993,7 +1315,7 @@ impl SyncPhaseLocker {
                                 ADDAPTATIVE_WAIT_SUCCESSES.fetch_add(1, Ordering::Relaxed);
                             }
                         }
-                        return lock;
+                        return Ok(lock);
                     } else {
                         #[cfg(feature = "lock_statistics")]
                         {
@@ -1017,7 +1339,9 @@ impl SyncPhaseLocker {
                         }
                     }
 
-                    if let Some(lock) = wait_as_reader_then_wake_with_lock(&self.0, cur, &how) {
+                    if let Some(lock) =
+                        wait_as_reader_then_wake_with_lock(&self.0, cur, &how, deadline)
+                    {
                         #[cfg(feature = "lock_statistics")]
                         {
                             READ_WAIT_SUCCESSES.fetch_add(1, Ordering::Relaxed);
@@ -1025,7 +1349,7 @@ impl SyncPhaseLocker {
                                 ADDAPTATIVE_WAIT_SUCCESSES.fetch_add(1, Ordering::Relaxed);
                             }
                         }
-                        return lock;
+                        return Ok(lock);
                     } else {
                         #[cfg(feature = "lock_statistics")]
                         {
@@ -1034,9 +1358,43 @@ impl SyncPhaseLocker {
                     }
                 }
             }
+            cur = self.0.load(Ordering::Relaxed);
+            if deadline_elapsed(deadline) {
+                return Err(Phase::from_bits_truncate(cur));
+            }
             spin_wait.reset();
+        }
+    }
+
+    fn raw_upgradable_lock(&self) -> UpgradableReadLock<'_> {
+        let mut spin_wait = SpinWait::new();
+        let mut cur = self.0.load(Ordering::Relaxed);
+        loop {
+            if is_upgradable_lockable(cur) {
+                match self.0.compare_exchange_weak(
+                    cur,
+                    cur + (READER_UNITY | UPGRADABLE_BIT),
+                    Ordering::Acquire,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return UpgradableReadLock::new(&self.0, cur),
+                    Err(x) => {
+                        cur = x;
+                        continue;
+                    }
+                }
+            }
+            if !spin_wait.spin() {
+                break;
+            }
             cur = self.0.load(Ordering::Relaxed);
         }
+        //Another upgradable lock is held: waiting for a write lock
+        //ensures it has been released.
+        match self.raw_lock(|_| LockNature::Write, |_| LockNature::Write, Phase::empty()) {
+            LockResult::Write(l) => l.into(),
+            _ => unreachable!(),
+        }
     }
 
     #[inline(always)]
@@ -1122,10 +1480,11 @@ fn wait_as_writer_then_wake_with_lock(
     futex: &Futex,
     cur: u32,
     how: impl Fn(Phase) -> LockNature,
+    deadline: Option<Deadline>,
 ) -> Option<LockResult<ReadLock<'_>, Lock<'_>>> {
     debug_assert_ne!(cur & WRITE_WAITER_BIT, 0);
 
-    if futex.compare_and_wait_as_writer(cur) {
+    if futex.compare_and_wait_as_writer(cur, deadline) {
         let cur = futex.load(Ordering::Relaxed);
 
         assert_ne!(cur & LOCKED_BIT, 0);
@@ -1161,10 +1520,11 @@ fn wait_as_reader_then_wake_with_lock(
     futex: &Futex,
     cur: u32,
     how: impl Fn(Phase) -> LockNature,
+    deadline: Option<Deadline>,
 ) -> Option<LockResult<ReadLock<'_>, Lock<'_>>> {
     debug_assert_ne!(cur & READ_WAITER_BIT, 0);
 
-    if futex.compare_and_wait_as_reader(cur) {
+    if futex.compare_and_wait_as_reader(cur, deadline) {
         let cur = futex.load(Ordering::Relaxed);
 
         assert_ne!(cur & (READER_BITS | READER_OVERF), 0);
@@ -1217,5 +1577,9 @@ fn wait_for_readers(futex: &Futex, mut cur: u32) -> Result<Lock<'_>, u32> {
             }
         }
     }
+    if cur & UPGRADE_WAITER_BIT != 0 {
+        //an upgrade was waiting for the LOCKED_BIT to be released
+        futex.wake_upgrader();
+    }
     Err(cur)
 }
@@ -1,12 +1,17 @@
 use super::spin_wait::SpinWait;
+use super::{deadline_elapsed, Deadline};
 use super::{LockNature, LockResult, Mappable, MutPhaseLocker, PhaseGuard, PhaseLocker};
 use crate::phase::*;
 use crate::{Phase, Phased};
 use core::cell::UnsafeCell;
+use core::hint::unreachable_unchecked;
 use core::mem::forget;
 use core::ops::{Deref, DerefMut};
 use core::sync::atomic::{fence, AtomicU32, Ordering};
 
+#[cfg(feature = "deadlock_detection")]
+use crate::deadlock::{Held, Waiting};
+
 /// A synchronised phase locker.
 pub(crate) struct SyncPhaseLocker(AtomicU32);
 
@@ -18,7 +23,11 @@ pub(crate) struct Lock<'a> {
 
 /// A phase guard that allow atomic phase transition that
 /// can be turned fastly into a [SyncReadPhaseGuard].
-pub(crate) struct SyncPhaseGuard<'a, T: ?Sized>(&'a T, Lock<'a>);
+pub(crate) struct SyncPhaseGuard<'a, T: ?Sized>(
+    &'a T,
+    Lock<'a>,
+    #[cfg(feature = "deadlock_detection")] Held,
+);
 
 pub(crate) struct ReadLock<'a> {
     futex: &'a AtomicU32,
@@ -26,7 +35,24 @@ pub(crate) struct ReadLock<'a> {
 }
 
 /// A kind of read lock.
-pub(crate) struct SyncReadPhaseGuard<'a, T: ?Sized>(&'a T, ReadLock<'a>);
+pub(crate) struct SyncReadPhaseGuard<'a, T: ?Sized>(
+    &'a T,
+    ReadLock<'a>,
+    #[cfg(feature = "deadlock_detection")] Held,
+);
+
+pub(crate) struct UpgradableReadLock<'a> {
+    futex: &'a AtomicU32,
+    init_phase: Phase,
+}
+
+/// A read lock that is exclusive with other upgradable read locks
+/// and that can be atomically turned into a [SyncPhaseGuard].
+pub(crate) struct SyncUpgradableReadPhaseGuard<'a, T: ?Sized>(
+    &'a T,
+    UpgradableReadLock<'a>,
+    #[cfg(feature = "deadlock_detection")] Held,
+);
 
 pub(crate) struct Mutex<T>(UnsafeCell<T>, SyncPhaseLocker);
 
@@ -46,12 +72,24 @@ impl<'a, T> Deref for SyncPhaseGuard<'a, T> {
 impl<'a, T: ?Sized> SyncPhaseGuard<'a, T> {
     #[inline(always)]
     fn new(r: &'a T, lock: Lock<'a>) -> Self {
-        Self(r, lock)
+        #[cfg(feature = "deadlock_detection")]
+        let held = Held::new(lock.futex as *const _ as usize);
+        Self(
+            r,
+            lock,
+            #[cfg(feature = "deadlock_detection")]
+            held,
+        )
     }
 
     #[inline(always)]
     pub fn map<S: ?Sized>(self, f: impl FnOnce(&'a T) -> &'a S) -> SyncPhaseGuard<'a, S> {
-        SyncPhaseGuard(f(self.0), self.1)
+        SyncPhaseGuard(
+            f(self.0),
+            self.1,
+            #[cfg(feature = "deadlock_detection")]
+            self.2,
+        )
     }
 }
 impl<'a, T: 'a, U: 'a> Mappable<T, U, SyncPhaseGuard<'a, U>> for SyncPhaseGuard<'a, T> {
@@ -111,12 +149,24 @@ impl<'a, T> Deref for SyncReadPhaseGuard<'a, T> {
 impl<'a, T: ?Sized> SyncReadPhaseGuard<'a, T> {
     #[inline(always)]
     fn new(r: &'a T, lock: ReadLock<'a>) -> Self {
-        Self(r, lock)
+        #[cfg(feature = "deadlock_detection")]
+        let held = Held::new(lock.futex as *const _ as usize);
+        Self(
+            r,
+            lock,
+            #[cfg(feature = "deadlock_detection")]
+            held,
+        )
     }
 
     #[inline(always)]
     pub fn map<S: ?Sized>(self, f: impl FnOnce(&'a T) -> &'a S) -> SyncReadPhaseGuard<'a, S> {
-        SyncReadPhaseGuard(f(self.0), self.1)
+        SyncReadPhaseGuard(
+            f(self.0),
+            self.1,
+            #[cfg(feature = "deadlock_detection")]
+            self.2,
+        )
     }
 }
 impl<'a, T: 'a, U: 'a> Mappable<T, U, SyncReadPhaseGuard<'a, U>> for SyncReadPhaseGuard<'a, T> {
@@ -128,7 +178,12 @@ impl<'a, T: 'a, U: 'a> Mappable<T, U, SyncReadPhaseGuard<'a, U>> for SyncReadPha
 impl<'a, T> From<SyncPhaseGuard<'a, T>> for SyncReadPhaseGuard<'a, T> {
     #[inline(always)]
     fn from(this: SyncPhaseGuard<'a, T>) -> SyncReadPhaseGuard<'a, T> {
-        SyncReadPhaseGuard(this.0, this.1.into())
+        SyncReadPhaseGuard(
+            this.0,
+            this.1.into(),
+            #[cfg(feature = "deadlock_detection")]
+            this.2,
+        )
     }
 }
 
@@ -142,7 +197,79 @@ impl<'a, T> Phased for SyncReadPhaseGuard<'a, T> {
 impl<'a, T> Clone for SyncReadPhaseGuard<'a, T> {
     #[inline(always)]
     fn clone(&self) -> Self {
-        Self(self.0, self.1.clone())
+        Self(
+            self.0,
+            self.1.clone(),
+            #[cfg(feature = "deadlock_detection")]
+            self.2.clone(),
+        )
+    }
+}
+
+// SyncUpgradableReadPhaseGuard
+//-------------------
+//
+impl<'a, T> Deref for SyncUpgradableReadPhaseGuard<'a, T> {
+    type Target = T;
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        self.0
+    }
+}
+
+impl<'a, T: ?Sized> SyncUpgradableReadPhaseGuard<'a, T> {
+    #[inline(always)]
+    fn new(r: &'a T, lock: UpgradableReadLock<'a>) -> Self {
+        #[cfg(feature = "deadlock_detection")]
+        let held = Held::new(lock.futex as *const _ as usize);
+        Self(
+            r,
+            lock,
+            #[cfg(feature = "deadlock_detection")]
+            held,
+        )
+    }
+    #[inline(always)]
+    /// Wait for other readers to release their locks and turn
+    /// this lock into a write lock.
+    pub fn upgrade(self) -> SyncPhaseGuard<'a, T> {
+        #[cfg(feature = "deadlock_detection")]
+        let _waiting = Waiting::enter(self.1.futex as *const _ as usize, true);
+        SyncPhaseGuard(
+            self.0,
+            self.1.upgrade(),
+            #[cfg(feature = "deadlock_detection")]
+            self.2,
+        )
+    }
+}
+impl<'a, T> From<SyncPhaseGuard<'a, T>> for SyncUpgradableReadPhaseGuard<'a, T> {
+    #[inline(always)]
+    fn from(this: SyncPhaseGuard<'a, T>) -> SyncUpgradableReadPhaseGuard<'a, T> {
+        SyncUpgradableReadPhaseGuard(
+            this.0,
+            this.1.into(),
+            #[cfg(feature = "deadlock_detection")]
+            this.2,
+        )
+    }
+}
+impl<'a, T> From<SyncUpgradableReadPhaseGuard<'a, T>> for SyncReadPhaseGuard<'a, T> {
+    #[inline(always)]
+    fn from(this: SyncUpgradableReadPhaseGuard<'a, T>) -> SyncReadPhaseGuard<'a, T> {
+        SyncReadPhaseGuard(
+            this.0,
+            this.1.into(),
+            #[cfg(feature = "deadlock_detection")]
+            this.2,
+        )
+    }
+}
+
+impl<'a, T> Phased for SyncUpgradableReadPhaseGuard<'a, T> {
+    #[inline(always)]
+    fn phase(this: &Self) -> Phase {
+        this.1.init_phase
     }
 }
 
@@ -314,11 +441,93 @@ impl<'a> Drop for ReadLock<'a> {
     }
 }
 
+impl<'a> From<Lock<'a>> for UpgradableReadLock<'a> {
+    #[inline(always)]
+    fn from(this: Lock<'a>) -> UpgradableReadLock<'a> {
+        //state: old_phase | LOCKED_BIT
+        let xor =
+            (this.init_phase ^ this.on_unlock).bits() | LOCKED_BIT | READER_UNITY | UPGRADABLE_BIT;
+        //state: phase | READER_UNITY | UPGRADABLE_BIT
+        this.futex.fetch_xor(xor, Ordering::Release);
+
+        let r = UpgradableReadLock {
+            futex: this.futex,
+            init_phase: this.on_unlock,
+        };
+
+        forget(this);
+
+        r
+    }
+}
+
+// UpgradableReadLock
+// ------------------
+impl<'a> UpgradableReadLock<'a> {
+    fn upgrade(self) -> Lock<'a> {
+        let mut spin_wait = SpinWait::new();
+        let mut cur = self.futex.load(Ordering::Relaxed);
+        loop {
+            //state: phase | READER_UNITY | UPGRADABLE_BIT | <UPGRADE_WAITER_BIT>
+            if has_one_reader(cur) {
+                match self.futex.compare_exchange_weak(
+                    cur,
+                    ((cur - READER_UNITY) & !(UPGRADABLE_BIT | UPGRADE_WAITER_BIT)) | LOCKED_BIT,
+                    Ordering::Acquire,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        let l = Lock::new(self.futex, cur);
+                        forget(self);
+                        return l;
+                    }
+                    Err(x) => {
+                        cur = x;
+                        continue;
+                    }
+                }
+            }
+            //prevent new readers from starving the upgrade
+            if cur & UPGRADE_WAITER_BIT == 0 {
+                self.futex.fetch_or(UPGRADE_WAITER_BIT, Ordering::Relaxed);
+            }
+            spin_wait.spin_no_yield();
+            cur = self.futex.load(Ordering::Relaxed);
+        }
+    }
+}
+
+impl<'a> From<UpgradableReadLock<'a>> for ReadLock<'a> {
+    #[inline(always)]
+    fn from(this: UpgradableReadLock<'a>) -> ReadLock<'a> {
+        this.futex.fetch_and(!UPGRADABLE_BIT, Ordering::Relaxed);
+        let r = ReadLock {
+            futex: this.futex,
+            init_phase: this.init_phase,
+        };
+        forget(this);
+        r
+    }
+}
+
+impl<'a> Drop for UpgradableReadLock<'a> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        self.futex
+            .fetch_sub(READER_UNITY | UPGRADABLE_BIT, Ordering::Release);
+    }
+}
+
 #[inline(always)]
 fn has_no_readers(v: u32) -> bool {
     v & (READER_OVERF | READER_BITS) == 0
 }
 
+#[inline(always)]
+fn has_one_reader(v: u32) -> bool {
+    v & (READER_OVERF | READER_BITS) == READER_UNITY
+}
+
 #[inline(always)]
 fn has_readers_max(v: u32) -> bool {
     //can actualy happen in two condition:
@@ -338,7 +547,11 @@ fn is_write_lockable(v: u32) -> bool {
 }
 #[inline(always)]
 fn is_read_lockable(v: u32) -> bool {
-    is_not_write_locked(v) && !has_readers_max(v)
+    is_not_write_locked(v) && v & UPGRADE_WAITER_BIT == 0 && !has_readers_max(v)
+}
+#[inline(always)]
+fn is_upgradable_lockable(v: u32) -> bool {
+    v & UPGRADABLE_BIT == 0 && is_read_lockable(v)
 }
 
 // SyncPhaseLocker
@@ -457,6 +670,70 @@ impl SyncPhaseLocker {
             LockResult::None(p) => LockResult::None(p),
         }
     }
+    #[cfg(feature = "timeout")]
+    #[inline(always)]
+    /// lock the phase, giving up if the lock could not be obtained before
+    /// `deadline`.
+    ///
+    /// The lock semantic is the same as the one of [lock](Self::lock). If the
+    /// lock attempt is abandoned, the returned error is the phase at the time
+    /// the deadline was reached.
+    pub fn lock_until<'a, T: ?Sized>(
+        &'a self,
+        v: &'a T,
+        how: impl Fn(Phase) -> LockNature,
+        on_waiting_how: impl Fn(Phase) -> LockNature,
+        hint: Phase,
+        deadline: Deadline,
+    ) -> Result<LockResult<SyncReadPhaseGuard<'_, T>, SyncPhaseGuard<'_, T>>, Phase> {
+        self.raw_lock_until(how, on_waiting_how, hint, Some(deadline))
+            .map(|l| match l {
+                LockResult::Write(l) => LockResult::Write(SyncPhaseGuard::new(v, l)),
+                LockResult::Read(l) => LockResult::Read(SyncReadPhaseGuard::new(v, l)),
+                LockResult::None(p) => LockResult::None(p),
+            })
+    }
+    #[inline(always)]
+    /// lock the phase for reading, with the possibility to atomically upgrade
+    /// the lock to a write lock.
+    ///
+    /// Other threads may hold read locks at the same time but only one thread
+    /// can hold an upgradable read lock. This lock call synchronize with the
+    /// phase transition that leads to the current phase and the phase will
+    /// not change while this lock is held.
+    pub fn upgradable_lock<'a, T: ?Sized>(
+        &'a self,
+        v: &'a T,
+    ) -> SyncUpgradableReadPhaseGuard<'_, T> {
+        let mut spin_wait = SpinWait::new();
+        let mut cur = self.0.load(Ordering::Relaxed);
+        loop {
+            if is_upgradable_lockable(cur) {
+                match self.0.compare_exchange_weak(
+                    cur,
+                    cur + (READER_UNITY | UPGRADABLE_BIT),
+                    Ordering::Acquire,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        return SyncUpgradableReadPhaseGuard::new(
+                            v,
+                            UpgradableReadLock {
+                                futex: &self.0,
+                                init_phase: Phase::from_bits_truncate(cur),
+                            },
+                        )
+                    }
+                    Err(x) => {
+                        cur = x;
+                        continue;
+                    }
+                }
+            }
+            spin_wait.spin_no_yield();
+            cur = self.0.load(Ordering::Relaxed);
+        }
+    }
     #[inline(always)]
     /// try to lock the phase.
     ///
@@ -581,16 +858,31 @@ impl SyncPhaseLocker {
     fn raw_lock(
         &self,
         how: impl Fn(Phase) -> LockNature,
-        _on_waiting_how: impl Fn(Phase) -> LockNature,
+        on_waiting_how: impl Fn(Phase) -> LockNature,
         hint: Phase,
     ) -> LockResult<ReadLock<'_>, Lock<'_>> {
+        match self.raw_lock_until(how, on_waiting_how, hint, None) {
+            Ok(l) => l,
+            // without deadline the lock attempt is never abandoned
+            Err(_) => unsafe { unreachable_unchecked() },
+        }
+    }
+
+    #[inline(always)]
+    fn raw_lock_until(
+        &self,
+        how: impl Fn(Phase) -> LockNature,
+        _on_waiting_how: impl Fn(Phase) -> LockNature,
+        hint: Phase,
+        deadline: Option<Deadline>,
+    ) -> Result<LockResult<ReadLock<'_>, Lock<'_>>, Phase> {
         let mut cur = hint.bits();
         match how(hint) {
             LockNature::None => {
                 cur = self.0.load(Ordering::Acquire);
                 let p = Phase::from_bits_truncate(cur);
                 if let LockNature::None = how(p) {
-                    return LockResult::None(p);
+                    return Ok(LockResult::None(p));
                 }
             }
             LockNature::Write => {
@@ -600,7 +892,7 @@ impl SyncPhaseLocker {
                     Ordering::Acquire,
                     Ordering::Relaxed,
                 ) {
-                    Ok(_) => return LockResult::Write(Lock::new(&self.0, cur)),
+                    Ok(_) => return Ok(LockResult::Write(Lock::new(&self.0, cur))),
                     Err(x) => {
                         cur = x;
                     }
@@ -614,7 +906,7 @@ impl SyncPhaseLocker {
                     Ordering::Relaxed,
                 ) {
                     Ok(_) => {
-                        return LockResult::Read(ReadLock::new(&self.0, cur));
+                        return Ok(LockResult::Read(ReadLock::new(&self.0, cur)));
                     }
                     Err(x) => {
                         cur = x;
@@ -622,7 +914,8 @@ impl SyncPhaseLocker {
                 }
             }
         }
-        self.raw_lock_slow(how, cur)
+
+        self.raw_lock_slow(how, cur, deadline)
     }
 
     #[cold]
@@ -630,7 +923,11 @@ impl SyncPhaseLocker {
         &self,
         how: impl Fn(Phase) -> LockNature,
         mut cur: u32,
-    ) -> LockResult<ReadLock<'_>, Lock<'_>> {
+        deadline: Option<Deadline>,
+    ) -> Result<LockResult<ReadLock<'_>, Lock<'_>>, Phase> {
+        #[cfg(feature = "deadlock_detection")]
+        let _waiting = Waiting::enter(&self.0 as *const _ as usize, false);
+
         let mut spin_wait = SpinWait::new();
         loop {
             let p = Phase::from_bits_truncate(cur);
@@ -643,7 +940,7 @@ impl SyncPhaseLocker {
                             Ordering::Acquire,
                             Ordering::Relaxed,
                         ) {
-                            Ok(_) => return LockResult::Write(Lock::new(&self.0, cur)),
+                            Ok(_) => return Ok(LockResult::Write(Lock::new(&self.0, cur))),
                             Err(x) => {
                                 cur = x;
                                 continue;
@@ -664,7 +961,7 @@ impl SyncPhaseLocker {
                             Ordering::Relaxed,
                         ) {
                             Ok(_) => {
-                                return LockResult::Read(ReadLock::new(&self.0, cur));
+                                return Ok(LockResult::Read(ReadLock::new(&self.0, cur)));
                             }
                             Err(_) => {
                                 if !spin_wait.spin_no_yield() {
@@ -680,9 +977,12 @@ impl SyncPhaseLocker {
                 }
                 LockNature::None => {
                     fence(Ordering::Acquire);
-                    return LockResult::None(p);
+                    return Ok(LockResult::None(p));
                 }
             }
+            if deadline_elapsed(deadline) {
+                return Err(p);
+            }
             spin_wait.spin_no_yield();
             cur = self.0.load(Ordering::Relaxed);
         }
@@ -0,0 +1,520 @@
+use crate::lazy::{LockedLazyAccess, UpgradableLockedLazyAccess};
+use crate::{AccessError, Phase, Phased};
+use core::convert::Infallible;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::ops::{Deref, DerefMut};
+
+#[cfg(feature = "timeout")]
+use crate::lazy::TimedLockedLazyAccess;
+#[cfg(feature = "timeout")]
+use crate::TimeoutError;
+#[cfg(feature = "timeout")]
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "stats")]
+use crate::statistics::{Instrumented, Statistics};
+
+/// A locked lazy wrapper that records panics that happen while the lazy is write locked.
+///
+/// This is the actual type of mutable statics attributed with
+/// [#[dynamic(poison)]](macro@crate::dynamic). If a panic happens while a
+/// [PoisonWriteGuard] is alive, the lazy is marked [poisoned](Phase::POISONED) when
+/// the lock is released. Then all lock methods return a [PoisonError] that still
+/// gives access to the target through [PoisonError::into_inner].
+///
+/// ```
+/// use static_init::dynamic;
+/// use std::panic::catch_unwind;
+///
+/// #[dynamic(poison)]
+/// static mut V: Vec<i32> = vec![1, 2];
+///
+/// assert!(catch_unwind(|| {
+///     let mut l = V.write().unwrap();
+///     l.push(3);
+///     panic!("half mutated");
+/// })
+/// .is_err());
+///
+/// assert!(V.is_poisoned());
+/// assert_eq!(*V.read().unwrap_err().into_inner(), vec![1, 2, 3]);
+///
+/// V.clear_poison();
+/// assert_eq!(V.read().unwrap().len(), 3);
+/// ```
+pub struct Poison<L>(L);
+
+/// The result of a lock of a [Poison] lazy.
+pub type PoisonResult<G> = Result<G, PoisonError<G>>;
+
+/// Error returned by the lock methods of a [Poison] lazy when a panic happened
+/// while it was write locked.
+pub struct PoisonError<G> {
+    guard: G,
+}
+
+/// A write lock of a [Poison] lazy that poisons the lazy if it is released while panicking.
+#[must_use = "If unused the write lock is immediatly released"]
+pub struct PoisonWriteGuard<'a, L: LockedLazyAccess<'a>> {
+    guard: L::WriteGuard,
+    panicking: bool,
+}
+
+/// A write lock on a part of the target of a [Poison] lazy, obtained with
+/// [PoisonWriteGuard::map], [PoisonWriteGuard::filter_map] or [PoisonWriteGuard::try_map].
+///
+/// It poisons the lazy if it is released while panicking.
+#[must_use = "If unused the write lock is immediatly released"]
+pub struct PoisonMappedWriteGuard<'a, L: LockedLazyAccess<'a>, U: 'a> {
+    guard: L::MappedWriteGuard<U>,
+    panicking: bool,
+}
+
+/// An upgradable read lock of a [Poison] lazy, that can be upgraded to a [PoisonWriteGuard].
+#[must_use = "If unused the upgradable read lock is immediatly released"]
+pub struct PoisonUpgradableReadGuard<'a, L: UpgradableLockedLazyAccess<'a>>(L::UpgradableReadGuard);
+
+#[inline(always)]
+fn check_poison<G: Phased>(guard: G) -> PoisonResult<G> {
+    if Phased::phase(&guard).intersects(Phase::POISONED) {
+        Err(PoisonError { guard })
+    } else {
+        Ok(guard)
+    }
+}
+
+impl<L> Poison<L> {
+    #[inline(always)]
+    /// Wrap a locked lazy.
+    pub const fn new(lazy: L) -> Self {
+        Self(lazy)
+    }
+}
+
+impl<L> Poison<L> {
+    #[inline(always)]
+    /// Initialize if necessary and returns a read lock, or a [PoisonError] if the
+    /// lazy is poisoned.
+    ///
+    /// # Panic
+    ///
+    /// Panics if initialization panics or if initialization has panicked in a previous attempt to initialize.
+    pub fn read<'a>(&'a self) -> PoisonResult<L::ReadGuard>
+    where
+        L: LockedLazyAccess<'a>,
+    {
+        check_poison(self.0.read())
+    }
+    #[inline(always)]
+    /// Initialize if necessary and returns some read lock, or a [PoisonError] if the
+    /// lazy is poisoned, if the lazy is not already write locked. If the lazy is already
+    /// write locked it returns `None`
+    ///
+    /// # Panic
+    ///
+    /// If locks succeeds, panics if initialization panics or if initialization has panicked in a previous attempt to initialize.
+    pub fn fast_read<'a>(&'a self) -> Option<PoisonResult<L::ReadGuard>>
+    where
+        L: LockedLazyAccess<'a>,
+    {
+        self.0.fast_read().map(check_poison)
+    }
+    #[inline(always)]
+    /// Get a read lock, or a [PoisonError] if the lazy is poisoned, if the lazy is initialized.
+    /// Otherwise returns an [AccessError]
+    pub fn try_read<'a>(&'a self) -> Result<PoisonResult<L::ReadGuard>, AccessError>
+    where
+        L: LockedLazyAccess<'a>,
+    {
+        self.0.try_read().map(check_poison)
+    }
+    #[inline(always)]
+    /// Initialize if necessary and returns a write lock, or a [PoisonError] if the
+    /// lazy is poisoned.
+    ///
+    /// # Panic
+    ///
+    /// Panics if initialization panics or if initialization has panicked in a previous attempt to initialize.
+    pub fn write<'a>(&'a self) -> PoisonResult<PoisonWriteGuard<'a, L>>
+    where
+        L: LockedLazyAccess<'a>,
+    {
+        check_poison(PoisonWriteGuard::new(self.0.write()))
+    }
+    #[inline(always)]
+    /// Initialize if necessary and returns some write lock, or a [PoisonError] if the
+    /// lazy is poisoned, if the lazy is not already locked. If the lazy is already
+    /// read or write locked it returns `None`
+    ///
+    /// # Panic
+    ///
+    /// If locks succeeds, panics if initialization panics or if initialization has panicked in a previous attempt to initialize.
+    pub fn fast_write<'a>(&'a self) -> Option<PoisonResult<PoisonWriteGuard<'a, L>>>
+    where
+        L: LockedLazyAccess<'a>,
+    {
+        self.0
+            .fast_write()
+            .map(|l| check_poison(PoisonWriteGuard::new(l)))
+    }
+    #[inline(always)]
+    /// Get a write lock, or a [PoisonError] if the lazy is poisoned, if the lazy is initialized.
+    /// Otherwise returns an [AccessError]
+    pub fn try_write<'a>(&'a self) -> Result<PoisonResult<PoisonWriteGuard<'a, L>>, AccessError>
+    where
+        L: LockedLazyAccess<'a>,
+    {
+        self.0
+            .try_write()
+            .map(|l| check_poison(PoisonWriteGuard::new(l)))
+    }
+    #[inline(always)]
+    /// Initialize if necessary and returns an upgradable read lock, or a [PoisonError]
+    /// if the lazy is poisoned.
+    ///
+    /// # Panic
+    ///
+    /// Panics if initialization panics or if initialization has panicked in a previous attempt to initialize.
+    pub fn upgradable_read<'a>(&'a self) -> PoisonResult<PoisonUpgradableReadGuard<'a, L>>
+    where
+        L: UpgradableLockedLazyAccess<'a>,
+    {
+        check_poison(PoisonUpgradableReadGuard(self.0.upgradable_read()))
+    }
+    #[cfg(feature = "timeout")]
+    #[inline(always)]
+    /// Initialize if necessary and returns a read lock, or a [PoisonError] if the
+    /// lazy is poisoned, or an error if the lock could not be obtained within `timeout`.
+    ///
+    /// # Panic
+    ///
+    /// Panics if initialization panics or if initialization has panicked in a previous attempt to initialize.
+    pub fn read_for<'a>(
+        &'a self,
+        timeout: Duration,
+    ) -> Result<PoisonResult<L::ReadGuard>, TimeoutError>
+    where
+        L: TimedLockedLazyAccess<'a>,
+    {
+        match Instant::now().checked_add(timeout) {
+            Some(deadline) => self.read_until(deadline),
+            None => Ok(self.read()),
+        }
+    }
+    #[cfg(feature = "timeout")]
+    #[inline(always)]
+    /// Initialize if necessary and returns a write lock, or a [PoisonError] if the
+    /// lazy is poisoned, or an error if the lock could not be obtained within `timeout`.
+    ///
+    /// # Panic
+    ///
+    /// Panics if initialization panics or if initialization has panicked in a previous attempt to initialize.
+    pub fn write_for<'a>(
+        &'a self,
+        timeout: Duration,
+    ) -> Result<PoisonResult<PoisonWriteGuard<'a, L>>, TimeoutError>
+    where
+        L: TimedLockedLazyAccess<'a>,
+    {
+        match Instant::now().checked_add(timeout) {
+            Some(deadline) => self.write_until(deadline),
+            None => Ok(self.write()),
+        }
+    }
+    #[cfg(feature = "timeout")]
+    #[inline(always)]
+    /// Initialize if necessary and returns a read lock, or a [PoisonError] if the
+    /// lazy is poisoned, or an error if the lock could not be obtained before `deadline`.
+    ///
+    /// # Panic
+    ///
+    /// Panics if initialization panics or if initialization has panicked in a previous attempt to initialize.
+    pub fn read_until<'a>(
+        &'a self,
+        deadline: Instant,
+    ) -> Result<PoisonResult<L::ReadGuard>, TimeoutError>
+    where
+        L: TimedLockedLazyAccess<'a>,
+    {
+        self.0.read_until(deadline).map(check_poison)
+    }
+    #[cfg(feature = "timeout")]
+    #[inline(always)]
+    /// Initialize if necessary and returns a write lock, or a [PoisonError] if the
+    /// lazy is poisoned, or an error if the lock could not be obtained before `deadline`.
+    ///
+    /// # Panic
+    ///
+    /// Panics if initialization panics or if initialization has panicked in a previous attempt to initialize.
+    pub fn write_until<'a>(
+        &'a self,
+        deadline: Instant,
+    ) -> Result<PoisonResult<PoisonWriteGuard<'a, L>>, TimeoutError>
+    where
+        L: TimedLockedLazyAccess<'a>,
+    {
+        self.0
+            .write_until(deadline)
+            .map(|l| check_poison(PoisonWriteGuard::new(l)))
+    }
+    #[inline(always)]
+    /// Returns true if a panic happened while the lazy was write locked.
+    pub fn is_poisoned<'a>(&'a self) -> bool
+    where
+        L: LockedLazyAccess<'a>,
+    {
+        self.0.phase().intersects(Phase::POISONED)
+    }
+    #[inline(always)]
+    /// Clear the poisoned state of the lazy.
+    pub fn clear_poison<'a>(&'a self)
+    where
+        L: LockedLazyAccess<'a>,
+    {
+        let mut l = self.0.write();
+        L::set_poisoned(&mut l, false);
+    }
+    #[inline(always)]
+    /// Returns the current phase and synchronize with the end
+    /// of the transition to the returned phase.
+    pub fn phase<'a>(&'a self) -> Phase
+    where
+        L: LockedLazyAccess<'a>,
+    {
+        self.0.phase()
+    }
+    #[inline(always)]
+    /// Initialize the lazy if no previous attempt to initialized it where performed
+    pub fn init<'a>(&'a self)
+    where
+        L: LockedLazyAccess<'a>,
+    {
+        self.0.init()
+    }
+}
+
+#[cfg(feature = "stats")]
+impl<L: Instrumented> Poison<L> {
+    #[inline(always)]
+    /// Returns the block in which lock contention on the lazy is recorded,
+    /// if it has one.
+    pub fn statistics(&self) -> Option<&'static Statistics> {
+        self.0.statistics()
+    }
+}
+
+#[cfg(feature = "stats")]
+impl<L: Instrumented> Instrumented for Poison<L> {
+    #[inline(always)]
+    fn statistics(&self) -> Option<&'static Statistics> {
+        Poison::statistics(self)
+    }
+}
+
+impl<L: Phased> Phased for Poison<L> {
+    #[inline(always)]
+    fn phase(this: &Self) -> Phase {
+        Phased::phase(&this.0)
+    }
+}
+
+impl<'a, L: LockedLazyAccess<'a>> PoisonWriteGuard<'a, L> {
+    #[inline(always)]
+    fn new(guard: L::WriteGuard) -> Self {
+        Self {
+            guard,
+            panicking: std::thread::panicking(),
+        }
+    }
+    #[inline(always)]
+    /// Make a [PoisonMappedWriteGuard] on a part of the target. The write lock is kept
+    /// until the returned guard is dropped.
+    ///
+    /// This is an associated function that needs to be used as `PoisonWriteGuard::map(guard, f)`,
+    /// so that it does not conflict with a method of the target.
+    pub fn map<U: 'a>(
+        this: Self,
+        f: impl FnOnce(&mut L::Target) -> &mut U,
+    ) -> PoisonMappedWriteGuard<'a, L, U> {
+        match Self::try_map(this, |t| Ok::<_, Infallible>(f(t))) {
+            Ok(l) => l,
+            Err((_, e)) => match e {},
+        }
+    }
+    #[inline(always)]
+    /// Make a [PoisonMappedWriteGuard] on a part of the target if `f` returns `Some`,
+    /// otherwise give back the original guard.
+    pub fn filter_map<U: 'a>(
+        this: Self,
+        f: impl FnOnce(&mut L::Target) -> Option<&mut U>,
+    ) -> Result<PoisonMappedWriteGuard<'a, L, U>, Self> {
+        Self::try_map(this, |t| f(t).ok_or(())).map_err(|(this, _)| this)
+    }
+    #[inline(always)]
+    /// Make a [PoisonMappedWriteGuard] on a part of the target if `f` returns `Ok`,
+    /// otherwise give back the original guard along with the error.
+    pub fn try_map<U: 'a, E>(
+        this: Self,
+        f: impl FnOnce(&mut L::Target) -> Result<&mut U, E>,
+    ) -> Result<PoisonMappedWriteGuard<'a, L, U>, (Self, E)> {
+        let this = core::mem::ManuallyDrop::new(this);
+        let panicking = this.panicking;
+        // SAFETY: this is not dropped so the guard is moved out only once
+        let guard = unsafe { core::ptr::read(&this.guard) };
+        match L::try_map_write(guard, f) {
+            Ok(guard) => Ok(PoisonMappedWriteGuard { guard, panicking }),
+            Err((guard, e)) => Err((Self { guard, panicking }, e)),
+        }
+    }
+}
+
+impl<'a, L: LockedLazyAccess<'a>> Deref for PoisonWriteGuard<'a, L> {
+    type Target = L::Target;
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.guard
+    }
+}
+
+impl<'a, L: LockedLazyAccess<'a>> DerefMut for PoisonWriteGuard<'a, L> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.guard
+    }
+}
+
+impl<'a, L: LockedLazyAccess<'a>> Phased for PoisonWriteGuard<'a, L> {
+    #[inline(always)]
+    fn phase(this: &Self) -> Phase {
+        Phased::phase(&this.guard)
+    }
+}
+
+impl<'a, L: LockedLazyAccess<'a>> Drop for PoisonWriteGuard<'a, L> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        if !self.panicking && std::thread::panicking() {
+            L::set_poisoned(&mut self.guard, true)
+        }
+    }
+}
+
+impl<'a, L: LockedLazyAccess<'a>> Debug for PoisonWriteGuard<'a, L>
+where
+    L::Target: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PoisonWriteGuard").field(&**self).finish()
+    }
+}
+
+impl<'a, L: LockedLazyAccess<'a>, U: 'a> Deref for PoisonMappedWriteGuard<'a, L, U> {
+    type Target = U;
+    #[inline(always)]
+    fn deref(&self) -> &U {
+        &self.guard
+    }
+}
+
+impl<'a, L: LockedLazyAccess<'a>, U: 'a> DerefMut for PoisonMappedWriteGuard<'a, L, U> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut U {
+        &mut self.guard
+    }
+}
+
+impl<'a, L: LockedLazyAccess<'a>, U: 'a> Phased for PoisonMappedWriteGuard<'a, L, U> {
+    #[inline(always)]
+    fn phase(this: &Self) -> Phase {
+        Phased::phase(&this.guard)
+    }
+}
+
+impl<'a, L: LockedLazyAccess<'a>, U: 'a> Drop for PoisonMappedWriteGuard<'a, L, U> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        if !self.panicking && std::thread::panicking() {
+            L::set_mapped_poisoned(&mut self.guard, true)
+        }
+    }
+}
+
+impl<'a, L: LockedLazyAccess<'a>, U: 'a + Debug> Debug for PoisonMappedWriteGuard<'a, L, U> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PoisonMappedWriteGuard")
+            .field(&**self)
+            .finish()
+    }
+}
+
+impl<'a, L: UpgradableLockedLazyAccess<'a>> PoisonUpgradableReadGuard<'a, L> {
+    #[inline(always)]
+    /// Wait for the other read locks to be released then turn this lock into a
+    /// write lock.
+    ///
+    /// See the `upgrade` method of the upgradable read lock of the wrapped lazy.
+    pub fn upgrade(this: Self) -> PoisonWriteGuard<'a, L> {
+        PoisonWriteGuard::new(L::upgrade(this.0))
+    }
+}
+
+impl<'a, L: UpgradableLockedLazyAccess<'a>> Deref for PoisonUpgradableReadGuard<'a, L> {
+    type Target = L::Target;
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a, L: UpgradableLockedLazyAccess<'a>> Phased for PoisonUpgradableReadGuard<'a, L> {
+    #[inline(always)]
+    fn phase(this: &Self) -> Phase {
+        Phased::phase(&this.0)
+    }
+}
+
+impl<'a, L: UpgradableLockedLazyAccess<'a>> Debug for PoisonUpgradableReadGuard<'a, L>
+where
+    L::Target: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PoisonUpgradableReadGuard")
+            .field(&**self)
+            .finish()
+    }
+}
+
+impl<G> PoisonError<G> {
+    #[inline(always)]
+    /// Returns the guard, ignoring the poisoning.
+    pub fn into_inner(self) -> G {
+        self.guard
+    }
+    #[inline(always)]
+    /// Returns a reference to the guard.
+    pub fn get_ref(&self) -> &G {
+        &self.guard
+    }
+    #[inline(always)]
+    /// Returns a mutable reference to the guard.
+    pub fn get_mut(&mut self) -> &mut G {
+        &mut self.guard
+    }
+}
+
+impl<G> Debug for PoisonError<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PoisonError").finish_non_exhaustive()
+    }
+}
+
+impl<G> Display for PoisonError<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "poisoned lazy: a panic happened while it was write locked"
+        )
+    }
+}
+
+impl<G> std::error::Error for PoisonError<G> {}
@@ -0,0 +1,196 @@
+use crate::{Phase, StaticInfo};
+use core::slice;
+use core::sync::atomic::{AtomicU32, Ordering};
+
+#[cfg(feature = "stats")]
+use crate::statistics::Statistics;
+
+/// The nodes are referenced by entries placed by the [dynamic](macro@crate::dynamic)
+/// attribute in a dedicated link section, so that registration costs nothing at
+/// program startup. Entries are options because the COFF linker may pad the section
+/// with zeros.
+type Entry = Option<&'static Node>;
+
+#[cfg(elf)]
+extern "Rust" {
+    #[link_name = "__start_static_init_registry"]
+    static REGISTRY_START: Entry;
+    #[link_name = "__stop_static_init_registry"]
+    static REGISTRY_STOP: Entry;
+}
+
+#[cfg(mach_o)]
+extern "Rust" {
+    #[link_name = "\x01section$start$__DATA$__sireg"]
+    static REGISTRY_START: Entry;
+    #[link_name = "\x01section$end$__DATA$__sireg"]
+    static REGISTRY_STOP: Entry;
+}
+
+// the linker sorts the sections .sireg$<suffix> by suffix, and entries are in .sireg$m
+#[cfg(coff)]
+#[link_section = ".sireg$a"]
+#[used]
+static REGISTRY_START: Entry = None;
+#[cfg(coff)]
+#[link_section = ".sireg$z"]
+#[used]
+static REGISTRY_STOP: Entry = None;
+
+// ensures the section exists, and so that its bounds are defined, if no static
+// is registered
+#[cfg(elf)]
+#[link_section = "static_init_registry"]
+#[used]
+static EMPTY: Entry = None;
+#[cfg(mach_o)]
+#[link_section = "__DATA,__sireg,regular,no_dead_strip"]
+#[used]
+static EMPTY: Entry = None;
+
+/// The entries of the registry section
+fn entries() -> &'static [Entry] {
+    #[cfg(any(elf, mach_o))]
+    core::hint::black_box(&EMPTY);
+    // SAFETY: the linker places the entries between the bounds of the section
+    unsafe {
+        let start = &REGISTRY_START as *const Entry;
+        let stop = &REGISTRY_STOP as *const Entry;
+        slice::from_raw_parts(start, stop.offset_from(start) as usize)
+    }
+}
+
+enum PhaseSource {
+    /// Lazy statics know their own phase
+    Lazy(fn() -> Phase),
+    /// Raw statics do not keep track of their phase in release
+    /// build, so the phase is tracked by the node.
+    Raw(AtomicU32),
+}
+
+#[doc(hidden)]
+/// A registry entry, generated by the [dynamic](macro@crate::dynamic) attribute.
+pub struct Node {
+    info: StaticInfo,
+    phase: PhaseSource,
+    #[cfg(feature = "stats")]
+    statistics: Option<fn() -> &'static Statistics>,
+}
+
+impl Node {
+    #[inline]
+    /// Entry for a lazy static, `phase` shall return the phase of the static.
+    pub const fn new(info: StaticInfo, phase: fn() -> Phase) -> Self {
+        Self {
+            info,
+            phase: PhaseSource::Lazy(phase),
+            #[cfg(feature = "stats")]
+            statistics: None,
+        }
+    }
+    #[inline]
+    /// Entry for a raw static whose phase is updated with [`set_phase`](Self::set_phase).
+    pub const fn new_raw(info: StaticInfo, phase: Phase) -> Self {
+        Self {
+            info,
+            phase: PhaseSource::Raw(AtomicU32::new(phase.bits())),
+            #[cfg(feature = "stats")]
+            statistics: None,
+        }
+    }
+    #[cfg(feature = "stats")]
+    #[inline]
+    /// Link the lock statistics of the static to the entry, `statistics` shall be
+    /// the [STATISTICS](crate::GeneratorTolerance::STATISTICS) of its generator.
+    pub const fn with_statistics(self, statistics: Option<fn() -> &'static Statistics>) -> Self {
+        Self { statistics, ..self }
+    }
+    #[inline]
+    /// Update the phase of a raw static entry.
+    pub fn set_phase(this: &Self, p: Phase) {
+        if let PhaseSource::Raw(v) = &this.phase {
+            v.store(p.bits(), Ordering::Release)
+        }
+    }
+    #[inline]
+    fn phase(&self) -> Phase {
+        match &self.phase {
+            PhaseSource::Lazy(f) => f(),
+            PhaseSource::Raw(v) => Phase::from_bits_truncate(v.load(Ordering::Acquire)),
+        }
+    }
+}
+
+/// Iterator over registered statics returned by [`iter`].
+pub struct Iter {
+    entries: slice::Iter<'static, Entry>,
+}
+
+impl Iterator for Iter {
+    type Item = (&'static StaticInfo, Phase);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries
+            .by_ref()
+            .flatten()
+            .next()
+            .map(|node| (&node.info, node.phase()))
+    }
+}
+
+/// Returns an iterator over the [info](StaticInfo) and the current [phase](Phase)
+/// of all the statics declared with the [dynamic](macro@crate::dynamic) attribute.
+///
+/// Each static is registered at link time, so all statics are already registered
+/// when the first program constructor runs. Statics are iterated in the order the
+/// linker placed them. The phase of a thread local static is the phase of the
+/// instance of the calling thread.
+///
+/// ```
+/// use static_init::{dynamic, registry, Phase};
+///
+/// #[dynamic(lazy)]
+/// static V: Vec<i32> = vec![1, 2];
+///
+/// let phase_of_v = || {
+///     registry::iter()
+///         .find(|(info, _)| info.variable_name == "V" && info.file_name == file!())
+///         .map(|(_, phase)| phase)
+/// };
+/// assert!(phase_of_v().unwrap().is_empty());
+/// assert_eq!(V.len(), 2);
+/// assert_eq!(phase_of_v(), Some(Phase::INITIALIZED));
+/// ```
+pub fn iter() -> Iter {
+    Iter {
+        entries: entries().iter(),
+    }
+}
+
+/// Returns an iterator over the [info](StaticInfo) and the lock
+/// [statistics](Statistics) of the statics declared with the
+/// [dynamic](macro@crate::dynamic) attribute and the `stats` argument.
+///
+/// ```
+/// use static_init::{dynamic, registry};
+///
+/// #[dynamic(stats)]
+/// static mut V: Vec<i32> = vec![];
+///
+/// for (info, stats) in registry::statistics() {
+///     println!(
+///         "{}:{} {}: {} contended writes, {:?} spent waiting",
+///         info.file_name,
+///         info.line,
+///         info.variable_name,
+///         stats.write_contentions(),
+///         stats.write_wait_time()
+///     );
+/// }
+/// ```
+#[cfg(feature = "stats")]
+pub fn statistics() -> impl Iterator<Item = (&'static StaticInfo, &'static Statistics)> {
+    entries()
+        .iter()
+        .flatten()
+        .filter_map(|node| node.statistics.map(|s| (&node.info, s())))
+}
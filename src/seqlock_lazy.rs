@@ -0,0 +1,226 @@
+use crate::generic_lazy::{AccessError, GenericLockedLazy, LazyData, UnInited};
+use crate::lazy::InitializedChecker;
+use crate::lazy_sequentializer::SyncSequentializer;
+use crate::phase_locker::SyncPhaseLocker;
+use crate::{Generator, Phase, Phased, StaticInfo};
+
+use core::fmt::{self, Debug, Formatter};
+use core::mem::MaybeUninit;
+use core::ptr;
+use core::sync::atomic::{fence, AtomicUsize, Ordering};
+
+/// Copy the value pointed by `src` as a `MaybeUninit<T>`, so that the padding bytes
+/// of `T` are copied without being read as initialized integers.
+///
+/// The copy may be torn by a concurrent [volatile_store], so it is only assumed
+/// initialized once the sequence counter has been checked. The volatile read can
+/// neither be elided nor duplicated by the compiler.
+///
+/// # Safety
+///
+/// `src` must be valid for reads and only be concurrently written by [volatile_store].
+#[inline(always)]
+unsafe fn volatile_load<T>(src: *const T) -> MaybeUninit<T> {
+    ptr::read_volatile(src as *const MaybeUninit<T>)
+}
+
+/// Copy `v` to `dst` as a `MaybeUninit<T>`, as [volatile_load] reads it.
+///
+/// # Safety
+///
+/// `dst` must be valid for writes and only be concurrently read by [volatile_load].
+#[inline(always)]
+unsafe fn volatile_store<T>(dst: *mut T, v: T) {
+    ptr::write_volatile(dst as *mut MaybeUninit<T>, MaybeUninit::new(v))
+}
+
+/// A mutable lazy whose value is read without lock: a copy of the value is loaded
+/// optimistically, and the load is retried if a writer modified the value meanwhile.
+///
+/// Writers take the write lock of the lazy, as those of a [LockedLazy](crate::LockedLazy),
+/// and bump a sequence counter before and after they store the new value. Readers never
+/// write to the lazy, so that reading the value does not cause cache line contention.
+/// This is suited to small values that are often read and seldom written.
+///
+/// The value is copied in and out as a `MaybeUninit<T>` with volatile accesses, ordered
+/// by fences around the updates of the sequence counter. So `T` may have padding bytes,
+/// as `Config` below. A copy that overlaps a store may be torn, but it is then discarded
+/// and never used as a value of `T`.
+///
+/// This is the actual type of mutable statics attributed with
+/// [#[dynamic(seqlock)]](macro@crate::dynamic):
+///
+/// ```
+/// use static_init::dynamic;
+///
+/// #[derive(Clone, Copy)]
+/// struct Config {
+///     verbose: bool,
+///     retries: u32,
+/// }
+///
+/// #[dynamic(seqlock)]
+/// static mut CONFIG: Config = Config {
+///     verbose: false,
+///     retries: 3,
+/// };
+///
+/// assert_eq!(CONFIG.load().retries, 3);
+/// CONFIG.update(|c| c.verbose = true);
+/// assert!(CONFIG.load().verbose);
+/// ```
+pub struct SeqLockLazy<T, G = fn() -> T> {
+    __private: GenericLockedLazy<UnInited<T>, G, SyncSequentializer<G>, InitializedChecker<G>>,
+    seq: AtomicUsize,
+}
+
+impl<T, G> SeqLockLazy<T, G> {
+    #[inline(always)]
+    /// Build a new lazy whose value will be the output of `f`.
+    pub const fn from_generator(f: G) -> Self {
+        Self {
+            __private: unsafe {
+                GenericLockedLazy::new(
+                    f,
+                    SyncSequentializer::new(SyncPhaseLocker::new(Phase::empty())),
+                    UnInited::INIT,
+                )
+            },
+            seq: AtomicUsize::new(0),
+        }
+    }
+    #[inline(always)]
+    /// Build a new lazy with debug informations.
+    pub const fn from_generator_with_info(f: G, info: StaticInfo) -> Self {
+        Self {
+            __private: unsafe {
+                GenericLockedLazy::new_with_info(
+                    f,
+                    SyncSequentializer::new(SyncPhaseLocker::new(Phase::empty())),
+                    UnInited::INIT,
+                    info,
+                )
+            },
+            seq: AtomicUsize::new(0),
+        }
+    }
+    #[inline(always)]
+    /// Return the current phase
+    pub fn phase(&self) -> Phase {
+        Phased::phase(GenericLockedLazy::sequentializer(&self.__private))
+    }
+}
+
+impl<T: Copy, G: Generator<T>> SeqLockLazy<T, G> {
+    /// Copy the value, that must be initialized.
+    #[inline(always)]
+    fn load_initialized(&self) -> T {
+        let ptr = (*self.__private).get();
+        loop {
+            let seq = self.seq.load(Ordering::Acquire);
+            if seq & 1 == 0 {
+                // SAFETY: the value is initialized and only written by volatile_store
+                let v = unsafe { volatile_load(ptr) };
+                fence(Ordering::Acquire);
+                if self.seq.load(Ordering::Relaxed) == seq {
+                    // SAFETY: no store happened during the copy, so it is not torn
+                    return unsafe { v.assume_init() };
+                }
+            }
+            core::hint::spin_loop();
+        }
+    }
+    /// Initialize if necessary and returns a copy of the value.
+    ///
+    /// A load does not wait for the write lock, but it spins, without bound, while a
+    /// [store](Self::store) or the end of an [update](Self::update) is copying the new
+    /// value: if the writing thread is preempted in the middle of the copy, loads spin
+    /// until it is rescheduled.
+    ///
+    /// # Panic
+    ///
+    /// Panics if initialization panics or if initialization has panicked in a previous attempt to initialize.
+    #[inline]
+    pub fn load(&self) -> T {
+        if !self.phase().intersects(Phase::INITIALIZED) {
+            return *GenericLockedLazy::init_then_read_lock(&self.__private);
+        }
+        self.load_initialized()
+    }
+    /// Returns a copy of the value if it is initialized, otherwise an [AccessError]
+    ///
+    /// As [load](Self::load), it spins while a new value is being stored.
+    #[inline]
+    pub fn try_load(&self) -> Result<T, AccessError> {
+        let phase = self.phase();
+        if phase.intersects(Phase::INITIALIZED) {
+            Ok(self.load_initialized())
+        } else {
+            Err(AccessError { phase })
+        }
+    }
+    /// Store `v` while the write lock is held.
+    #[inline(always)]
+    fn store_locked(&self, v: T) {
+        // writers are serialized by the lock: the counter is odd while the value
+        // is being stored
+        let seq = self.seq.load(Ordering::Relaxed);
+        self.seq.store(seq.wrapping_add(1), Ordering::Relaxed);
+        fence(Ordering::Release);
+        // SAFETY: the value is initialized and the write lock is held
+        unsafe { volatile_store((*self.__private).get(), v) };
+        self.seq.store(seq.wrapping_add(2), Ordering::Release);
+    }
+    /// Initialize if necessary and replace the value.
+    ///
+    /// # Panic
+    ///
+    /// Panics if initialization panics or if initialization has panicked in a previous attempt to initialize.
+    #[inline]
+    pub fn store(&self, v: T) {
+        let _lock = GenericLockedLazy::init_then_write_lock(&self.__private);
+        self.store_locked(v)
+    }
+    /// Initialize if necessary, then call `f` on a copy of the value and store the
+    /// modified copy.
+    ///
+    /// Concurrent updates are serialized by the write lock of the lazy, while concurrent
+    /// [load](Self::load) keep returning the previous value until the modified copy is stored.
+    ///
+    /// # Panic
+    ///
+    /// Panics if initialization panics or if initialization has panicked in a previous attempt to initialize.
+    /// If `f` panics, the value is not modified.
+    #[inline]
+    pub fn update<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        let _lock = GenericLockedLazy::init_then_write_lock(&self.__private);
+        // SAFETY: the write lock is held, so the value is not concurrently written
+        let mut v = unsafe { *(*self.__private).get() };
+        let r = f(&mut v);
+        self.store_locked(v);
+        r
+    }
+    /// Initialize the lazy if no previous attempt to initialized it where performed
+    #[inline]
+    pub fn init(&self) -> Phase {
+        let _ = GenericLockedLazy::init_then_try_read_lock(&self.__private);
+        self.phase()
+    }
+}
+
+impl<T, G> Phased for SeqLockLazy<T, G> {
+    #[inline(always)]
+    fn phase(this: &Self) -> Phase {
+        this.phase()
+    }
+}
+
+impl<T, G> Debug for SeqLockLazy<T, G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.phase().intersects(Phase::INITIALIZED) {
+            write!(f, "Initialized")
+        } else {
+            write!(f, "UnInitialized")
+        }
+    }
+}
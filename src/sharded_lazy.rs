@@ -0,0 +1,250 @@
+use crate::generic_lazy::{AccessError, GenericLazy, LazyData};
+use crate::lazy_array::Entry;
+use crate::phase_locker::{SyncPhaseGuard, SyncPhaseLocker, SyncReadPhaseGuard};
+use crate::{Generator, LockNature, LockResult, Phase, Phased};
+
+use core::fmt::{self, Debug, Formatter};
+use core::ops::{Deref, DerefMut};
+
+/// The shard of the current thread.
+///
+/// Threads are given a shard in round-robin order the first time they read, so that
+/// up to `N` threads never share a shard.
+#[cfg(has_std)]
+#[inline(always)]
+fn shard_index<const N: usize>() -> usize {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    std::thread_local! {
+        static INDEX: usize = NEXT.fetch_add(1, Ordering::Relaxed);
+    }
+    // the thread local may already be destroyed when a thread exits
+    INDEX
+        .try_with(|i| *i % N)
+        .unwrap_or_else(|_| stack_shard_index::<N>())
+}
+
+/// The shard of the current thread.
+///
+/// Without thread local storage the shard is a hash of the address of a local variable.
+/// Threads run on distinct stacks so they are likely, but not guaranteed, to use
+/// distinct shards. A thread may use an other shard when its stack grows, which only
+/// costs a cache miss.
+#[cfg(not(has_std))]
+#[inline(always)]
+fn shard_index<const N: usize>() -> usize {
+    stack_shard_index::<N>()
+}
+
+#[inline(always)]
+fn stack_shard_index<const N: usize>() -> usize {
+    let local = 0u8;
+    let h = (&local as *const u8 as usize >> 16).wrapping_mul(0x9E37_79B9);
+    (h >> 16) % N
+}
+
+/// A read-write lock on its own cache line
+#[repr(align(128))]
+struct Shard(SyncPhaseLocker);
+
+impl Shard {
+    #[allow(clippy::declare_interior_mutable_const)]
+    const INIT: Self = Self(SyncPhaseLocker::new(Phase::empty()));
+}
+
+/// A mutable lazy whose lock is split into `N` shards, as a big-reader lock.
+///
+/// A reader only locks the shard of its thread, so that readers running on distinct
+/// cores do not share the cache line of a lock. A writer locks all the shards. This
+/// makes reads cheaper than those of a [LockedLazy](crate::LockedLazy) under contention,
+/// at the price of more expensive writes.
+///
+/// ```
+/// use static_init::ShardedLockedLazy;
+///
+/// static ROUTES: ShardedLockedLazy<Vec<&'static str>> =
+///     ShardedLockedLazy::new(|| vec!["/", "/login"]);
+///
+/// assert!(ROUTES.try_read().is_err());
+/// assert_eq!(ROUTES.read().len(), 2);
+/// ROUTES.write().push("/logout");
+/// assert_eq!(ROUTES.read()[2], "/logout");
+/// ```
+///
+/// The value has a single initialization phase, independent of the shards. If the
+/// generator panics, whether initialization is attempted again on the next access
+/// depends on the [GeneratorTolerance](crate::GeneratorTolerance) of the generator:
+/// closures are called again.
+pub struct ShardedLockedLazy<T, const N: usize = 8, G = fn() -> T> {
+    value: Entry<T, G>,
+    generator: G,
+    shards: [Shard; N],
+}
+
+unsafe impl<T: Send + Sync, const N: usize, G: Sync> Sync for ShardedLockedLazy<T, N, G> {}
+
+/// A read guard returned by [ShardedLockedLazy::read].
+pub struct ReadGuard<'a, T>(SyncReadPhaseGuard<'a, T>);
+
+/// A write guard returned by [ShardedLockedLazy::write], that holds the locks of all
+/// the shards.
+pub struct WriteGuard<'a, T, const N: usize> {
+    value: &'a mut T,
+    _shards: [SyncPhaseGuard<'a, ()>; N],
+}
+
+impl<'a, T> Deref for ReadGuard<'a, T> {
+    type Target = T;
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<'a, T, const N: usize> Deref for WriteGuard<'a, T, N> {
+    type Target = T;
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        self.value
+    }
+}
+
+impl<'a, T, const N: usize> DerefMut for WriteGuard<'a, T, N> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut T {
+        self.value
+    }
+}
+
+impl<'a, T: Debug> Debug for ReadGuard<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", **self)
+    }
+}
+
+impl<'a, T: Debug, const N: usize> Debug for WriteGuard<'a, T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", **self)
+    }
+}
+
+impl<T, const N: usize, G> ShardedLockedLazy<T, N, G> {
+    #[allow(clippy::declare_interior_mutable_const)]
+    const SHARD: Shard = Shard::INIT;
+
+    #[inline(always)]
+    /// Build a new lazy whose value will be the output of `generator`.
+    pub const fn new(generator: G) -> Self {
+        Self {
+            value: Entry::INIT,
+            generator,
+            shards: [Self::SHARD; N],
+        }
+    }
+    #[inline(always)]
+    /// Return the current phase
+    pub fn phase(&self) -> Phase {
+        Phased::phase(GenericLazy::sequentializer(&self.value.0))
+    }
+}
+
+impl<T, const N: usize, G: Generator<T>> ShardedLockedLazy<T, N, G> {
+    /// Lock the shard of the current thread for reading
+    #[inline(always)]
+    fn read_lock(&self) -> ReadGuard<'_, T> {
+        // SAFETY: the value is only accessed under the lock of a shard
+        let v = unsafe { &*GenericLazy::get_raw_data(&self.value.0).get() };
+        match self.shards[shard_index::<N>()].0.lock(
+            v,
+            |_| LockNature::Read,
+            |_| LockNature::Read,
+            Phase::empty(),
+        ) {
+            LockResult::Read(l) => ReadGuard(l),
+            _ => unreachable!(),
+        }
+    }
+    /// Lock all the shards, in order, for writing
+    #[inline(always)]
+    fn write_lock(&self) -> WriteGuard<'_, T, N> {
+        let shards = core::array::from_fn(|i| {
+            match self.shards[i].0.lock(
+                &(),
+                |_| LockNature::Write,
+                |_| LockNature::Write,
+                Phase::empty(),
+            ) {
+                LockResult::Write(l) => l,
+                _ => unreachable!(),
+            }
+        });
+        WriteGuard {
+            // SAFETY: all the shards are locked for writing
+            value: unsafe { &mut *GenericLazy::get_raw_data(&self.value.0).get() },
+            _shards: shards,
+        }
+    }
+    /// Initialize if necessary and returns a read lock
+    ///
+    /// # Panics
+    ///
+    /// Panics if initialization panics.
+    #[inline]
+    pub fn read(&self) -> ReadGuard<'_, T> {
+        self.init();
+        self.read_lock()
+    }
+    /// Initialize if necessary and returns a write lock
+    ///
+    /// # Panics
+    ///
+    /// Panics if initialization panics.
+    #[inline]
+    pub fn write(&self) -> WriteGuard<'_, T, N> {
+        self.init();
+        self.write_lock()
+    }
+    /// Returns a read lock if the value is initialized, otherwise an [AccessError]
+    #[inline]
+    pub fn try_read(&self) -> Result<ReadGuard<'_, T>, AccessError> {
+        self.value.0.try_get().map(|_| self.read_lock())
+    }
+    /// Returns a write lock if the value is initialized, otherwise an [AccessError]
+    #[inline]
+    pub fn try_write(&self) -> Result<WriteGuard<'_, T, N>, AccessError> {
+        self.value.0.try_get().map(|_| self.write_lock())
+    }
+    /// Initialize the value if it is not, and return the phase.
+    ///
+    /// # Panics
+    ///
+    /// Panics if initialization panics.
+    #[inline]
+    pub fn init(&self) -> Phase {
+        if let Err(e) = self
+            .value
+            .0
+            .init_with_then_try_get(|| self.generator.generate())
+        {
+            panic!("{}", e)
+        }
+        self.phase()
+    }
+}
+
+impl<T, const N: usize, G> Phased for ShardedLockedLazy<T, N, G> {
+    #[inline(always)]
+    fn phase(this: &Self) -> Phase {
+        this.phase()
+    }
+}
+
+impl<T, const N: usize, G> Debug for ShardedLockedLazy<T, N, G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.phase().intersects(Phase::INITIALIZED) {
+            write!(f, "Initialized")
+        } else {
+            write!(f, "UnInitialized")
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use crate::StaticInfo;
+use core::fmt::{self, Write};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+struct Measure {
+    name: &'static str,
+    priority: Option<u16>,
+    info: Option<StaticInfo>,
+    start: Instant,
+    end: Instant,
+}
+
+static MEASURES: Mutex<Vec<Measure>> = Mutex::new(Vec::new());
+
+#[doc(hidden)]
+/// Measures the execution of a program constructor, generated by the
+/// [dynamic](macro@crate::dynamic) and [constructor](macro@crate::constructor) attributes.
+pub struct __Probe {
+    name: &'static str,
+    priority: Option<u16>,
+    info: Option<StaticInfo>,
+    start: Instant,
+}
+
+impl __Probe {
+    #[inline]
+    pub fn start(name: &'static str, priority: Option<u16>, info: Option<StaticInfo>) -> Self {
+        Self {
+            name,
+            priority,
+            info,
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Drop for __Probe {
+    fn drop(&mut self) {
+        let end = Instant::now();
+        MEASURES
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(Measure {
+                name: self.name,
+                priority: self.priority,
+                info: self.info,
+                start: self.start,
+                end,
+            });
+    }
+}
+
+/// The execution of a program constructor or of the initialization of a static
+/// before `main`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StartupRecord {
+    /// Name of the static or of the constructor function.
+    pub name: &'static str,
+    /// Priority of the constructor, `None` for lesser lazy statics.
+    pub priority: Option<u16>,
+    /// Informations about the static, `None` for constructor functions.
+    pub info: Option<StaticInfo>,
+    /// Start of the execution, relative to the start of the first record.
+    pub start: Duration,
+    /// End of the execution, relative to the start of the first record.
+    pub end: Duration,
+}
+
+impl StartupRecord {
+    /// Execution time of the constructor or of the initialization.
+    pub fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+}
+
+/// Timings of the statics and constructors run before `main`, see [startup_report].
+#[derive(Debug, Clone, Default)]
+pub struct StartupReport {
+    records: Vec<StartupRecord>,
+}
+
+/// Returns the timings of the statics declared with the [dynamic](macro@crate::dynamic)
+/// attribute that were initialized by a program constructor, and of the functions
+/// declared with the [constructor](macro@crate::constructor) attribute.
+///
+/// Timestamps are taken with the monotonic clock. Records are sorted by start time.
+///
+/// ```
+/// use static_init::{dynamic, startup_report};
+///
+/// #[dynamic(10)]
+/// static V: Vec<i32> = vec![1, 2, 3];
+///
+/// let report = startup_report();
+/// let v = report.iter().find(|r| r.name == "V").unwrap();
+/// assert_eq!(v.priority, Some(10));
+/// println!("{}", report.to_chrome_trace());
+/// ```
+pub fn startup_report() -> StartupReport {
+    let measures = MEASURES.lock().unwrap_or_else(|e| e.into_inner());
+    let origin = match measures.iter().map(|m| m.start).min() {
+        Some(origin) => origin,
+        None => return StartupReport::default(),
+    };
+    let mut records = measures
+        .iter()
+        .map(|m| StartupRecord {
+            name: m.name,
+            priority: m.priority,
+            info: m.info,
+            start: m.start - origin,
+            end: m.end - origin,
+        })
+        .collect::<Vec<_>>();
+    records.sort_by_key(|r| r.start);
+    StartupReport { records }
+}
+
+impl StartupReport {
+    /// The records, sorted by start time.
+    pub fn records(&self) -> &[StartupRecord] {
+        &self.records
+    }
+    /// Iterate over the records, sorted by start time.
+    pub fn iter(&self) -> core::slice::Iter<'_, StartupRecord> {
+        self.records.iter()
+    }
+    /// Total time between the start of the first record and the end of the last one.
+    pub fn total(&self) -> Duration {
+        self.records
+            .iter()
+            .map(|r| r.end)
+            .max()
+            .unwrap_or(Duration::ZERO)
+    }
+    /// The report in the Chrome trace event format, that can be loaded in
+    /// `chrome://tracing` or Perfetto.
+    pub fn to_chrome_trace(&self) -> String {
+        let mut out = String::from("{\"traceEvents\":[");
+        for (i, r) in self.records.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            write_event(&mut out, r).unwrap();
+        }
+        out.push_str("]}");
+        out
+    }
+}
+
+impl<'a> IntoIterator for &'a StartupReport {
+    type Item = &'a StartupRecord;
+    type IntoIter = core::slice::Iter<'a, StartupRecord>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+fn write_event(out: &mut String, r: &StartupRecord) -> fmt::Result {
+    out.push_str("{\"name\":");
+    write_str(out, r.name)?;
+    write!(
+        out,
+        ",\"cat\":\"{}\",\"ph\":\"X\",\"ts\":{},\"dur\":{},\"pid\":0,\"tid\":0,\"args\":{{",
+        if r.info.is_some() {
+            "static"
+        } else {
+            "constructor"
+        },
+        r.start.as_micros(),
+        r.duration().as_micros()
+    )?;
+    if let Some(priority) = r.priority {
+        write!(out, "\"priority\":{},", priority)?;
+    }
+    if let Some(info) = &r.info {
+        out.push_str("\"file\":");
+        write_str(out, info.file_name)?;
+        write!(
+            out,
+            ",\"line\":{},\"column\":{},\"init_mode\":\"{:?}\",",
+            info.line, info.column, info.init_mode
+        )?;
+    }
+    write!(out, "\"duration_ns\":{}}}}}", r.duration().as_nanos())
+}
+
+fn write_str(out: &mut String, s: &str) -> fmt::Result {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32)?,
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    Ok(())
+}
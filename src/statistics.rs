@@ -0,0 +1,136 @@
+use crate::phase_locker::LockResult;
+use core::fmt::{self, Debug, Formatter};
+use core::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::Duration;
+
+/// Lock contention statistics of a locked lazy.
+///
+/// Mutable statics declared with [#[dynamic(stats)]](macro@crate::dynamic) have a
+/// statistics block. Any synchronized locked lazy whose generator returns one from
+/// [GeneratorTolerance::STATISTICS](crate::GeneratorTolerance::STATISTICS) records in it too. Only lock attempts that could
+/// not be satisfied immediately are recorded, and locked lazies without a statistics
+/// block are not slowed down.
+///
+/// ```
+/// use static_init::{dynamic, registry};
+///
+/// #[dynamic(lazy, stats)]
+/// static mut V: Vec<i32> = vec![];
+///
+/// V.write().push(1);
+///
+/// let stats = V.statistics().unwrap();
+/// assert_eq!(stats.timeouts(), 0);
+///
+/// assert!(registry::statistics()
+///     .any(|(info, s)| info.variable_name == "V" && core::ptr::eq(s, stats)));
+/// ```
+pub struct Statistics {
+    read_contentions: AtomicUsize,
+    write_contentions: AtomicUsize,
+    timeouts: AtomicUsize,
+    read_wait: AtomicU64,
+    write_wait: AtomicU64,
+    max_wait: AtomicU64,
+}
+
+/// Locked lazies that may record their lock contention in a [Statistics] block.
+pub trait Instrumented {
+    /// Returns the statistics block of the lazy, if any.
+    fn statistics(&self) -> Option<&'static Statistics>;
+}
+
+#[inline(always)]
+fn nanos(d: Duration) -> u64 {
+    d.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+impl Statistics {
+    #[inline(always)]
+    /// An empty statistics block.
+    pub const fn new() -> Self {
+        Self {
+            read_contentions: AtomicUsize::new(0),
+            write_contentions: AtomicUsize::new(0),
+            timeouts: AtomicUsize::new(0),
+            read_wait: AtomicU64::new(0),
+            write_wait: AtomicU64::new(0),
+            max_wait: AtomicU64::new(0),
+        }
+    }
+    /// Number of read locks that were obtained after waiting for an other
+    /// thread.
+    pub fn read_contentions(&self) -> usize {
+        self.read_contentions.load(Ordering::Relaxed)
+    }
+    /// Number of write locks that were obtained after waiting for an other
+    /// thread.
+    pub fn write_contentions(&self) -> usize {
+        self.write_contentions.load(Ordering::Relaxed)
+    }
+    /// Number of lock attempts that were abandoned because their deadline was
+    /// reached.
+    pub fn timeouts(&self) -> usize {
+        self.timeouts.load(Ordering::Relaxed)
+    }
+    /// Total time spent waiting for read locks.
+    pub fn read_wait_time(&self) -> Duration {
+        Duration::from_nanos(self.read_wait.load(Ordering::Relaxed))
+    }
+    /// Total time spent waiting for write locks.
+    pub fn write_wait_time(&self) -> Duration {
+        Duration::from_nanos(self.write_wait.load(Ordering::Relaxed))
+    }
+    /// Longest time a thread waited for a lock, including abandoned attempts.
+    pub fn max_wait_time(&self) -> Duration {
+        Duration::from_nanos(self.max_wait.load(Ordering::Relaxed))
+    }
+    /// Set all counters to zero.
+    pub fn reset(&self) {
+        self.read_contentions.store(0, Ordering::Relaxed);
+        self.write_contentions.store(0, Ordering::Relaxed);
+        self.timeouts.store(0, Ordering::Relaxed);
+        self.read_wait.store(0, Ordering::Relaxed);
+        self.write_wait.store(0, Ordering::Relaxed);
+        self.max_wait.store(0, Ordering::Relaxed);
+    }
+    /// Record the result of a contended lock attempt that lasted `waited`, `None`
+    /// if the attempt was abandoned.
+    pub(crate) fn record<R, W>(&self, result: Option<&LockResult<R, W>>, waited: Duration) {
+        let waited = nanos(waited);
+        match result {
+            Some(LockResult::Write(_)) => {
+                self.write_contentions.fetch_add(1, Ordering::Relaxed);
+                self.write_wait.fetch_add(waited, Ordering::Relaxed);
+            }
+            Some(LockResult::Read(_)) | Some(LockResult::None(_)) => {
+                self.read_contentions.fetch_add(1, Ordering::Relaxed);
+                self.read_wait.fetch_add(waited, Ordering::Relaxed);
+            }
+            None => {
+                self.timeouts.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        self.max_wait.fetch_max(waited, Ordering::Relaxed);
+    }
+}
+
+impl Default for Statistics {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for Statistics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Statistics")
+            .field("read_contentions", &self.read_contentions())
+            .field("write_contentions", &self.write_contentions())
+            .field("timeouts", &self.timeouts())
+            .field("read_wait_time", &self.read_wait_time())
+            .field("write_wait_time", &self.write_wait_time())
+            .field("max_wait_time", &self.max_wait_time())
+            .finish()
+    }
+}
@@ -0,0 +1,174 @@
+//! Spans and events emitted with the [tracing](https://docs.rs/tracing) crate.
+//!
+//! All spans and events use the `static_init` target. When the lazy was declared with the
+//! [dynamic](macro@crate::dynamic) attribute, they carry the fields of its
+//! [StaticInfo](crate::StaticInfo): `variable_name`, `file_name`, `line`, `column`,
+//! `init_mode` and `drop_mode`.
+//!
+//! - an `init` span is entered while a lazy is being initialized, an error event is emitted
+//!   inside it if the initialization panics and a warning if the lazy could not be registered
+//!   for finalization at program exit;
+//! - a `finalize` span, or a `drop` span for statics declared with `#[dynamic(drop)]`, is
+//!   entered while the lazy is finalized;
+//! - a warning event with a `waited` field is emitted when a thread waited on the lock of a
+//!   lazy longer than the [wait threshold](set_wait_threshold).
+use crate::{FinalyMode, Phase, StaticInfo};
+use core::cell::Cell;
+use core::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use tracing::span::EnteredSpan;
+
+static WAIT_THRESHOLD: AtomicU64 = AtomicU64::new(1_000_000);
+
+/// Set the duration above which a wait on the lock of a lazy is reported.
+///
+/// The default threshold is one millisecond.
+pub fn set_wait_threshold(threshold: Duration) {
+    WAIT_THRESHOLD.store(
+        threshold.as_nanos().try_into().unwrap_or(u64::MAX),
+        Ordering::Relaxed,
+    )
+}
+
+/// The duration above which a wait on the lock of a lazy is reported.
+pub fn wait_threshold() -> Duration {
+    Duration::from_nanos(WAIT_THRESHOLD.load(Ordering::Relaxed))
+}
+
+macro_rules! static_span {
+    ($name:literal, $info:expr) => {
+        match $info {
+            Some(info) => tracing::info_span!(
+                target: "static_init",
+                $name,
+                variable_name = info.variable_name,
+                file_name = info.file_name,
+                line = info.line,
+                column = info.column,
+                init_mode = ?info.init_mode,
+                drop_mode = ?info.drop_mode,
+            ),
+            None => tracing::info_span!(target: "static_init", $name),
+        }
+    };
+}
+
+/// Keeps the `init` span entered and reports initialization panics.
+#[must_use]
+pub(crate) struct InitTrace {
+    _span: EnteredSpan,
+    panicking: bool,
+}
+
+impl InitTrace {
+    pub(crate) fn enter(info: Option<&StaticInfo>) -> Self {
+        Self {
+            _span: static_span!("init", info).entered(),
+            panicking: std::thread::panicking(),
+        }
+    }
+}
+
+impl Drop for InitTrace {
+    fn drop(&mut self) {
+        if !self.panicking && std::thread::panicking() {
+            tracing::error!(target: "static_init", "initialization panicked");
+        }
+    }
+}
+
+/// Keeps the `finalize` or `drop` span entered and reports finalization panics.
+#[must_use]
+pub(crate) struct FinalizeTrace {
+    _span: EnteredSpan,
+    panicking: bool,
+}
+
+impl FinalizeTrace {
+    pub(crate) fn enter(info: Option<&StaticInfo>) -> Self {
+        let span = if matches!(info, Some(info) if info.drop_mode == FinalyMode::Drop) {
+            static_span!("drop", info)
+        } else {
+            static_span!("finalize", info)
+        };
+        Self {
+            _span: span.entered(),
+            panicking: std::thread::panicking(),
+        }
+    }
+}
+
+impl Drop for FinalizeTrace {
+    fn drop(&mut self) {
+        if !self.panicking && std::thread::panicking() {
+            tracing::error!(target: "static_init", "finalization panicked");
+        }
+    }
+}
+
+pub(crate) fn registration_refused() {
+    tracing::warn!(
+        target: "static_init",
+        "registration for finalization at exit refused"
+    );
+}
+
+/// Measures how long a thread waited on a lock.
+///
+/// The start of the wait is taken the first time the locker asks how to wait,
+/// which only happens once the lock could not be taken by spinning.
+pub(crate) struct WaitTrace<'a> {
+    info: Option<&'a StaticInfo>,
+    start: Cell<Option<Instant>>,
+}
+
+impl<'a> WaitTrace<'a> {
+    #[inline(always)]
+    pub(crate) fn new(info: Option<&'a StaticInfo>) -> Self {
+        Self {
+            info,
+            start: Cell::new(None),
+        }
+    }
+    #[inline(always)]
+    pub(crate) fn on_wait<'b, R>(
+        &'b self,
+        f: impl Fn(Phase) -> R + 'b,
+    ) -> impl Fn(Phase) -> R + 'b {
+        move |p| {
+            if self.start.get().is_none() {
+                self.start.set(Some(Instant::now()));
+            }
+            f(p)
+        }
+    }
+    #[inline(always)]
+    pub(crate) fn report<R>(&self, r: R) -> R {
+        if let Some(start) = self.start.get() {
+            self.long_wait(start.elapsed());
+        }
+        r
+    }
+    #[cold]
+    fn long_wait(&self, waited: Duration) {
+        if waited < wait_threshold() {
+            return;
+        }
+        match self.info {
+            Some(info) => tracing::warn!(
+                target: "static_init",
+                variable_name = info.variable_name,
+                file_name = info.file_name,
+                line = info.line,
+                column = info.column,
+                waited = ?waited,
+                "long wait on the lock of a lazy"
+            ),
+            None => tracing::warn!(
+                target: "static_init",
+                waited = ?waited,
+                "long wait on the lock of a lazy"
+            ),
+        }
+    }
+}
@@ -0,0 +1,327 @@
+use crate::generic_lazy::{self, AccessError, GenericLazy, GenericLockedLazy, LazyData, UnInited};
+use crate::lazy::InitializedChecker;
+use crate::lazy_sequentializer::SyncSequentializer;
+use crate::phase_locker::{SyncPhaseGuard, SyncPhaseLocker, SyncReadPhaseGuard};
+use crate::{Phase, Phased, StaticInfo, TryGenerator};
+
+use core::fmt::{self, Debug, Display, Formatter};
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+
+/// Error returned by fallible lazies
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum InitError<E> {
+    /// The generator failed during this initialization attempt
+    Generator(E),
+    /// The target is not accessible: a previous initialization attempt failed or
+    /// panicked and the generator does not tolerate a new attempt.
+    Access(AccessError),
+}
+
+impl<E> From<AccessError> for InitError<E> {
+    #[inline(always)]
+    fn from(e: AccessError) -> Self {
+        Self::Access(e)
+    }
+}
+
+impl<E: Display> Display for InitError<E> {
+    fn fmt(&self, ft: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Generator(e) => write!(ft, "Error: lazy initialization failed: {}", e),
+            Self::Access(e) => write!(ft, "{}", e),
+        }
+    }
+}
+
+#[cfg(feature = "parking_lot_core")]
+impl<E: std::error::Error> std::error::Error for InitError<E> {}
+
+/// A lazy whose initialization may fail.
+///
+/// ```
+/// use static_init::TryLazy;
+///
+/// static V: TryLazy<i32, &str> = TryLazy::new(|| "33".parse().map_err(|_| "not a number"));
+///
+/// assert_eq!(*V.get().unwrap(), 33);
+/// ```
+///
+/// If the generator is a function, a failure or a panic of the initialization
+/// can be retried at the next access.
+pub struct TryLazy<T, E, G = fn() -> Result<T, E>> {
+    __private: GenericLazy<UnInited<T>, G, SyncSequentializer<G>, InitializedChecker<G>>,
+    phantom: PhantomData<fn() -> E>,
+}
+
+impl<T, E, G> TryLazy<T, E, G> {
+    #[inline(always)]
+    /// Build a new lazy that will be initialized with the value
+    /// returned by `generator`.
+    pub const fn new(generator: G) -> Self {
+        Self {
+            __private: unsafe {
+                GenericLazy::new(
+                    generator,
+                    SyncSequentializer::new(SyncPhaseLocker::new(Phase::empty())),
+                    UnInited::INIT,
+                )
+            },
+            phantom: PhantomData,
+        }
+    }
+    #[inline(always)]
+    /// Build a new lazy that will be initialized with the value
+    /// returned by `generator`, with debug information
+    pub const fn from_generator_with_info(generator: G, info: StaticInfo) -> Self {
+        Self {
+            __private: unsafe {
+                GenericLazy::new_with_info(
+                    generator,
+                    SyncSequentializer::new(SyncPhaseLocker::new(Phase::empty())),
+                    UnInited::INIT,
+                    info,
+                )
+            },
+            phantom: PhantomData,
+        }
+    }
+    #[inline(always)]
+    /// Return a reference to the target if initialized otherwise return an error.
+    pub fn try_get(&self) -> Result<&T, AccessError> {
+        let phase = self.phase();
+        if phase.intersects(Phase::INITIALIZED) {
+            // SAFETY: the value is initialized
+            Ok(unsafe { &*GenericLazy::get_raw_data(&self.__private).get() })
+        } else {
+            Err(AccessError { phase })
+        }
+    }
+    #[inline(always)]
+    /// Return the current phase
+    pub fn phase(&self) -> Phase {
+        Phased::phase(&self.__private)
+    }
+}
+
+impl<T, E, G: TryGenerator<T, E>> TryLazy<T, E, G> {
+    #[inline(always)]
+    /// Initialize if necessary then return a reference to the target.
+    ///
+    /// If the initialization attempt of this call fails, the error returned by
+    /// the generator is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panic if initialization panics.
+    pub fn get(&self) -> Result<&T, InitError<E>> {
+        self.__private.try_init_then_try_get()
+    }
+    #[inline(always)]
+    /// Initialize the static if there were no previous attempt to initialize it
+    /// and return the phase.
+    pub fn init(&self) -> Phase {
+        let _ = self.get();
+        self.phase()
+    }
+}
+
+impl<T, E, G> Phased for TryLazy<T, E, G> {
+    #[inline(always)]
+    fn phase(this: &Self) -> Phase {
+        Phased::phase(&this.__private)
+    }
+}
+
+impl<T: Debug, E, G> Debug for TryLazy<T, E, G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.try_get() {
+            Ok(v) => write!(f, "{:?}", v),
+            Err(_) => write!(f, "UnInitialized"),
+        }
+    }
+}
+
+impl<T, E, G> Drop for TryLazy<T, E, G> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        if self.phase().intersects(Phase::INITIALIZED) {
+            unsafe {
+                GenericLazy::get_raw_data(&self.__private)
+                    .get()
+                    .drop_in_place()
+            }
+        }
+    }
+}
+
+/// A mutable lazy whose initialization may fail.
+///
+/// ```
+/// use static_init::TryLockedLazy;
+///
+/// static V: TryLockedLazy<Vec<i32>, &str> = TryLockedLazy::new(|| Ok(vec![1, 2]));
+///
+/// V.write().unwrap().push(3);
+/// assert_eq!(V.read().unwrap().len(), 3);
+/// ```
+pub struct TryLockedLazy<T, E, G = fn() -> Result<T, E>> {
+    __private: GenericLockedLazy<UnInited<T>, G, SyncSequentializer<G>, InitializedChecker<G>>,
+    phantom: PhantomData<fn() -> E>,
+}
+
+/// A read guard returned by [TryLockedLazy::read].
+#[must_use = "If unused the read lock is immediatly released"]
+pub struct ReadGuard<'a, T>(generic_lazy::ReadGuard<SyncReadPhaseGuard<'a, UnInited<T>>>);
+
+/// A write guard returned by [TryLockedLazy::write].
+#[must_use = "If unused the write lock is immediatly released"]
+pub struct WriteGuard<'a, T>(generic_lazy::WriteGuard<SyncPhaseGuard<'a, UnInited<T>>>);
+
+impl<'a, T> Deref for ReadGuard<'a, T> {
+    type Target = T;
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<'a, T> Deref for WriteGuard<'a, T> {
+    type Target = T;
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<'a, T> DerefMut for WriteGuard<'a, T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<'a, T> Phased for ReadGuard<'a, T> {
+    #[inline(always)]
+    fn phase(this: &Self) -> Phase {
+        Phased::phase(&this.0)
+    }
+}
+
+impl<'a, T> Phased for WriteGuard<'a, T> {
+    #[inline(always)]
+    fn phase(this: &Self) -> Phase {
+        Phased::phase(&this.0)
+    }
+}
+
+impl<'a, T: Debug> Debug for ReadGuard<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", **self)
+    }
+}
+
+impl<'a, T: Debug> Debug for WriteGuard<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", **self)
+    }
+}
+
+impl<T, E, G> TryLockedLazy<T, E, G> {
+    #[inline(always)]
+    /// Build a new lazy that will be initialized with the value
+    /// returned by `generator`.
+    pub const fn new(generator: G) -> Self {
+        Self {
+            __private: unsafe {
+                GenericLockedLazy::new(
+                    generator,
+                    SyncSequentializer::new(SyncPhaseLocker::new(Phase::empty())),
+                    UnInited::INIT,
+                )
+            },
+            phantom: PhantomData,
+        }
+    }
+    #[inline(always)]
+    /// Build a new lazy that will be initialized with the value
+    /// returned by `generator`, with debug information
+    pub const fn from_generator_with_info(generator: G, info: StaticInfo) -> Self {
+        Self {
+            __private: unsafe {
+                GenericLockedLazy::new_with_info(
+                    generator,
+                    SyncSequentializer::new(SyncPhaseLocker::new(Phase::empty())),
+                    UnInited::INIT,
+                    info,
+                )
+            },
+            phantom: PhantomData,
+        }
+    }
+    #[inline(always)]
+    /// Return the current phase
+    pub fn phase(&self) -> Phase {
+        Phased::phase(&self.__private)
+    }
+}
+
+impl<T, E, G: TryGenerator<T, E>> TryLockedLazy<T, E, G> {
+    #[inline(always)]
+    /// Initialize if necessary and returns a read lock.
+    ///
+    /// If the initialization attempt of this call fails, the error returned by
+    /// the generator is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panic if initialization panics.
+    pub fn read(&self) -> Result<ReadGuard<'_, T>, InitError<E>> {
+        GenericLockedLazy::try_init_then_read_lock(&self.__private).map(ReadGuard)
+    }
+    #[inline(always)]
+    /// Initialize if necessary and returns a write lock.
+    ///
+    /// If the initialization attempt of this call fails, the error returned by
+    /// the generator is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panic if initialization panics.
+    pub fn write(&self) -> Result<WriteGuard<'_, T>, InitError<E>> {
+        GenericLockedLazy::try_init_then_write_lock(&self.__private).map(WriteGuard)
+    }
+    #[inline(always)]
+    /// Initialize the static if there were no previous attempt to initialize it
+    /// and return the phase.
+    pub fn init(&self) -> Phase {
+        let _ = self.read();
+        self.phase()
+    }
+}
+
+impl<T, E, G> Phased for TryLockedLazy<T, E, G> {
+    #[inline(always)]
+    fn phase(this: &Self) -> Phase {
+        Phased::phase(&this.__private)
+    }
+}
+
+impl<T, E, G> Debug for TryLockedLazy<T, E, G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.phase().intersects(Phase::INITIALIZED) {
+            write!(f, "Initialized")
+        } else {
+            write!(f, "UnInitialized")
+        }
+    }
+}
+
+impl<T, E, G> Drop for TryLockedLazy<T, E, G> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        if self.phase().intersects(Phase::INITIALIZED) {
+            unsafe { self.__private.get().drop_in_place() }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::lazy::LockedLazyDroped;
+use crate::LockedLazy;
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use core::any::TypeId;
+
+type Entry<T, G> = &'static LockedLazyDroped<T, &'static G>;
+
+type Entries<T, G> = BTreeMap<TypeId, Entry<T, G>>;
+
+type EntriesGenerator<T, G> = fn() -> Entries<T, G>;
+
+/// A family of lazy statics, one per type, as would be a generic static if rust
+/// allowed them.
+///
+/// Each type `K` passed to [get](Self::get) is associated to its own lazy, initialized
+/// by the generator of the map the first time it is accessed. The lazies are
+/// [LockedLazyDroped]: their value is accessed through read and write locks and is
+/// dropped at program exit, as the value of a static declared with
+/// [#[dynamic(lazy,drop)]](macro@crate::dynamic).
+///
+/// ```
+/// use static_init::TypeMap;
+///
+/// struct Registry<K> {
+///     names: Vec<&'static str>,
+///     key: core::marker::PhantomData<K>,
+/// }
+///
+/// static REGISTRIES: TypeMap<Vec<&'static str>> = TypeMap::new(Vec::new);
+///
+/// impl<K: 'static> Registry<K> {
+///     fn register(name: &'static str) {
+///         REGISTRIES.get::<K>().write().push(name)
+///     }
+///     fn names() -> Vec<&'static str> {
+///         REGISTRIES.get::<K>().read().to_vec()
+///     }
+/// }
+///
+/// Registry::<u8>::register("a");
+/// Registry::<u16>::register("b");
+/// assert_eq!(Registry::<u8>::names(), ["a"]);
+/// assert_eq!(Registry::<u16>::names(), ["b"]);
+/// ```
+///
+/// The type `K` is only used as a key. The lazies are never freed, so there should
+/// be a bounded number of key types.
+pub struct TypeMap<T: 'static, G: 'static = fn() -> T> {
+    generator: G,
+    entries: LockedLazy<Entries<T, G>, EntriesGenerator<T, G>>,
+}
+
+impl<T, G> TypeMap<T, G> {
+    /// Build a map whose lazies will be initialized by `generator`.
+    #[inline(always)]
+    pub const fn new(generator: G) -> Self {
+        Self {
+            generator,
+            entries: LockedLazy::from_generator(BTreeMap::new as EntriesGenerator<T, G>),
+        }
+    }
+}
+
+impl<T: Send, G: Fn() -> T + Sync> TypeMap<T, G> {
+    /// Returns the lazy associated to the type `K`.
+    ///
+    /// The lazy is created by the first call with this type but its value is only
+    /// initialized on its first access.
+    pub fn get<K: ?Sized + 'static>(&'static self) -> &'static LockedLazyDroped<T, &'static G> {
+        let id = TypeId::of::<K>();
+        let found = self.entries.read().get(&id).copied();
+        match found {
+            Some(entry) => entry,
+            None => {
+                let mut entries = self.entries.write();
+                let entry = entries.entry(id).or_insert_with(|| {
+                    Box::leak(Box::new(LockedLazyDroped::from_generator(&self.generator)))
+                });
+                entry
+            }
+        }
+    }
+
+    /// The number of types for which a lazy has been created.
+    pub fn len(&'static self) -> usize {
+        self.entries.read().len()
+    }
+
+    /// Returns true if no lazy has been created yet.
+    pub fn is_empty(&'static self) -> bool {
+        self.len() == 0
+    }
+}
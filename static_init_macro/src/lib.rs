@@ -16,7 +16,7 @@ use core::result::Result;
 extern crate quote;
 use quote::quote_spanned;
 
-use proc_macro::TokenStream;
+use proc_macro::{TokenStream, TokenTree};
 
 extern crate proc_macro2;
 use proc_macro2::{Span, TokenStream as TokenStream2};
@@ -32,11 +32,30 @@ macro_rules! ok_or_return {
 
 #[proc_macro_attribute]
 pub fn constructor(args: TokenStream, input: TokenStream) -> TokenStream {
+    let (args, generated) = split_dynamic_marker(args);
+
+    let internal = is_internal_priority(&args);
+
     let priority = ok_or_return!(parse_priority(args));
 
     let section = ok_or_return!(init_section(priority));
 
-    let func: ItemFn = parse_macro_input!(input);
+    let mut func: ItemFn = parse_macro_input!(input);
+
+    // constructors generated by the dynamic attribute are profiled
+    // with the informations of their static
+    if cfg!(feature = "startup_profile") && !internal && !generated {
+        let name = &func.sig.ident;
+        let sp = Span::mixed_site();
+        let probe: Stmt = parse_quote_spanned!(sp=>
+            let __static_init_probe = ::static_init::startup_profile::__Probe::start(
+                ::core::stringify!(#name),
+                ::core::option::Option::Some(#priority),
+                ::core::option::Option::None,
+            );
+        );
+        func.block.stmts.insert(0, probe);
+    }
 
     let func_ptr_name = format!("__static_init_constructor_{}", func.sig.ident);
 
@@ -91,6 +110,20 @@ fn init_section(priority: u16) -> Result<String, TokenStream> {
     }
 }
 
+/// The link section of the entries of the registry of statics, whose bounds are
+/// looked up by `static_init::registry`
+fn registry_section() -> Result<String, TokenStream> {
+    if cfg!(elf) {
+        Ok("static_init_registry".to_string())
+    } else if cfg!(mach_o) {
+        Ok("__DATA,__sireg,regular,no_dead_strip".to_string())
+    } else if cfg!(coff) {
+        Ok(".sireg$m".to_string())
+    } else {
+        Err(const_dtor_no_support())
+    }
+}
+
 fn fini_section(priority: u16) -> Result<String, TokenStream> {
     if cfg!(elf) {
         // destructors not used by standard library
@@ -157,12 +190,41 @@ struct Tolerance {
     registration_fail: bool,
 }
 
-#[derive(Clone, Copy, Eq, PartialEq)]
+#[derive(Clone)]
 struct DynMode {
     init: InitMode,
     drop: DropMode,
     tolerance: Tolerance,
     priming: bool,
+    fallible: bool,
+    poison: bool,
+    stats: bool,
+    seqlock: bool,
+    finaly_priority: u16,
+    after: Vec<Path>,
+}
+
+/// Constructors generated by the dynamic attribute receive a trailing `__dynamic`
+/// argument, returns the arguments without it and whether it was present.
+fn split_dynamic_marker(args: TokenStream) -> (TokenStream, bool) {
+    let mut tokens: Vec<TokenTree> = args.into_iter().collect();
+    let marked = matches!(
+        tokens.as_slice(),
+        [.., TokenTree::Punct(comma), TokenTree::Ident(marker)]
+            if comma.as_char() == ',' && marker.to_string() == "__dynamic"
+    );
+    if marked {
+        tokens.truncate(tokens.len() - 2);
+    }
+    (tokens.into_iter().collect(), marked)
+}
+
+/// Priorities reserved to the constructors of this crate
+fn is_internal_priority(args: &TokenStream) -> bool {
+    match syn::parse::<Ident>(args.clone()) {
+        Ok(n) => n.to_string().starts_with("__"),
+        Err(_) => false,
+    }
 }
 
 fn parse_priority(args: TokenStream) -> std::result::Result<u16, TokenStream2> {
@@ -173,6 +235,10 @@ fn parse_priority(args: TokenStream) -> std::result::Result<u16, TokenStream2> {
                 return Ok(1);
             } else if n == "__lazy_init_finished" {
                 return Ok(0);
+            } else if n == "__dependency_check" {
+                return Ok(2);
+            } else if n == "__registry" {
+                return Ok(if cfg!(support_priority) { 65535 } else { 0 });
             }
         }
         let lit: Lit = syn::parse(args).map_err(|e| e.to_compile_error())?;
@@ -223,6 +289,12 @@ fn parse_dyn_options(args: AttributeArgs) -> std::result::Result<DynMode, TokenS
             registration_fail: false,
         },
         priming: false,
+        fallible: false,
+        poison: false,
+        stats: false,
+        seqlock: false,
+        finaly_priority: 0,
+        after: Vec::new(),
     };
 
     let mut init_set = false;
@@ -254,7 +326,7 @@ fn parse_dyn_options(args: AttributeArgs) -> std::result::Result<DynMode, TokenS
             Err(generate_error!($id.span()=>
                 "Unexpected attribute argument `",
                 __unexpected,
-                "`. Expected either `init[=<u16>]`, `drop[=<u16>]`, `lazy`, `lesser_lazy`, `drop_only=<u16>`, `prime`, `tolerate_leak` or `try_init_once`."
+                "`. Expected either `init[=<u16>]`, `drop[=<u16>]`, `finalize[=<u16>]`, `lazy`, `lesser_lazy`, `drop_only=<u16>`, `prime`, `fallible`, `poison`, `stats`, `seqlock`, `tolerate_leak`, `try_init_once` or `after(<statics>)`."
                 ))
         }
         }
@@ -299,6 +371,24 @@ fn parse_dyn_options(args: AttributeArgs) -> std::result::Result<DynMode, TokenS
                     opt.tolerance.registration_fail = true;
                 } else if id == "prime" {
                     opt.priming = true;
+                } else if id == "fallible" {
+                    opt.fallible = true;
+                } else if id == "poison" {
+                    if !cfg!(feature = "poison") {
+                        return Err(
+                            generate_error!(id.span()=>"Poisoning requires the `poison` feature of static_init"),
+                        );
+                    }
+                    opt.poison = true;
+                } else if id == "stats" {
+                    if !cfg!(feature = "stats") {
+                        return Err(
+                            generate_error!(id.span()=>"Lock statistics require the `stats` feature of static_init"),
+                        );
+                    }
+                    opt.stats = true;
+                } else if id == "seqlock" {
+                    opt.seqlock = true;
                 } else {
                     return unexpected_arg!(id);
                 }
@@ -317,6 +407,15 @@ fn parse_dyn_options(args: AttributeArgs) -> std::result::Result<DynMode, TokenS
                     check_no_drop!(id);
                     let priority = parse_priority_literal(&nv.lit)?;
                     opt.drop = DropMode::Dynamic(priority);
+                } else if id == "finalize" {
+                    if !cfg!(constructor_destructor) {
+                        return Err(
+                            generate_error!(id.span()=>"Static finalization mode only supported on unixes and windows"),
+                        );
+                    }
+                    check_no_drop!(id);
+                    opt.drop = DropMode::Finalize;
+                    opt.finaly_priority = parse_priority_literal(&nv.lit)?;
                 } else if id == "drop_only" {
                     check_no_init!(id);
                     check_no_drop!(id);
@@ -327,6 +426,17 @@ fn parse_dyn_options(args: AttributeArgs) -> std::result::Result<DynMode, TokenS
                     return unexpected_arg!(id);
                 }
             }
+            NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("after") => {
+                for dep in list.nested {
+                    match dep {
+                        NestedMeta::Meta(Meta::Path(path)) => opt.after.push(path),
+                        _ => {
+                            return Err(generate_error!(dep.span()=>
+                            "Expected the path of a lazy static, found `",dep,"`."))
+                        }
+                    }
+                }
+            }
             NestedMeta::Lit(lit) => {
                 check_no_init!(lit);
                 let priority = parse_priority_literal(&lit)?;
@@ -337,9 +447,62 @@ fn parse_dyn_options(args: AttributeArgs) -> std::result::Result<DynMode, TokenS
             }
         }
     }
+    if opt.fallible && !init_set {
+        opt.init = InitMode::Lazy
+    }
+    if let (InitMode::Lazy | InitMode::LesserLazy, DropMode::Dynamic(p)) = (opt.init, opt.drop) {
+        // lazy statics are dropped at program exit in the order of their finalization priority
+        opt.drop = DropMode::Drop;
+        opt.finaly_priority = p;
+    }
     if opt.init == InitMode::LesserLazy && !cfg!(constructor_destructor) {
         opt.init = InitMode::Lazy
     }
+    if !(opt.after.is_empty() || opt.init == InitMode::Lazy || opt.init == InitMode::LesserLazy) {
+        return Err(generate_error!(
+            "Only lazy statics can be declared to be initialized `after` other statics"
+        ));
+    }
+    if opt.poison
+        && (!(opt.init == InitMode::Lazy || opt.init == InitMode::LesserLazy)
+            || opt.priming
+            || opt.fallible)
+    {
+        return Err(generate_error!(
+            "Only lazy statics that are neither primed nor fallible can be poisoned"
+        ));
+    }
+    if opt.stats
+        && (!(opt.init == InitMode::Lazy || opt.init == InitMode::LesserLazy) || opt.fallible)
+    {
+        return Err(generate_error!(
+            "Only lazy statics that are not fallible can have lock statistics"
+        ));
+    }
+    if opt.seqlock
+        && (!(opt.init == InitMode::Lazy || opt.init == InitMode::LesserLazy)
+            || opt.drop != DropMode::None
+            || opt.priming
+            || opt.fallible
+            || opt.poison
+            || opt.stats)
+    {
+        return Err(generate_error!(
+            "Only lazy statics that are neither dropped, finalized, primed, fallible, poisoned \
+             nor with lock statistics can be seqlocked"
+        ));
+    }
+    if opt.stats && !cfg!(constructor_destructor) {
+        return Err(generate_error!(
+            "Lock statistics are not supported on this plateform"
+        ));
+    }
+    if opt.fallible && (opt.init != InitMode::Lazy || opt.drop != DropMode::None || opt.priming) {
+        return Err(generate_error!(
+            "Fallible statics must be lazy, not primed and not dropped: use `#[dynamic(lazy, \
+             fallible)]`."
+        ));
+    }
     if opt.drop == DropMode::None && opt.tolerance.registration_fail {
         return Err(generate_error!(
             "Unusefull `tolerate_leak`: this static is not dropped, it will always leak. Add \
@@ -425,6 +588,26 @@ fn has_thread_local(attrs: &[Attribute]) -> bool {
     false
 }
 
+/// Returns `T` and `E` if `typ` is of the form `Result<T, E>`
+fn result_types(typ: &Type) -> Option<(&Type, &Type)> {
+    let seg = match typ {
+        Type::Path(p) if p.qself.is_none() => p.path.segments.last()?,
+        _ => return None,
+    };
+    if seg.ident != "Result" {
+        return None;
+    }
+    match &seg.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 2 => {
+            match (&args.args[0], &args.args[1]) {
+                (GenericArgument::Type(t), GenericArgument::Type(e)) => Some((t, e)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 fn gen_dyn_init(mut stat: ItemStatic, options: DynMode) -> TokenStream2 {
     //TODO: dropped static must be initialized by unsafe code because
     //if initialization panic this will cause UB TBC.
@@ -454,6 +637,67 @@ fn gen_dyn_init(mut stat: ItemStatic, options: DynMode) -> TokenStream2 {
         );
     }
 
+    if is_thread_local && options.fallible {
+        return generate_error!("Fallible statics can not be thread locals");
+    }
+
+    if is_thread_local && options.poison {
+        return generate_error!("Thread locals can not be poisoned");
+    }
+
+    if is_thread_local && options.stats {
+        return generate_error!("Thread locals can not have lock statistics");
+    }
+
+    if options.stats && stat.mutability.is_none() {
+        return generate_error!(stat.static_token.span()=>
+            "Only mutable statics are locked, lock statistics require the `mut` keyword."
+        );
+    }
+
+    if is_thread_local && options.seqlock {
+        return generate_error!("Thread locals can not be seqlocked");
+    }
+
+    if options.seqlock && stat.mutability.is_none() {
+        return generate_error!(stat.static_token.span()=>
+            "Seqlocked statics are mutating. Add the `mut` keyword."
+        );
+    }
+
+    if options.poison && stat.mutability.is_none() {
+        return generate_error!(stat.static_token.span()=>
+            "Poisoned statics are mutating. Add the `mut` keyword."
+        );
+    }
+
+    if is_thread_local && !options.after.is_empty() {
+        return generate_error!(
+            "Thread locals can not be declared to be initialized `after` other statics"
+        );
+    }
+
+    if is_thread_local && options.finaly_priority != 0 {
+        return generate_error!(
+            "Thread locals are finalized at thread exit, they can not have a finalization priority"
+        );
+    }
+
+    let fallible_types = if options.fallible {
+        match result_types(stat_typ) {
+            Some(types) => Some(types),
+            None => {
+                return generate_error!(stat_typ.span()=>
+                    "Fallible statics must have a type of the form `Result<T, E>`, found `",
+                    stat_typ,
+                    "`."
+                )
+            }
+        }
+    } else {
+        None
+    };
+
     let stat_ref: Expr =
         if !(options.init == InitMode::Lazy || options.init == InitMode::LesserLazy) {
             parse_quote! {
@@ -489,6 +733,22 @@ fn gen_dyn_init(mut stat: ItemStatic, options: DynMode) -> TokenStream2 {
                 ::static_init::raw_static::Static::<#stat_typ>
             }
         }
+    } else if let Some((ok_typ, err_typ)) = fallible_types {
+        if stat.mutability.is_none() {
+            parse_quote! {
+                ::static_init::TryLazy::<#ok_typ,#err_typ,#stat_generator_name>
+            }
+        } else {
+            into_immutable!();
+            parse_quote! {
+                ::static_init::TryLockedLazy::<#ok_typ,#err_typ,#stat_generator_name>
+            }
+        }
+    } else if options.seqlock {
+        into_immutable!();
+        parse_quote! {
+            ::static_init::SeqLockLazy::<#stat_typ,#stat_generator_name>
+        }
     } else if is_thread_local && options.priming && options.drop == DropMode::None {
         if stat.mutability.is_none() {
             return generate_error!(stat.static_token.span()=>
@@ -657,6 +917,14 @@ fn gen_dyn_init(mut stat: ItemStatic, options: DynMode) -> TokenStream2 {
         }
     };
 
+    let static_typ: Type = if options.poison {
+        parse_quote! {
+            ::static_init::poison::Poison::<#typ>
+        }
+    } else {
+        typ.clone()
+    };
+
     let (expr, prime_expr) = if !options.priming {
         (&*stat.expr, None)
     } else if let Expr::Match(mexp) = &*stat.expr {
@@ -708,6 +976,32 @@ fn gen_dyn_init(mut stat: ItemStatic, options: DynMode) -> TokenStream2 {
 
     let sp = stat.expr.span().resolved_at(Span::mixed_site());
 
+    let statid = &stat.ident;
+
+    let init_priority: Expr = match options.init {
+        InitMode::Dynamic(n) => parse_quote!(::static_init::InitMode::ProgramConstructor(#n)),
+        InitMode::Lazy => parse_quote!(::static_init::InitMode::Lazy),
+        InitMode::LesserLazy => parse_quote!(::static_init::InitMode::LesserLazy),
+        InitMode::Const => parse_quote!(::static_init::InitMode::Const),
+    };
+
+    let drop_priority: Expr = match options.drop {
+        DropMode::Dynamic(n) => parse_quote!(::static_init::FinalyMode::ProgramDestructor(#n)),
+        DropMode::Finalize => parse_quote!(::static_init::FinalyMode::Finalize),
+        DropMode::Drop => parse_quote!(::static_init::FinalyMode::Drop),
+        DropMode::None => parse_quote!(::static_init::FinalyMode::None),
+    };
+
+    let info: Expr = parse_quote!(
+    ::static_init::StaticInfo{
+        variable_name: ::core::stringify!(#statid),
+        file_name: ::core::file!(),
+        line: ::core::line!(),
+        column: ::core::column!(),
+        init_mode: #init_priority,
+        drop_mode: #drop_priority
+        });
+
     let initer = match options.init {
         //InitMode::Dynamic(priority) if options.drop == DropMode::Drop => {
         //    let attr: Attribute = parse_quote_spanned!(Span::mixed_site()=>
@@ -743,23 +1037,47 @@ fn gen_dyn_init(mut stat: ItemStatic, options: DynMode) -> TokenStream2 {
         //    })
         //}
         InitMode::Dynamic(priority) => {
-            let attr: Attribute = parse_quote!(#[::static_init::constructor(#priority)]);
+            let attr: Attribute = parse_quote!(#[::static_init::constructor(#priority, __dynamic)]);
+            let probe = cfg!(feature = "startup_profile").then(|| {
+                quote_spanned! {sp=>
+                    let __static_init_probe = ::static_init::startup_profile::__Probe::start(
+                        ::core::stringify!(#statid),
+                        ::core::option::Option::Some(#priority),
+                        ::core::option::Option::Some(#info),
+                    );
+                }
+            });
             Some(quote_spanned! {sp=>
                     #attr
                     extern "C" fn __static_init_initializer() {
+                        #probe
                         ::static_init::raw_static::__set_init_prio(#priority as i32);
                         let __static_init_expr_result = #expr;
                         unsafe {#typ::set_to(#stat_ref,__static_init_expr_result)};
+                        ::static_init::registry::Node::set_phase(
+                            &__STATIC_INIT_REGISTRY_NODE,
+                            ::static_init::Phase::INITIALIZED
+                        );
                         ::static_init::raw_static::__set_init_prio(i32::MIN);
                     }
             })
         }
 
         InitMode::LesserLazy if !is_thread_local && cfg!(support_priority) => {
+            let probe = cfg!(feature = "startup_profile").then(|| {
+                quote_spanned! {sp=>
+                    let __static_init_probe = ::static_init::startup_profile::__Probe::start(
+                        ::core::stringify!(#statid),
+                        ::core::option::Option::None,
+                        ::core::option::Option::Some(#info),
+                    );
+                }
+            });
             Some(quote_spanned! {sp=>
                     #[::static_init::constructor(__lazy_init)]
                     extern "C" fn __static_init_initializer() {
-                        unsafe {#typ::init(#stat_ref)};
+                        #probe
+                        unsafe {#static_typ::init(#stat_ref)};
                     }
             })
         }
@@ -773,58 +1091,200 @@ fn gen_dyn_init(mut stat: ItemStatic, options: DynMode) -> TokenStream2 {
                 #attr
                 extern "C" fn __static_init_droper() {
                     unsafe {#typ::drop(#stat_ref)}
+                    ::static_init::registry::Node::set_phase(
+                        &__STATIC_INIT_REGISTRY_NODE,
+                        ::static_init::Phase::INITIALIZED | ::static_init::Phase::FINALIZED
+                    );
                 }
         })
     } else {
         None
     };
 
-    let statid = &stat.ident;
+    let static_info: Option<&Expr> = if cfg!(debug_mode) { Some(&info) } else { None };
 
-    let init_priority: Expr = match options.init {
-        InitMode::Dynamic(n) => parse_quote!(::static_init::InitMode::ProgramConstructor(#n)),
-        InitMode::Lazy => parse_quote!(::static_init::InitMode::Lazy),
-        InitMode::LesserLazy => parse_quote!(::static_init::InitMode::LesserLazy),
-        InitMode::Const => parse_quote!(::static_init::InitMode::Const),
+    // statics finalized at program exit keep their informations for shutdown_with
+    let lazy_info = cfg!(debug_mode)
+        || cfg!(feature = "tracing")
+        || cfg!(feature = "cycle_detect")
+        || cfg!(feature = "deadlock_detection")
+        || matches!(options.drop, DropMode::Drop | DropMode::Finalize);
+
+    let registration = if cfg!(constructor_destructor) {
+        let node = match options.init {
+            InitMode::Lazy | InitMode::LesserLazy if options.stats => quote_spanned! {sp=>
+                fn __static_init_phase() -> ::static_init::Phase {
+                    ::static_init::Phased::phase(&#statid)
+                }
+                static __STATIC_INIT_REGISTRY_NODE: ::static_init::registry::Node =
+                    ::static_init::registry::Node::new(#info, __static_init_phase)
+                        .with_statistics(
+                            <#stat_generator_name as ::static_init::GeneratorTolerance>::STATISTICS
+                        );
+            },
+            InitMode::Lazy | InitMode::LesserLazy => quote_spanned! {sp=>
+                fn __static_init_phase() -> ::static_init::Phase {
+                    ::static_init::Phased::phase(&#statid)
+                }
+                static __STATIC_INIT_REGISTRY_NODE: ::static_init::registry::Node =
+                    ::static_init::registry::Node::new(#info, __static_init_phase);
+            },
+            InitMode::Dynamic(_) => quote_spanned! {sp=>
+                static __STATIC_INIT_REGISTRY_NODE: ::static_init::registry::Node =
+                    ::static_init::registry::Node::new_raw(#info, ::static_init::Phase::empty());
+            },
+            InitMode::Const => quote_spanned! {sp=>
+                static __STATIC_INIT_REGISTRY_NODE: ::static_init::registry::Node =
+                    ::static_init::registry::Node::new_raw(#info, ::static_init::Phase::INITIALIZED);
+            },
+        };
+        let section = match registry_section() {
+            Ok(section) => LitStr::new(&section, Span::call_site()),
+            Err(e) => return e.into(),
+        };
+        Some(quote_spanned! {sp=>
+                #node
+                #[link_section = #section]
+                #[used]
+                static __STATIC_INIT_REGISTRY_ENTRY: ::core::option::Option<
+                    &'static ::static_init::registry::Node
+                > = ::core::option::Option::Some(&__STATIC_INIT_REGISTRY_NODE);
+        })
+    } else {
+        None
     };
 
-    let drop_priority: Expr = match options.drop {
-        DropMode::Dynamic(n) => parse_quote!(::static_init::FinalyMode::ProgramDestructor(#n)),
-        DropMode::Finalize => parse_quote!(::static_init::FinalyMode::Finalize),
-        DropMode::Drop => parse_quote!(::static_init::FinalyMode::Drop),
-        DropMode::None => parse_quote!(::static_init::FinalyMode::None),
+    let dependency_node = if options.after.is_empty() {
+        None
+    } else {
+        let after = options.after.iter().map(|dep| {
+            let mut dep = dep.clone();
+            let last = dep.segments.last_mut().unwrap();
+            last.ident = Ident::new(
+                &format!("__StaticInitGeneratorFor_{}", last.ident),
+                last.ident.span(),
+            );
+            dep
+        });
+        Some(quote_spanned! {sp=>
+            impl #stat_generator_name {
+                #[doc(hidden)]
+                #[inline]
+                #stat_vis fn __static_init_dependency() -> &'static ::static_init::dependency::Node {
+                    static __STATIC_INIT_DEPENDENCY_NODE: ::static_init::dependency::Node =
+                        ::static_init::dependency::Node::new(
+                            #info,
+                            &[#(::static_init::dependency::Dependency::of(
+                                <#after as ::static_init::GeneratorTolerance>::DEPENDENCY
+                            )),*]
+                        );
+                    &__STATIC_INIT_DEPENDENCY_NODE
+                }
+            }
+        })
     };
 
-    let static_info: Option<Expr> = if cfg!(debug_mode) {
-        Some(parse_quote!(
-        ::static_init::StaticInfo{
-            variable_name: ::core::stringify!(#statid),
-            file_name: ::core::file!(),
-            line: ::core::line!(),
-            column: ::core::column!(),
-            init_mode: #init_priority,
-            drop_mode: #drop_priority
-            }))
+    // lazy statics can be named in `after(..)` by other statics
+    let dependency =
+        if matches!(options.init, InitMode::Lazy | InitMode::LesserLazy) && !is_thread_local {
+            let node = if options.after.is_empty() {
+                quote_spanned! {sp=> None}
+            } else {
+                quote_spanned! {sp=> Some(#stat_generator_name::__static_init_dependency)}
+            };
+            Some(quote_spanned! {sp=>
+                const DEPENDENCY: Option<::static_init::dependency::Dependency> = {
+                    fn __static_init_init() {
+                        let _ = #static_typ::init(&#statid);
+                    }
+                    Some(::static_init::dependency::Dependency::new(__static_init_init, #node))
+                };
+            })
+        } else {
+            None
+        };
+
+    // the statistics block is reached through the generator so that lockers
+    // of statics without statistics do not have to store a pointer to it
+    let statistics = if options.stats {
+        Some(quote_spanned! {sp=>
+            const STATISTICS: Option<fn() -> &'static ::static_init::statistics::Statistics> = {
+                fn __static_init_statistics() -> &'static ::static_init::statistics::Statistics {
+                    static __STATIC_INIT_STATISTICS: ::static_init::statistics::Statistics =
+                        ::static_init::statistics::Statistics::new();
+                    &__STATIC_INIT_STATISTICS
+                }
+                Some(__static_init_statistics)
+            };
+        })
     } else {
         None
     };
 
+    let init_dependencies = if options.after.is_empty() {
+        None
+    } else {
+        Some(quote_spanned! {sp=>
+            ::static_init::dependency::Node::init_dependencies(
+                #stat_generator_name::__static_init_dependency()
+            );
+        })
+    };
+
+    let registration = if !options.after.is_empty() && cfg!(support_priority) {
+        Some(quote_spanned! {sp=>
+            #registration
+            #[::static_init::constructor(__registry)]
+            extern "C" fn __static_init_register_dependency() {
+                ::static_init::dependency::Node::register(
+                    #stat_generator_name::__static_init_dependency()
+                );
+            }
+        })
+    } else {
+        registration
+    };
+
     let init_fail_tol = options.tolerance.init_fail;
     let reg_fail_tol = options.tolerance.registration_fail;
+    let finaly_priority = options.finaly_priority;
 
-    let lazy_generator = if matches!(options.init, InitMode::Lazy | InitMode::LesserLazy) {
+    let lazy_generator = if let Some((ok_typ, err_typ)) = fallible_types {
+        Some(quote_spanned! {sp=>
+            #[allow(clippy::upper_case_acronyms)]
+            #stat_vis struct #stat_generator_name;
+            impl ::static_init::TryGenerator<#ok_typ, #err_typ> for #stat_generator_name {
+                #[inline]
+                fn try_generate(&self) -> #stat_typ {
+                    #init_dependencies
+                    #expr
+                }
+            }
+            impl ::static_init::GeneratorTolerance for #stat_generator_name {
+                const INIT_FAILURE: bool = #init_fail_tol;
+                const FINAL_REGISTRATION_FAILURE: bool = #reg_fail_tol;
+                const FINALIZATION_PRIORITY: u16 = #finaly_priority;
+                #dependency
+                #statistics
+            }
+        })
+    } else if matches!(options.init, InitMode::Lazy | InitMode::LesserLazy) {
         Some(quote_spanned! {sp=>
             #[allow(clippy::upper_case_acronyms)]
             #stat_vis struct #stat_generator_name;
             impl ::static_init::Generator<#stat_typ> for #stat_generator_name {
                 #[inline]
                 fn generate(&self) -> #stat_typ {
+                    #init_dependencies
                     #expr
                 }
             }
             impl ::static_init::GeneratorTolerance for #stat_generator_name {
                 const INIT_FAILURE: bool = #init_fail_tol;
                 const FINAL_REGISTRATION_FAILURE: bool = #reg_fail_tol;
+                const FINALIZATION_PRIORITY: u16 = #finaly_priority;
+                #dependency
+                #statistics
             }
         })
     } else {
@@ -836,23 +1296,36 @@ fn gen_dyn_init(mut stat: ItemStatic, options: DynMode) -> TokenStream2 {
             quote_spanned! {sp=>{
                 #initer
                 #droper
+                #registration
                 unsafe{#typ::uninit(#static_info)}
             }
             }
         }
-        InitMode::Lazy | InitMode::LesserLazy if options.priming && cfg!(debug_mode) => {
+        InitMode::Lazy if options.fallible => {
+            quote_spanned! {sp=>{
+                #registration
+
+                let _ = ();
+
+                #typ::from_generator_with_info(#stat_generator_name, #info)
+            }
+            }
+        }
+        InitMode::Lazy | InitMode::LesserLazy if options.priming && lazy_info => {
             quote_spanned! {sp=> {
                 #initer
+                #registration
 
                 let _ = ();
 
-                unsafe{#typ::from_generator_with_info(#prime_expr,#stat_generator_name, #static_info)}
+                unsafe{#typ::from_generator_with_info(#prime_expr,#stat_generator_name, #info)}
             }
             }
         }
         InitMode::Lazy | InitMode::LesserLazy if options.priming => {
             quote_spanned! {sp=> {
                 #initer
+                #registration
 
                 let _ = ();
 
@@ -860,19 +1333,21 @@ fn gen_dyn_init(mut stat: ItemStatic, options: DynMode) -> TokenStream2 {
             }
             }
         }
-        InitMode::Lazy | InitMode::LesserLazy if cfg!(debug_mode) => {
+        InitMode::Lazy | InitMode::LesserLazy if lazy_info => {
             quote_spanned! {sp=> {
                 #initer
+                #registration
 
                 let _ = ();
 
-                unsafe{#typ::from_generator_with_info(#stat_generator_name, #static_info)}
+                unsafe{#typ::from_generator_with_info(#stat_generator_name, #info)}
             }
             }
         }
         InitMode::Lazy | InitMode::LesserLazy => {
             quote_spanned! {sp=>{
                 #initer
+                #registration
 
                 let _ = ();
 
@@ -884,21 +1359,31 @@ fn gen_dyn_init(mut stat: ItemStatic, options: DynMode) -> TokenStream2 {
             quote_spanned! {sp=>{
                 #initer
                 #droper
+                #registration
                 #typ::from(#expr, #static_info)
             }
             }
         }
     };
 
+    let const_init = if options.poison {
+        quote_spanned! {sp=>
+            ::static_init::poison::Poison::new(#const_init)
+        }
+    } else {
+        const_init
+    };
+
     *stat.expr = match parse(const_init.into()) {
         Ok(exp) => exp,
         Err(e) => return e.to_compile_error(),
     };
 
-    *stat.ty = typ;
+    *stat.ty = static_typ;
 
     quote_spanned! {sp=>
     #lazy_generator
+    #dependency_node
     #stat
     }
 }
@@ -0,0 +1,153 @@
+#![cfg(feature = "alloc")]
+
+use static_init::{AsyncLazy, AsyncLockedLazy, Phase};
+use std::future::Future;
+use std::panic::catch_unwind;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark()
+    }
+}
+
+fn block_on<F: Future>(f: F) -> F::Output {
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let mut f = Box::pin(f);
+    loop {
+        match f.as_mut().poll(&mut cx) {
+            Poll::Ready(v) => return v,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+/// A future that returns pending `n` times before completing.
+struct YieldNow(u32);
+
+impl Future for YieldNow {
+    type Output = ();
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 == 0 {
+            Poll::Ready(())
+        } else {
+            self.0 -= 1;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+static INIT_COUNT: AtomicU32 = AtomicU32::new(0);
+
+static NORMAL: AsyncLazy<Vec<i32>> = AsyncLazy::new(|| {
+    Box::pin(async {
+        INIT_COUNT.fetch_add(1, Ordering::Relaxed);
+        YieldNow(10).await;
+        vec![1, 2]
+    })
+});
+
+#[test]
+fn normal() {
+    assert!(NORMAL.phase().is_empty());
+    assert!(NORMAL.try_get().is_err());
+
+    let threads: Vec<_> = (0..8)
+        .map(|_| thread::spawn(|| block_on(NORMAL.get()).len()))
+        .collect();
+    for t in threads {
+        assert_eq!(t.join().unwrap(), 2);
+    }
+
+    assert_eq!(INIT_COUNT.load(Ordering::Relaxed), 1);
+    assert_eq!(NORMAL.phase(), Phase::INITIALIZED);
+    assert_eq!(*NORMAL.try_get().unwrap(), vec![1, 2]);
+}
+
+static PANIC_COUNT: AtomicU32 = AtomicU32::new(0);
+
+static INIT_MAY_PANICK: AsyncLazy<i32> = AsyncLazy::new(|| {
+    Box::pin(async {
+        YieldNow(1).await;
+        if PANIC_COUNT.fetch_add(1, Ordering::Relaxed) < 1 {
+            panic!("Panicked on purpose");
+        }
+        3
+    })
+});
+
+#[test]
+fn init_may_panick() {
+    assert!(catch_unwind(|| block_on(INIT_MAY_PANICK.get())).is_err());
+    assert_eq!(
+        INIT_MAY_PANICK.phase(),
+        Phase::INITIALIZATION_PANICKED | Phase::INITIALIZATION_SKIPED
+    );
+    assert_eq!(*block_on(INIT_MAY_PANICK.get()), 3);
+    assert_eq!(INIT_MAY_PANICK.phase(), Phase::INITIALIZED);
+}
+
+#[test]
+fn cancelled_initialization() {
+    let v: AsyncLazy<i32> = AsyncLazy::new(|| {
+        Box::pin(async {
+            YieldNow(1).await;
+            4
+        })
+    });
+    {
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let mut f = Box::pin(v.get());
+        assert!(f.as_mut().poll(&mut cx).is_pending());
+    }
+    assert!(v.phase().is_empty());
+    assert_eq!(*block_on(v.get()), 4);
+}
+
+static LOCKED: AsyncLockedLazy<Vec<i32>> = AsyncLockedLazy::new(|| Box::pin(async { vec![1, 2] }));
+
+#[test]
+fn locked() {
+    assert!(LOCKED.phase().is_empty());
+
+    let threads: Vec<_> = (0..8)
+        .map(|_| {
+            thread::spawn(|| {
+                block_on(async {
+                    for _ in 0..100 {
+                        let mut l = LOCKED.write().await;
+                        l.push(0);
+                        YieldNow(1).await;
+                        l.pop();
+                        drop(l);
+                        assert_eq!(LOCKED.read().await.len(), 2);
+                    }
+                })
+            })
+        })
+        .collect();
+    for t in threads {
+        t.join().unwrap();
+    }
+
+    assert_eq!(LOCKED.phase(), Phase::INITIALIZED);
+    assert_eq!(*block_on(LOCKED.read()), vec![1, 2]);
+}
+
+fn is_send<T: Send>(_: &T) {}
+
+#[test]
+fn futures_are_send() {
+    is_send(&NORMAL.get());
+    is_send(&LOCKED.read());
+    is_send(&LOCKED.write());
+}
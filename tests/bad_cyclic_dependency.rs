@@ -0,0 +1,27 @@
+use static_init::{constructor, dynamic};
+
+#[dynamic(after(V1))]
+static V0: i32 = 0;
+
+#[dynamic(after(V0))]
+static V1: i32 = 1;
+
+#[constructor(10)]
+extern "C" fn set_hook() {
+    std::panic::set_hook(Box::new(|p| {
+        let msg = p.to_string();
+        if msg.contains("Cyclic dependency between statics: V") {
+            println!("Panic expectedly caught {}", msg);
+            std::process::exit(0)
+        } else {
+            println!("Unexpected panic {}", msg);
+            std::process::exit(1)
+        }
+    }));
+}
+
+#[test]
+fn bad_cyclic_dependency() {
+    assert_eq!(*V0 + *V1, 1);
+    panic!("Cyclic dependency not detected")
+}
@@ -7,17 +7,23 @@
 
 #[cfg(debug_mode)]
 mod test {
-    use static_init::{constructor, dynamic, CyclicPanic};
+    use static_init::{constructor, dynamic, CycleError};
 
     #[dynamic(lesser_lazy)]
     static V0: i32 = *V0;
 
     fn panic_hook(p: &core::panic::PanicInfo<'_>) {
-        if p.payload().is::<CyclicPanic>() {
-            println!("Cyclic Panic running");
-        } else {
+        let expected = format!(
+            "Circular lazy initialization: V0 ({}:12) -> V0",
+            file!()
+        );
+        let err = p.payload().downcast_ref::<CycleError>();
+        if err.map_or(false, |e| e.to_string() == expected) {
             println!("Panic expectedly caught {:?}", p);
             std::process::exit(0)
+        } else {
+            println!("Unexpected panic {:?}", p);
+            std::process::exit(1)
         }
     }
 
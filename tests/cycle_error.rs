@@ -0,0 +1,103 @@
+#![cfg(any(feature = "cycle_detect", debug_mode))]
+
+use static_init::{dynamic, CycleError, Lazy};
+use std::panic::catch_unwind;
+
+#[dynamic(lazy)]
+static A: u32 = *B + 1;
+
+#[dynamic(lazy)]
+static B: u32 = *C + 1;
+
+#[dynamic(lazy)]
+static C: u32 = *A + 1;
+
+#[dynamic(lazy)]
+static mut M: u32 = *M.read() + 1;
+
+#[dynamic(lazy)]
+static OK: u32 = *C2 + 1;
+
+#[dynamic(lazy)]
+static C2: u32 = 1;
+
+#[dynamic(lazy, fallible)]
+static F: Result<u32, String> = Ok(*F.get().unwrap() + 1);
+
+#[dynamic(lazy, fallible)]
+static mut FM: Result<u32, String> = Ok(*FM.read().unwrap() + 1);
+
+fn cycle_error(f: impl FnOnce() + std::panic::UnwindSafe) -> CycleError {
+    *catch_unwind(f)
+        .unwrap_err()
+        .downcast::<CycleError>()
+        .unwrap()
+}
+
+#[test]
+fn cycle_chain() {
+    let err = cycle_error(|| {
+        let _ = *A;
+    });
+    let path = err
+        .path()
+        .iter()
+        .map(|info| (info.unwrap().variable_name, info.unwrap().line))
+        .collect::<Vec<_>>();
+    assert_eq!(path, [("A", 6), ("B", 9), ("C", 12), ("A", 6)]);
+    let file = file!();
+    assert_eq!(
+        err.to_string(),
+        format!("Circular lazy initialization: A ({file}:6) -> B ({file}:9) -> C ({file}:12) -> A")
+    );
+}
+
+#[test]
+fn self_dependent_mut() {
+    let err = cycle_error(|| {
+        let _ = *M.read();
+    });
+    assert_eq!(
+        err.to_string(),
+        format!("Circular lazy initialization: M ({}:15) -> M", file!())
+    );
+}
+
+#[test]
+fn self_dependent_fallible() {
+    let err = cycle_error(|| {
+        let _ = F.get();
+    });
+    assert_eq!(
+        err.to_string(),
+        format!("Circular lazy initialization: F ({}:24) -> F", file!())
+    );
+    let err = cycle_error(|| {
+        let _ = FM.read();
+    });
+    assert_eq!(
+        err.to_string(),
+        format!("Circular lazy initialization: FM ({}:27) -> FM", file!())
+    );
+}
+
+#[test]
+fn anonymous_lazy() {
+    fn l() -> u32 {
+        *L
+    }
+    static L: Lazy<u32> = Lazy::from_generator(l);
+    let err = cycle_error(|| {
+        let _ = *L;
+    });
+    assert_eq!(err.path(), [None, None]);
+    assert_eq!(
+        err.to_string(),
+        "Circular lazy initialization: <lazy> -> <lazy>"
+    );
+}
+
+#[test]
+fn no_cycle() {
+    assert_eq!(*OK, 2);
+}
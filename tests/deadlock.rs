@@ -0,0 +1,75 @@
+#![cfg(feature = "deadlock_detection")]
+
+use static_init::{check_deadlocks, dynamic};
+use std::sync::Barrier;
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[dynamic(lazy)]
+static mut A: i32 = 0;
+
+#[dynamic(lazy)]
+static mut B: i32 = 0;
+
+#[dynamic(lazy)]
+static mut C: i32 = 0;
+
+#[test]
+fn two_threads_deadlock() {
+    let barrier: &'static Barrier = Box::leak(Box::new(Barrier::new(2)));
+    let t1 = thread::spawn(move || {
+        let _a = A.write();
+        barrier.wait();
+        *B.write() += 1;
+    });
+    let t2 = thread::spawn(move || {
+        let _b = B.write();
+        barrier.wait();
+        *A.write() += 1;
+    });
+    let ids = [t1.thread().id(), t2.thread().id()];
+
+    let start = Instant::now();
+    let deadlocks = loop {
+        let deadlocks = check_deadlocks();
+        if !deadlocks.is_empty() || start.elapsed() > Duration::from_secs(10) {
+            break deadlocks;
+        }
+        thread::sleep(Duration::from_millis(10));
+    };
+
+    assert_eq!(deadlocks.len(), 1);
+    let threads = deadlocks[0].threads();
+    assert_eq!(threads.len(), 2);
+    for t in threads {
+        assert!(ids.contains(&t.thread_id()));
+    }
+    assert_ne!(threads[0].thread_id(), threads[1].thread_id());
+
+    #[cfg(not(feature = "spin_loop"))]
+    {
+        let t1 = threads.iter().find(|t| t.thread_id() == ids[0]).unwrap();
+        let t2 = threads.iter().find(|t| t.thread_id() == ids[1]).unwrap();
+        assert_eq!(t1.waiting_on().unwrap().variable_name, "B");
+        assert_eq!(t2.waiting_on().unwrap().variable_name, "A");
+        assert_eq!(t1.waiting_on().unwrap().line, 11);
+
+        let message = deadlocks[0].to_string();
+        assert!(message.starts_with("Dead lock:"));
+        assert!(message.contains(&format!(
+            "{:?} waits for B (tests/deadlock.rs:11) held by {:?};",
+            ids[0], ids[1]
+        )));
+    }
+}
+
+#[test]
+fn contention_is_not_a_deadlock() {
+    let c = C.write();
+    let t = thread::spawn(|| *C.write() += 1);
+    thread::sleep(Duration::from_millis(50));
+    assert!(check_deadlocks().is_empty());
+    drop(c);
+    t.join().unwrap();
+    assert_eq!(*C.read(), 1);
+}
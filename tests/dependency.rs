@@ -0,0 +1,57 @@
+use static_init::dynamic;
+use std::sync::Mutex;
+
+static ORDER: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+
+fn record(name: &'static str) {
+    ORDER.lock().unwrap().push(name)
+}
+
+mod config {
+    use super::record;
+    use static_init::dynamic;
+
+    #[dynamic(after(super::LOGGER))]
+    pub static CONFIG: u32 = {
+        record("CONFIG");
+        42
+    };
+}
+
+#[dynamic(after(config::CONFIG, LOGGER))]
+static SERVICE: u32 = {
+    record("SERVICE");
+    *config::CONFIG + 1
+};
+
+#[dynamic(after(SERVICE))]
+static mut REGISTRY: Vec<u32> = {
+    record("REGISTRY");
+    vec![*SERVICE]
+};
+
+#[dynamic]
+static LOGGER: u32 = {
+    record("LOGGER");
+    1
+};
+
+#[dynamic(lazy, after(LOGGER))]
+static LAZY: u32 = {
+    record("LAZY");
+    2
+};
+
+#[test]
+fn topological_order() {
+    let order = ORDER.lock().unwrap().clone();
+    let pos = |name| order.iter().position(|n| *n == name).unwrap();
+    assert_eq!(order.len(), 4);
+    assert!(pos("LOGGER") < pos("CONFIG"));
+    assert!(pos("CONFIG") < pos("SERVICE"));
+    assert!(pos("SERVICE") < pos("REGISTRY"));
+    assert_eq!(REGISTRY.read()[0], 43);
+
+    assert_eq!(*LAZY, 2);
+    assert_eq!(ORDER.lock().unwrap().last(), Some(&"LAZY"));
+}
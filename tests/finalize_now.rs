@@ -0,0 +1,95 @@
+use static_init::{destructor, dynamic, Finaly, Phase};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static DROP_COUNT: AtomicU32 = AtomicU32::new(0);
+
+struct A(u32);
+
+impl Drop for A {
+    fn drop(&mut self) {
+        DROP_COUNT.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[dynamic(lazy, drop)]
+static mut FIRST: A = A(1);
+
+#[dynamic(lazy, drop)]
+static mut MIDDLE: A = A(2);
+
+#[dynamic(lazy, drop)]
+static mut LAST: A = A(3);
+
+#[dynamic(lazy, drop)]
+static mut UNINITIALIZED: A = A(4);
+
+#[test]
+fn locked_lazy_droped() {
+    assert_eq!(FIRST.read().0, 1);
+    assert_eq!(MIDDLE.read().0, 2);
+    assert_eq!(LAST.read().0, 3);
+
+    assert_eq!(
+        MIDDLE.finalize_now(),
+        Phase::INITIALIZED | Phase::REGISTERED | Phase::FINALIZED
+    );
+
+    assert!(MIDDLE.try_read().is_err());
+
+    assert_eq!(DROP_COUNT.load(Ordering::Relaxed), 1);
+
+    assert_eq!(
+        MIDDLE.finalize_now(),
+        Phase::INITIALIZED | Phase::REGISTERED | Phase::FINALIZED
+    );
+
+    assert_eq!(DROP_COUNT.load(Ordering::Relaxed), 1);
+
+    assert!(UNINITIALIZED.finalize_now().is_empty());
+
+    assert_eq!(FIRST.read().0, 1);
+    assert_eq!(LAST.read().0, 3);
+}
+
+static FINALIZE_COUNT: AtomicU32 = AtomicU32::new(0);
+
+struct B(u32);
+
+impl Finaly for B {
+    fn finaly(&self) {
+        FINALIZE_COUNT.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[dynamic(lazy, finalize)]
+static LAZY_FINALIZE: B = B(5);
+
+#[dynamic(finalize)]
+static LESSER_FINALIZE: B = B(6);
+
+#[test]
+fn lazy_finalize() {
+    assert_eq!(LAZY_FINALIZE.0, 5);
+
+    assert_eq!(
+        LAZY_FINALIZE.finalize_now(),
+        Phase::INITIALIZED | Phase::REGISTERED | Phase::FINALIZED
+    );
+
+    assert_eq!(
+        LESSER_FINALIZE.finalize_now(),
+        Phase::INITIALIZED | Phase::REGISTERED | Phase::FINALIZED
+    );
+
+    assert_eq!(FINALIZE_COUNT.load(Ordering::Relaxed), 2);
+
+    // finalized statics are still accessible
+    assert_eq!(LAZY_FINALIZE.0, 5);
+    assert_eq!(LESSER_FINALIZE.0, 6);
+}
+
+#[destructor(10)]
+extern "C" fn check_finalized_once() {
+    assert_eq!(DROP_COUNT.load(Ordering::Relaxed), 3);
+    assert_eq!(FINALIZE_COUNT.load(Ordering::Relaxed), 2);
+}
@@ -0,0 +1,45 @@
+use static_init::{destructor, dynamic, Finaly};
+use std::sync::Mutex;
+
+static ORDER: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+struct A(u32);
+
+impl Drop for A {
+    fn drop(&mut self) {
+        ORDER.lock().unwrap().push(self.0);
+    }
+}
+
+struct B(u32);
+
+impl Finaly for B {
+    fn finaly(&self) {
+        ORDER.lock().unwrap().push(self.0);
+    }
+}
+
+#[dynamic(lazy, drop = 10)]
+static mut EXPORTER: A = A(10);
+
+#[dynamic(finalize = 5)]
+static FLUSHER: B = B(5);
+
+#[dynamic(lazy, drop)]
+static mut COMPONENT_1: A = A(1);
+
+#[dynamic(lazy, drop)]
+static mut COMPONENT_2: A = A(2);
+
+#[test]
+fn priority_order() {
+    assert_eq!(EXPORTER.read().0, 10);
+    assert_eq!(FLUSHER.0, 5);
+    assert_eq!(COMPONENT_1.read().0, 1);
+    assert_eq!(COMPONENT_2.read().0, 2);
+}
+
+#[destructor(10)]
+extern "C" fn check_finalization_order() {
+    assert_eq!(*ORDER.lock().unwrap(), vec![2, 1, 5, 10]);
+}
@@ -1,6 +1,8 @@
-use static_init::{dynamic, Lazy, LazyAccess, Phase};
+use static_init::{dynamic, Lazy, LazyAccess, OnceGenerator, Phase};
 use std::panic::catch_unwind;
 use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::thread;
 
 #[dynamic(lazy)]
 static NORMAL: Vec<i32> = vec![1, 2];
@@ -180,6 +182,33 @@ fn local_lazy() {
     assert_eq!(drop_count, 1);
 }
 
+#[test]
+fn shared_local_lazy() {
+    struct Shared {
+        v: Lazy<Vec<i32>, OnceGenerator<Box<dyn FnOnce() -> Vec<i32> + Send>>>,
+    }
+    static INIT_COUNT: AtomicU32 = AtomicU32::new(0);
+
+    let base = vec![1, 2];
+    let shared = Arc::new(Shared {
+        v: Lazy::new(Box::new(move || {
+            INIT_COUNT.fetch_add(1, Ordering::Relaxed);
+            base
+        })),
+    });
+
+    let threads = (0..4)
+        .map(|_| {
+            let shared = Arc::clone(&shared);
+            thread::spawn(move || assert_eq!(*shared.v, vec![1, 2]))
+        })
+        .collect::<Vec<_>>();
+    for t in threads {
+        t.join().unwrap();
+    }
+    assert_eq!(INIT_COUNT.load(Ordering::Relaxed), 1);
+}
+
 #[test]
 fn local_lazy_mut() {
     let mut v = Lazy::new(|| vec![1, 2]);
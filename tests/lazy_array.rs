@@ -0,0 +1,145 @@
+use static_init::{destructor, dynamic, LazyArray, LockedLazyArray, Phase};
+use std::panic::catch_unwind;
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+static GENERATED: [AtomicU32; 4] = [
+    AtomicU32::new(0),
+    AtomicU32::new(0),
+    AtomicU32::new(0),
+    AtomicU32::new(0),
+];
+
+static SQUARES: LazyArray<usize, 4> = LazyArray::new(|i| {
+    GENERATED[i].fetch_add(1, Ordering::Relaxed);
+    i * i
+});
+
+#[test]
+fn once_per_slot() {
+    assert_eq!(SQUARES.try_get(2).unwrap_err().phase, Phase::empty());
+    assert!(SQUARES.phase(2).is_empty());
+
+    assert_eq!(*SQUARES.get(2), 4);
+    assert_eq!(*SQUARES.get(3), 9);
+    assert_eq!(*SQUARES.get(2), 4);
+    assert!(std::ptr::eq(SQUARES.get(2), SQUARES.try_get(2).unwrap()));
+
+    assert_eq!(GENERATED[2].load(Ordering::Relaxed), 1);
+    assert_eq!(GENERATED[3].load(Ordering::Relaxed), 1);
+    assert_eq!(GENERATED[1].load(Ordering::Relaxed), 0);
+    assert_eq!(SQUARES.phase(2), Phase::INITIALIZED);
+    assert!(SQUARES.try_get(1).is_err());
+}
+
+#[test]
+fn out_of_bounds() {
+    assert!(catch_unwind(|| SQUARES.get(4)).is_err());
+}
+
+type Signals = (Sender<()>, Receiver<()>);
+
+static SLOW_SLOT: Mutex<Option<Signals>> = Mutex::new(None);
+
+static CONCURRENT: LazyArray<usize, 2> = LazyArray::new(|i| {
+    if i == 0 {
+        let (started, resume) = SLOW_SLOT.lock().unwrap().take().unwrap();
+        started.send(()).unwrap();
+        resume
+            .recv_timeout(Duration::from_secs(10))
+            .expect("the initializations of distinct slots are serialized");
+    }
+    i
+});
+
+#[test]
+fn distinct_slots_are_initialized_concurrently() {
+    let (started, on_started) = channel();
+    let (resume, on_resume) = channel();
+    *SLOW_SLOT.lock().unwrap() = Some((started, on_resume));
+    let slow = thread::spawn(|| *CONCURRENT.get(0));
+    on_started.recv().unwrap();
+    assert!(CONCURRENT.try_get(0).is_err());
+    assert_eq!(*CONCURRENT.get(1), 1);
+    resume.send(()).unwrap();
+    assert_eq!(slow.join().unwrap(), 0);
+}
+
+static ATTEMPTS: AtomicU32 = AtomicU32::new(0);
+
+static RETRIED: LazyArray<usize, 1> = LazyArray::new(|i| {
+    if ATTEMPTS.fetch_add(1, Ordering::Relaxed) == 0 {
+        panic!("first attempt")
+    }
+    i
+});
+
+#[test]
+fn initialization_panic() {
+    assert!(catch_unwind(|| RETRIED.get(0)).is_err());
+    let err = RETRIED.try_get(0).unwrap_err();
+    assert!(err.phase.intersects(Phase::INITIALIZATION_PANICKED));
+    assert_eq!(*RETRIED.get(0), 0);
+    assert!(RETRIED.try_get(0).is_ok());
+}
+
+static COUNTERS: LockedLazyArray<Vec<usize>, 3> = LockedLazyArray::new(|i| vec![i]);
+
+#[test]
+fn locked_slots() {
+    assert!(COUNTERS.try_read(1).is_err());
+    assert!(COUNTERS.try_write(1).is_err());
+    assert!(COUNTERS.phase(1).is_empty());
+
+    let threads = (0..8)
+        .map(|i| thread::spawn(move || COUNTERS.write(1).push(i)))
+        .collect::<Vec<_>>();
+    for t in threads {
+        t.join().unwrap();
+    }
+    assert_eq!(COUNTERS.read(1).len(), 9);
+    assert_eq!(COUNTERS.read(1)[0], 1);
+    assert_eq!(*COUNTERS.read(2), vec![2]);
+    assert_eq!(COUNTERS.phase(1), Phase::INITIALIZED);
+    assert!(COUNTERS.try_write(2).is_ok());
+    assert!(COUNTERS.try_read(0).is_err());
+}
+
+static DROPED: AtomicUsize = AtomicUsize::new(0);
+
+struct A(usize);
+
+impl Drop for A {
+    fn drop(&mut self) {
+        DROPED.fetch_add(1 << (8 * self.0), Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn initialized_slots_are_dropped_with_the_array() {
+    let array = LazyArray::<_, 2>::new(|i| A(i + 4));
+    let locked = LockedLazyArray::<_, 2>::new(|i| A(i + 6));
+    assert_eq!(array.get(1).0, 5);
+    assert_eq!(locked.read(0).0, 6);
+    drop(array);
+    drop(locked);
+    assert_eq!(DROPED.load(Ordering::Relaxed) >> 32, 1 << 8 | 1 << 16);
+}
+
+#[dynamic(lazy, drop)]
+static mut TABLE: LazyArray<A, 4> = LazyArray::new(A);
+
+#[test]
+fn dropped_at_exit() {
+    assert_eq!(TABLE.read().get(0).0, 0);
+    assert_eq!(TABLE.read().get(2).0, 2);
+    assert!(TABLE.read().try_get(1).is_err());
+}
+
+#[destructor(10)]
+extern "C" fn check_dropped_at_exit() {
+    assert_eq!(DROPED.load(Ordering::Relaxed) & 0xFFFF_FFFF, 1 | 1 << 16);
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "alloc")]
+
+use static_init::{lazy, locked_lazy, Phase};
+use std::any::type_name;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::thread;
+
+static INIT_COUNT: AtomicU32 = AtomicU32::new(0);
+
+fn counted() -> &'static Vec<i32> {
+    lazy!({
+        INIT_COUNT.fetch_add(1, Ordering::Relaxed);
+        vec![1, 2]
+    })
+}
+
+#[test]
+fn lazy_expression() {
+    let threads = (0..4)
+        .map(|_| thread::spawn(|| assert_eq!(*counted(), vec![1, 2])))
+        .collect::<Vec<_>>();
+    for t in threads {
+        t.join().unwrap();
+    }
+    assert!(std::ptr::eq(counted(), counted()));
+    assert_eq!(INIT_COUNT.load(Ordering::Relaxed), 1);
+}
+
+trait Named {
+    fn name(&self) -> &'static str;
+}
+
+struct A;
+
+impl Named for A {
+    fn name(&self) -> &'static str {
+        lazy!(String::from("A")).as_str()
+    }
+}
+
+#[test]
+fn lazy_in_impl() {
+    assert_eq!(A.name(), "A");
+}
+
+fn name_of<T>() -> &'static String {
+    lazy!(type_name::<T>().to_owned())
+}
+
+#[test]
+fn lazy_per_instantiation() {
+    assert_eq!(name_of::<u8>(), "u8");
+    assert_eq!(name_of::<String>(), type_name::<String>());
+    assert!(std::ptr::eq(name_of::<u8>(), name_of::<u8>()));
+}
+
+fn counter<T>(_: T) -> u32 {
+    let mut w = locked_lazy!(0).write();
+    *w += 1;
+    *w
+}
+
+#[test]
+fn locked_lazy_per_instantiation() {
+    assert_eq!(counter(0u8), 1);
+    assert_eq!(counter(0u8), 2);
+    assert_eq!(counter(0u16), 1);
+
+    let l = locked_lazy!(vec![1]);
+    assert!(l.phase().is_empty());
+    l.write().push(2);
+    assert_eq!(l.phase(), Phase::INITIALIZED);
+    assert_eq!(*l.read(), vec![1, 2]);
+}
@@ -0,0 +1,126 @@
+#![cfg(feature = "alloc")]
+
+use static_init::{destructor, Finaly, LazyMap, LazyMapFinalize, Phase};
+use std::panic::catch_unwind;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+static GENERATED: AtomicU32 = AtomicU32::new(0);
+
+static SQUARES: LazyMap<u32, u32> = LazyMap::new(|k: &u32| {
+    GENERATED.fetch_add(1, Ordering::Relaxed);
+    k * k
+});
+
+#[test]
+fn once_per_key() {
+    assert!(SQUARES.is_empty());
+    assert_eq!(SQUARES.try_get(&3).unwrap_err().phase, Phase::empty());
+    assert!(SQUARES.phase(&3).is_empty());
+
+    assert_eq!(*SQUARES.get(&3), 9);
+    assert_eq!(*SQUARES.get(&4), 16);
+    assert_eq!(*SQUARES.get(&3), 9);
+    assert!(std::ptr::eq(SQUARES.get(&3), SQUARES.try_get(&3).unwrap()));
+
+    assert_eq!(GENERATED.load(Ordering::Relaxed), 2);
+    assert_eq!(SQUARES.len(), 2);
+    assert_eq!(SQUARES.phase(&4), Phase::INITIALIZED);
+}
+
+type Signals = (Sender<()>, Receiver<()>);
+
+static SLOW_KEY: Mutex<Option<Signals>> = Mutex::new(None);
+
+static CONCURRENT: LazyMap<&'static str, &'static str> = LazyMap::new(|k: &&'static str| {
+    if *k == "slow" {
+        let (started, resume) = SLOW_KEY.lock().unwrap().take().unwrap();
+        started.send(()).unwrap();
+        resume
+            .recv_timeout(Duration::from_secs(10))
+            .expect("the initializations of distinct keys are serialized");
+    }
+    k
+});
+
+#[test]
+fn distinct_keys_are_initialized_concurrently() {
+    let (started, on_started) = channel();
+    let (resume, on_resume) = channel();
+    *SLOW_KEY.lock().unwrap() = Some((started, on_resume));
+    let slow = thread::spawn(|| *CONCURRENT.get(&"slow"));
+    on_started.recv().unwrap();
+    assert!(CONCURRENT.try_get(&"slow").is_err());
+    assert_eq!(*CONCURRENT.get(&"fast"), "fast");
+    resume.send(()).unwrap();
+    assert_eq!(slow.join().unwrap(), "slow");
+}
+
+static ATTEMPTS: AtomicU32 = AtomicU32::new(0);
+
+static RETRIED: LazyMap<u32, u32> = LazyMap::new(|k: &u32| {
+    if ATTEMPTS.fetch_add(1, Ordering::Relaxed) == 0 {
+        panic!("first attempt")
+    }
+    *k
+});
+
+#[test]
+fn initialization_panic() {
+    assert!(catch_unwind(|| RETRIED.get(&1)).is_err());
+    let err = RETRIED.try_get(&1).unwrap_err();
+    assert!(err.phase.intersects(Phase::INITIALIZATION_PANICKED));
+    assert_eq!(*RETRIED.get(&1), 1);
+    assert!(RETRIED.try_get(&1).is_ok());
+}
+
+static DROPED_COUNT: AtomicU32 = AtomicU32::new(0);
+
+struct A(u32);
+
+impl Drop for A {
+    fn drop(&mut self) {
+        DROPED_COUNT.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn values_are_dropped_with_the_map() {
+    let map = LazyMap::new(|k: &u32| A(*k));
+    assert_eq!(map.get(&1).0, 1);
+    assert_eq!(map.get(&2).0, 2);
+    assert!(map.try_get(&3).is_err());
+    let _ = map.phase(&3);
+    drop(map);
+    assert_eq!(DROPED_COUNT.load(Ordering::Relaxed), 2);
+}
+
+static FINALIZED: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+struct B(u32);
+
+impl Finaly for B {
+    fn finaly(&self) {
+        FINALIZED.lock().unwrap().push(self.0);
+    }
+}
+
+static FINALIZE: LazyMapFinalize<u32, B> = LazyMapFinalize::new(|k: &u32| B(*k));
+
+#[test]
+fn finalized_at_exit() {
+    assert!(FINALIZE.try_get(&1).is_err());
+    assert_eq!(FINALIZE.get(&1).0, 1);
+    assert_eq!(FINALIZE.get(&2).0, 2);
+    assert_eq!(FINALIZE.phase(&1), Phase::INITIALIZED | Phase::REGISTERED);
+    let _ = FINALIZE.phase(&3);
+    assert_eq!(FINALIZE.len(), 2);
+}
+
+#[destructor(10)]
+extern "C" fn check_finalized() {
+    assert_eq!(*FINALIZED.lock().unwrap(), vec![2, 1]);
+}
@@ -71,3 +71,31 @@ fn pre_inited_normal() {
 
     assert_eq!(*PRE_INITED_NORMAL.write(), vec![1, 2, 3]);
 }
+
+#[dynamic]
+static mut RESET: Vec<i32> = vec![1, 2];
+
+#[test]
+fn reset() {
+    RESET.write().push(3);
+
+    assert_eq!(RESET.reset(), Phase::INITIALIZED);
+
+    assert!(RESET.phase().is_empty());
+
+    assert!(RESET.try_read().is_err());
+
+    assert_eq!(*RESET.read(), vec![1, 2]);
+
+    RESET.reset();
+
+    RESET.fast_write().unwrap().push(4);
+
+    assert_eq!(*RESET.read(), vec![1, 2, 4]);
+
+    RESET.reinit().push(5);
+
+    assert_eq!(*RESET.read(), vec![1, 2, 5]);
+
+    assert_eq!(RESET.phase(), Phase::INITIALIZED);
+}
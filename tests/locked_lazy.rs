@@ -1,6 +1,8 @@
 use static_init::{dynamic, LockedLazy, Phase};
-use std::panic::catch_unwind;
+use std::panic::{catch_unwind, AssertUnwindSafe};
 use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::thread;
 
 #[dynamic(lazy)]
 static mut NORMAL: Vec<i32> = vec![1, 2];
@@ -240,3 +242,96 @@ fn local_lazy_mut() {
 
     assert_eq!(*v.get_mut(), vec![1, 2, 3, 4, 5]);
 }
+
+static RESET_INIT_COUNT: AtomicU32 = AtomicU32::new(0);
+#[dynamic(lazy)]
+static mut RESET: Vec<u32> = vec![RESET_INIT_COUNT.fetch_add(1, Ordering::Relaxed)];
+
+#[test]
+fn reset() {
+    assert!(RESET.reset().is_empty());
+
+    assert_eq!(*RESET.read(), vec![0]);
+
+    RESET.write().push(10);
+
+    assert_eq!(RESET.reset(), Phase::INITIALIZED);
+
+    assert!(RESET.phase().is_empty());
+
+    assert!(RESET.try_read().is_err());
+
+    assert_eq!(*RESET.read(), vec![1]);
+
+    RESET.reinit().push(20);
+
+    assert_eq!(*RESET.read(), vec![2, 20]);
+
+    assert_eq!(RESET.phase(), Phase::INITIALIZED);
+
+    assert_eq!(RESET_INIT_COUNT.load(Ordering::Relaxed), 3);
+}
+
+#[test]
+fn local_reset() {
+    let drop_count = AtomicU32::new(0);
+
+    struct A<'a>(&'a AtomicU32);
+    impl<'a> Drop for A<'a> {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let v = LockedLazy::from_generator(|| A(&drop_count));
+
+    v.reset();
+
+    assert_eq!(drop_count.load(Ordering::Relaxed), 0);
+
+    v.init();
+
+    v.reset();
+
+    assert_eq!(drop_count.load(Ordering::Relaxed), 1);
+
+    drop(v.reinit());
+
+    drop(v.reinit());
+
+    assert_eq!(drop_count.load(Ordering::Relaxed), 2);
+
+    drop(v);
+
+    assert_eq!(drop_count.load(Ordering::Relaxed), 3);
+
+    let v: LockedLazy<i32> = LockedLazy::from_generator(|| panic!("Panicked on purpose"));
+
+    assert!(catch_unwind(AssertUnwindSafe(|| v.reinit().is_positive())).is_err());
+
+    assert_eq!(
+        v.phase(),
+        Phase::INITIALIZATION_PANICKED | Phase::INITIALIZATION_SKIPED
+    );
+
+    v.reset();
+
+    assert!(v.phase().is_empty());
+}
+
+#[test]
+fn shared_local_lazy() {
+    let base = vec![1, 2];
+    let v = Arc::new(LockedLazy::new(move || base));
+
+    let threads = (0..4)
+        .map(|_| {
+            let v = Arc::clone(&v);
+            thread::spawn(move || v.write().push(3))
+        })
+        .collect::<Vec<_>>();
+    for t in threads {
+        t.join().unwrap();
+    }
+    assert_eq!(*v.read(), vec![1, 2, 3, 3, 3, 3]);
+}
@@ -0,0 +1,100 @@
+use static_init::lazy::{lesser_locked_lazy, locked_lazy, unsync_locked_lazy};
+use static_init::{dynamic, LockedLazy, UnSyncLockedLazy};
+
+#[derive(Debug, PartialEq)]
+struct Settings {
+    verbose: bool,
+    level: u32,
+}
+
+struct AppState {
+    settings: Settings,
+    plugins: Vec<String>,
+}
+
+#[dynamic]
+static mut APP: AppState = AppState {
+    settings: Settings {
+        verbose: false,
+        level: 1,
+    },
+    plugins: vec!["core".to_string()],
+};
+
+fn settings() -> lesser_locked_lazy::MappedReadGuard<'static, Settings> {
+    lesser_locked_lazy::ReadGuard::map(APP.read(), |app| &app.settings)
+}
+
+#[test]
+fn map_static() {
+    let s = settings();
+    assert_eq!(s.level, 1);
+    assert!(APP.fast_write().is_none());
+    let s2 = s.clone();
+    drop(s);
+    assert!(APP.fast_write().is_none());
+    drop(s2);
+
+    let mut s = lesser_locked_lazy::WriteGuard::map(APP.write(), |app| &mut app.settings);
+    s.verbose = true;
+    assert!(APP.fast_read().is_none());
+    let s: lesser_locked_lazy::MappedReadGuard<_> = s.into();
+    assert_eq!(
+        *s,
+        Settings {
+            verbose: true,
+            level: 1
+        }
+    );
+    assert!(APP.fast_read().is_some());
+    drop(s);
+
+    assert!(APP.read().settings.verbose);
+}
+
+#[test]
+fn filter_and_try_map() {
+    let l = LockedLazy::new(|| vec![1, 2, 3]);
+
+    let r = locked_lazy::ReadGuard::filter_map(l.read(), |v| v.get(5)).unwrap_err();
+    let r = locked_lazy::ReadGuard::filter_map(r, |v| v.get(1)).unwrap();
+    assert_eq!(*r, 2);
+    assert!(l.fast_write().is_none());
+    drop(r);
+
+    let mut w = locked_lazy::WriteGuard::filter_map(l.write(), |v| v.last_mut()).unwrap();
+    *w = 4;
+    drop(w);
+    assert_eq!(*l.read(), vec![1, 2, 4]);
+
+    let w = l.write();
+    let (mut w, e) =
+        locked_lazy::WriteGuard::try_map(w, |v| v.get_mut(3).ok_or("out of bounds")).unwrap_err();
+    assert_eq!(e, "out of bounds");
+    w.clear();
+    let w = locked_lazy::WriteGuard::try_map(w, |v| {
+        v.push(7);
+        v.first_mut().ok_or("empty")
+    })
+    .unwrap();
+    assert_eq!(*w, 7);
+    assert!(l.fast_read().is_none());
+    drop(w);
+
+    let r = locked_lazy::ReadGuard::try_map(l.read(), |v| v.first().ok_or(())).unwrap();
+    assert_eq!(format!("{:?}", r), "MappedReadGuard(7)");
+}
+
+#[test]
+fn unsync_map() {
+    let l = UnSyncLockedLazy::new(|| (1, String::from("a")));
+
+    let r = unsync_locked_lazy::ReadGuard::map(l.read(), |v| &v.1);
+    assert_eq!(*r, "a");
+    drop(r);
+
+    let mut w = unsync_locked_lazy::WriteGuard::map(l.write(), |v| &mut v.0);
+    *w += 1;
+    drop(w);
+    assert_eq!(l.read().0, 2);
+}
@@ -0,0 +1,136 @@
+#![cfg(feature = "poison")]
+
+use static_init::poison::{PoisonUpgradableReadGuard, PoisonWriteGuard};
+use static_init::{dynamic, Phase};
+use std::panic::catch_unwind;
+
+#[dynamic(poison)]
+static mut LESSER: Vec<i32> = vec![1, 2];
+
+#[dynamic(lazy, poison)]
+static mut LAZY: Vec<i32> = vec![1, 2];
+
+#[dynamic(lazy, drop, poison)]
+static mut DROPED: Vec<i32> = vec![1, 2];
+
+#[test]
+fn lesser_poison() {
+    assert_eq!(LESSER.read().unwrap().len(), 2);
+    assert!(!LESSER.is_poisoned());
+
+    assert!(catch_unwind(|| {
+        let mut l = LESSER.write().unwrap();
+        l.push(3);
+        panic!("half mutated");
+    })
+    .is_err());
+
+    assert!(LESSER.is_poisoned());
+    assert!(LESSER.phase().intersects(Phase::POISONED));
+
+    let r = LESSER.read().unwrap_err().into_inner();
+    assert_eq!(*r, vec![1, 2, 3]);
+    drop(r);
+
+    LESSER.write().unwrap_err().into_inner().push(4);
+    assert!(LESSER.is_poisoned());
+
+    LESSER.clear_poison();
+    assert!(!LESSER.is_poisoned());
+    assert_eq!(*LESSER.read().unwrap(), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn lazy_poison() {
+    assert!(LAZY.phase().is_empty());
+    assert!(catch_unwind(|| {
+        let _l = LAZY.write().unwrap();
+        panic!("poisoning");
+    })
+    .is_err());
+    assert!(LAZY.is_poisoned());
+    assert_eq!(LAZY.read().unwrap_err().into_inner().len(), 2);
+}
+
+#[test]
+fn no_poison_without_panic() {
+    DROPED.write().unwrap().push(3);
+    assert!(!DROPED.is_poisoned());
+    assert_eq!(DROPED.read().unwrap().len(), 3);
+}
+
+#[dynamic(poison)]
+static mut FORWARDED: Vec<i32> = vec![1, 2];
+
+#[dynamic(lazy, poison)]
+static mut MAPPED: (i32, Vec<i32>) = (0, vec![1, 2]);
+
+#[test]
+fn forwarded_locks_check_poison() {
+    assert!(FORWARDED.try_read().unwrap().is_ok());
+    assert_eq!(FORWARDED.fast_read().unwrap().unwrap().len(), 2);
+    FORWARDED.try_write().unwrap().unwrap().push(3);
+
+    let l = FORWARDED.upgradable_read().unwrap();
+    assert_eq!(l.len(), 3);
+    let mut l = PoisonUpgradableReadGuard::upgrade(l);
+    l.push(4);
+    drop(l);
+
+    assert!(catch_unwind(|| {
+        let mut l = FORWARDED.fast_write().unwrap().unwrap();
+        l.push(5);
+        panic!("half mutated");
+    })
+    .is_err());
+
+    assert!(FORWARDED.is_poisoned());
+    assert!(FORWARDED.try_read().unwrap().is_err());
+    assert!(FORWARDED.fast_read().unwrap().is_err());
+    assert!(FORWARDED.try_write().unwrap().is_err());
+    assert!(FORWARDED.fast_write().unwrap().is_err());
+    assert_eq!(
+        *FORWARDED.upgradable_read().unwrap_err().into_inner(),
+        vec![1, 2, 3, 4, 5]
+    );
+}
+
+#[test]
+fn mapped_write_guard_poisons() {
+    let l = PoisonWriteGuard::map(MAPPED.write().unwrap(), |v| &mut v.0);
+    assert_eq!(*l, 0);
+    drop(l);
+    assert!(!MAPPED.is_poisoned());
+
+    assert!(PoisonWriteGuard::filter_map(MAPPED.write().unwrap(), |_| None::<&mut i32>).is_err());
+
+    assert!(catch_unwind(|| {
+        let mut l = PoisonWriteGuard::map(MAPPED.write().unwrap(), |v| &mut v.1);
+        l.push(3);
+        panic!("half mutated");
+    })
+    .is_err());
+
+    assert!(MAPPED.is_poisoned());
+    assert_eq!(MAPPED.read().unwrap_err().into_inner().1, vec![1, 2, 3]);
+}
+
+#[cfg(feature = "timeout")]
+#[test]
+fn timed_locks_check_poison() {
+    use std::time::Duration;
+
+    #[dynamic(poison)]
+    static mut TIMED: i32 = 1;
+
+    assert_eq!(*TIMED.read_for(Duration::from_secs(1)).unwrap().unwrap(), 1);
+
+    assert!(catch_unwind(|| {
+        let _l = TIMED.write_for(Duration::from_secs(1)).unwrap().unwrap();
+        panic!("poisoning");
+    })
+    .is_err());
+
+    assert!(TIMED.read_for(Duration::from_secs(1)).unwrap().is_err());
+    assert!(TIMED.write_for(Duration::from_secs(1)).unwrap().is_err());
+}
@@ -0,0 +1,51 @@
+use static_init::{dynamic, registry, FinalyMode, InitMode, Phase};
+use std::panic::catch_unwind;
+
+#[dynamic(lazy)]
+static LAZY: Vec<i32> = vec![1, 2];
+
+#[dynamic(lazy, drop)]
+static mut LAZY_DROP: Vec<i32> = vec![1, 2];
+
+#[dynamic(lazy)]
+static PANICKING: i32 = panic!("Panicked on purpose");
+
+#[dynamic(10)]
+static RAW: i32 = 3;
+
+#[dynamic]
+static LESSER: i32 = 4;
+
+fn find(name: &str) -> (&'static static_init::StaticInfo, Phase) {
+    registry::iter()
+        .find(|(info, _)| info.variable_name == name && info.file_name == file!())
+        .unwrap()
+}
+
+#[test]
+fn registry() {
+    let (info, phase) = find("LAZY");
+    assert_eq!(info.init_mode, InitMode::Lazy);
+    assert_eq!(info.drop_mode, FinalyMode::None);
+    assert!(phase.is_empty());
+    assert_eq!(LAZY.len(), 2);
+    assert_eq!(find("LAZY").1, Phase::INITIALIZED);
+
+    let (info, _) = find("LAZY_DROP");
+    assert_eq!(info.drop_mode, FinalyMode::Drop);
+    LAZY_DROP.write().push(3);
+    assert_eq!(find("LAZY_DROP").1, Phase::INITIALIZED_AND_REGISTERED);
+
+    assert!(catch_unwind(|| *PANICKING).is_err());
+    assert!(find("PANICKING").1.contains(Phase::INITIALIZATION_PANICKED));
+
+    let (info, phase) = find("RAW");
+    assert_eq!(info.init_mode, InitMode::ProgramConstructor(10));
+    assert_eq!(phase, Phase::INITIALIZED);
+    assert_eq!(unsafe { *RAW }, 3);
+
+    let (info, phase) = find("LESSER");
+    assert_eq!(info.init_mode, InitMode::LesserLazy);
+    assert_eq!(phase, Phase::INITIALIZED);
+    assert_eq!(*LESSER, 4);
+}
@@ -0,0 +1,172 @@
+use static_init::{dynamic, Phase, SeqLockLazy};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::thread;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Config {
+    verbose: bool,
+    retries: u32,
+}
+
+#[dynamic(seqlock)]
+static mut CONFIG: Config = Config {
+    verbose: false,
+    retries: 3,
+};
+
+#[test]
+fn lesser_lazy() {
+    assert_eq!(
+        CONFIG.load(),
+        Config {
+            verbose: false,
+            retries: 3
+        }
+    );
+    assert_eq!(CONFIG.phase(), Phase::INITIALIZED);
+    CONFIG.update(|c| c.verbose = true);
+    assert!(CONFIG.load().verbose);
+    assert!(CONFIG.try_load().unwrap().verbose);
+}
+
+static GENERATED: AtomicU32 = AtomicU32::new(0);
+
+#[dynamic(lazy, seqlock)]
+static mut COUNTER: u64 = {
+    GENERATED.fetch_add(1, Ordering::Relaxed);
+    10
+};
+
+#[test]
+fn lazy() {
+    assert!(COUNTER.phase().is_empty());
+    assert!(COUNTER.try_load().is_err());
+    assert_eq!(COUNTER.load(), 10);
+    COUNTER.store(11);
+    assert_eq!(
+        COUNTER.update(|c| {
+            *c += 1;
+            *c
+        }),
+        12
+    );
+    assert_eq!(COUNTER.load(), 12);
+    assert_eq!(GENERATED.load(Ordering::Relaxed), 1);
+}
+
+static PAIR: SeqLockLazy<(u64, u64)> = SeqLockLazy::from_generator(|| (0, 0));
+
+#[test]
+fn loads_are_not_torn() {
+    static STOP: AtomicBool = AtomicBool::new(false);
+    let writer = thread::spawn(|| {
+        for i in 1..=100_000 {
+            PAIR.store((i, i));
+        }
+        STOP.store(true, Ordering::Relaxed);
+    });
+    let readers = (0..4)
+        .map(|_| {
+            thread::spawn(|| {
+                let mut last = 0;
+                while !STOP.load(Ordering::Relaxed) {
+                    let (a, b) = PAIR.load();
+                    assert_eq!(a, b);
+                    assert!(a >= last);
+                    last = a;
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    writer.join().unwrap();
+    for r in readers {
+        r.join().unwrap();
+    }
+    assert_eq!(PAIR.load(), (100_000, 100_000));
+}
+
+static PADDED: SeqLockLazy<(u8, u32)> = SeqLockLazy::from_generator(|| (0, 0));
+
+#[test]
+fn padded_loads_are_not_torn() {
+    static STOP: AtomicBool = AtomicBool::new(false);
+    let writer = thread::spawn(|| {
+        for i in 1..=10_000u32 {
+            PADDED.store((i as u8, i));
+        }
+        STOP.store(true, Ordering::Relaxed);
+    });
+    let readers = (0..4)
+        .map(|_| {
+            thread::spawn(|| {
+                while !STOP.load(Ordering::Relaxed) {
+                    let (a, b) = PADDED.load();
+                    assert_eq!(a, b as u8);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    writer.join().unwrap();
+    for r in readers {
+        r.join().unwrap();
+    }
+    assert_eq!(PADDED.load(), (16, 10_000));
+}
+
+static ATTEMPTS: AtomicU32 = AtomicU32::new(0);
+
+static RETRIED: SeqLockLazy<u32> = SeqLockLazy::from_generator(|| {
+    if ATTEMPTS.fetch_add(1, Ordering::Relaxed) == 0 {
+        panic!("first attempt")
+    }
+    33
+});
+
+#[test]
+fn initialization_panic() {
+    assert!(catch_unwind(|| RETRIED.load()).is_err());
+    let err = RETRIED.try_load().unwrap_err();
+    assert!(err.phase.intersects(Phase::INITIALIZATION_PANICKED));
+    assert_eq!(RETRIED.load(), 33);
+    assert_eq!(RETRIED.try_load().unwrap(), 33);
+}
+
+static BYTES: SeqLockLazy<[u8; 7]> = SeqLockLazy::from_generator(|| [0; 7]);
+
+#[test]
+fn unaligned_loads_are_not_torn() {
+    static STOP: AtomicBool = AtomicBool::new(false);
+    let writer = thread::spawn(|| {
+        for i in 1..=10_000u32 {
+            BYTES.update(|b| *b = [i as u8; 7]);
+        }
+        STOP.store(true, Ordering::Relaxed);
+    });
+    let readers = (0..4)
+        .map(|_| {
+            thread::spawn(|| {
+                while !STOP.load(Ordering::Relaxed) {
+                    let b = BYTES.load();
+                    assert!(b.iter().all(|x| *x == b[0]));
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    writer.join().unwrap();
+    for r in readers {
+        r.join().unwrap();
+    }
+    assert_eq!(BYTES.load(), [16; 7]);
+}
+
+#[test]
+fn panicking_update_keeps_the_value() {
+    let lazy = SeqLockLazy::<u32>::from_generator(|| 1);
+    assert!(catch_unwind(AssertUnwindSafe(|| lazy.update(|v| {
+        *v = 2;
+        panic!("on purpose")
+    })))
+    .is_err());
+    assert_eq!(lazy.load(), 1);
+}
@@ -0,0 +1,121 @@
+use static_init::{Generator, GeneratorTolerance, Phase, ShardedLockedLazy};
+use std::panic::catch_unwind;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Barrier;
+use std::thread;
+
+static GENERATED: AtomicU32 = AtomicU32::new(0);
+
+static SHARED: ShardedLockedLazy<Vec<usize>, 4> = ShardedLockedLazy::new(|| {
+    GENERATED.fetch_add(1, Ordering::Relaxed);
+    Vec::new()
+});
+
+#[test]
+fn readers_and_writers() {
+    assert!(SHARED.phase().is_empty());
+    assert!(SHARED.try_read().is_err());
+    assert!(SHARED.try_write().is_err());
+
+    let barrier = &*Box::leak(Box::new(Barrier::new(8)));
+    let threads = (0..8)
+        .map(|i| {
+            thread::spawn(move || {
+                barrier.wait();
+                SHARED.write().push(i);
+                SHARED.read().len()
+            })
+        })
+        .collect::<Vec<_>>();
+    for t in threads {
+        assert!(t.join().unwrap() >= 1);
+    }
+
+    assert_eq!(GENERATED.load(Ordering::Relaxed), 1);
+    assert_eq!(SHARED.phase(), Phase::INITIALIZED);
+    let mut v = SHARED.read().clone();
+    v.sort_unstable();
+    assert_eq!(v, (0..8).collect::<Vec<_>>());
+    assert_eq!(SHARED.try_write().unwrap().len(), 8);
+}
+
+#[test]
+fn concurrent_readers() {
+    let lazy = ShardedLockedLazy::<u32, 2>::new(|| 12);
+    let first = lazy.read();
+    thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| assert_eq!(*lazy.read(), 12));
+        }
+    });
+    assert_eq!(*first, 12);
+    drop(first);
+    *lazy.write() += 1;
+    assert_eq!(*lazy.try_read().unwrap(), 13);
+}
+
+static ATTEMPTS: AtomicU32 = AtomicU32::new(0);
+
+static RETRIED: ShardedLockedLazy<u32> = ShardedLockedLazy::new(|| {
+    if ATTEMPTS.fetch_add(1, Ordering::Relaxed) == 0 {
+        panic!("first attempt")
+    }
+    33
+});
+
+#[test]
+fn initialization_panic() {
+    assert!(catch_unwind(|| RETRIED.read().to_owned()).is_err());
+    let err = RETRIED.try_read().unwrap_err();
+    assert!(err.phase.intersects(Phase::INITIALIZATION_PANICKED));
+    assert_eq!(*RETRIED.write(), 33);
+    assert!(RETRIED.try_read().is_ok());
+}
+
+static NOT_RETRIED_ATTEMPTS: AtomicU32 = AtomicU32::new(0);
+
+struct NoRetry;
+
+impl GeneratorTolerance for NoRetry {
+    const INIT_FAILURE: bool = false;
+    const FINAL_REGISTRATION_FAILURE: bool = false;
+}
+
+impl Generator<u32> for NoRetry {
+    fn generate(&self) -> u32 {
+        NOT_RETRIED_ATTEMPTS.fetch_add(1, Ordering::Relaxed);
+        panic!("no retry")
+    }
+}
+
+static NOT_RETRIED: ShardedLockedLazy<u32, 4, NoRetry> = ShardedLockedLazy::new(NoRetry);
+
+#[test]
+fn generator_tolerance() {
+    assert!(catch_unwind(|| *NOT_RETRIED.read()).is_err());
+    assert!(catch_unwind(|| *NOT_RETRIED.write()).is_err());
+    assert!(NOT_RETRIED
+        .phase()
+        .intersects(Phase::INITIALIZATION_PANICKED));
+    assert_eq!(NOT_RETRIED_ATTEMPTS.load(Ordering::Relaxed), 1);
+}
+
+static DROPED: AtomicU32 = AtomicU32::new(0);
+
+struct A;
+
+impl Drop for A {
+    fn drop(&mut self) {
+        DROPED.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn value_is_dropped_with_the_lazy() {
+    drop(ShardedLockedLazy::<A>::new(|| A));
+    assert_eq!(DROPED.load(Ordering::Relaxed), 0);
+    let lazy = ShardedLockedLazy::<A>::new(|| A);
+    let _ = lazy.read();
+    drop(lazy);
+    assert_eq!(DROPED.load(Ordering::Relaxed), 1);
+}
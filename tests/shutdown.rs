@@ -0,0 +1,72 @@
+#![cfg(feature = "alloc")]
+
+use static_init::{destructor, dynamic, shutdown, Finaly, Phase};
+use std::sync::Mutex;
+
+static ORDER: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+struct A(u32);
+
+impl Drop for A {
+    fn drop(&mut self) {
+        ORDER.lock().unwrap().push(self.0);
+    }
+}
+
+struct B(u32);
+
+impl Finaly for B {
+    fn finaly(&self) {
+        ORDER.lock().unwrap().push(self.0);
+    }
+}
+
+#[dynamic(lazy, drop)]
+static mut FIRST: A = A(1);
+
+#[dynamic(lazy, drop)]
+static mut SECOND: A = A(2);
+
+#[dynamic(lazy, finalize)]
+static THIRD: B = B(3);
+
+#[dynamic(lazy, drop)]
+static mut NEVER_USED: A = A(4);
+
+#[test]
+fn shutdown_finalizes_once() {
+    assert_eq!(FIRST.read().0, 1);
+    assert_eq!(SECOND.read().0, 2);
+    assert_eq!(THIRD.0, 3);
+
+    let report = shutdown();
+
+    assert!(report.succeeded());
+
+    assert_eq!(
+        report.statics.iter().map(|s| s.phase).collect::<Vec<_>>(),
+        vec![Phase::INITIALIZED | Phase::REGISTERED | Phase::FINALIZED; 3]
+    );
+
+    let names = report
+        .statics
+        .iter()
+        .map(|s| s.info.map(|i| i.variable_name))
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec![Some("THIRD"), Some("SECOND"), Some("FIRST")]);
+
+    assert_eq!(*ORDER.lock().unwrap(), vec![3, 2, 1]);
+
+    assert!(FIRST.try_read().is_err());
+
+    assert!(NEVER_USED.phase().is_empty());
+
+    let report = shutdown();
+
+    assert!(report.statics.is_empty());
+}
+
+#[destructor(10)]
+extern "C" fn check_finalized_once() {
+    assert_eq!(*ORDER.lock().unwrap(), vec![3, 2, 1]);
+}
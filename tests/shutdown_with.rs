@@ -0,0 +1,37 @@
+use static_init::{dynamic, shutdown_with, Finaly, Phase};
+use std::sync::Mutex;
+
+static ORDER: Mutex<Vec<(&'static str, Phase)>> = Mutex::new(Vec::new());
+
+struct B(u32);
+
+impl Finaly for B {
+    fn finaly(&self) {}
+}
+
+#[dynamic(lazy, finalize)]
+static FIRST: B = B(1);
+
+#[dynamic(lazy, finalize)]
+static SECOND: B = B(2);
+
+#[test]
+fn shutdown_with_reports_each_static() {
+    assert_eq!(FIRST.0, 1);
+    assert_eq!(SECOND.0, 2);
+
+    shutdown_with(|info, phase| {
+        ORDER
+            .lock()
+            .unwrap()
+            .push((info.unwrap().variable_name, phase))
+    });
+
+    let phase = Phase::INITIALIZED | Phase::REGISTERED | Phase::FINALIZED;
+    assert_eq!(
+        *ORDER.lock().unwrap(),
+        vec![("SECOND", phase), ("FIRST", phase)]
+    );
+
+    shutdown_with(|_, _| panic!("statics finalized twice"));
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "startup_profile")]
+
+use static_init::{constructor, dynamic, startup_report, InitMode};
+use std::time::Duration;
+
+#[dynamic(10)]
+static SLOW: u32 = {
+    std::thread::sleep(Duration::from_millis(10));
+    1
+};
+
+#[dynamic]
+static LESSER: u32 = unsafe { *SLOW + 1 };
+
+#[dynamic(lazy)]
+static LAZY: u32 = 3;
+
+#[constructor(20)]
+extern "C" fn first() {}
+
+#[constructor(20)]
+extern "C" fn __static_init_lookalike() {}
+
+#[test]
+fn records() {
+    assert_eq!(*LAZY, 3);
+    assert_eq!(*LESSER, 2);
+    let report = startup_report();
+
+    assert_eq!(report.iter().filter(|r| r.name == "SLOW").count(), 1);
+    let slow = report.iter().find(|r| r.name == "SLOW").unwrap();
+    assert_eq!(slow.priority, Some(10));
+    assert_eq!(
+        slow.info.unwrap().init_mode,
+        InitMode::ProgramConstructor(10)
+    );
+    assert_eq!(slow.info.unwrap().file_name, file!());
+    assert!(slow.duration() >= Duration::from_millis(10));
+
+    let lesser = report.iter().find(|r| r.name == "LESSER").unwrap();
+    assert_eq!(lesser.priority, None);
+    assert!(lesser.start >= slow.end);
+
+    let first = report.iter().find(|r| r.name == "first").unwrap();
+    assert_eq!(first.priority, Some(20));
+    assert!(first.info.is_none());
+    assert!(first.end <= slow.start);
+
+    let lookalike = report
+        .iter()
+        .find(|r| r.name == "__static_init_lookalike")
+        .unwrap();
+    assert!(lookalike.info.is_none());
+
+    assert!(report.iter().all(|r| r.name != "LAZY"));
+    assert!(report.total() >= lesser.end);
+    assert!(report
+        .records()
+        .windows(2)
+        .all(|w| w[0].start <= w[1].start));
+}
+
+#[test]
+fn chrome_trace() {
+    let trace = startup_report().to_chrome_trace();
+    assert!(trace.starts_with("{\"traceEvents\":[{"));
+    assert!(trace.ends_with("}]}"));
+    assert!(trace.contains("\"name\":\"SLOW\",\"cat\":\"static\",\"ph\":\"X\""));
+    assert!(trace.contains("\"priority\":10,\"file\":\"tests/startup_profile.rs\""));
+    assert!(trace.contains("\"name\":\"first\",\"cat\":\"constructor\""));
+}
@@ -0,0 +1,111 @@
+#![cfg(feature = "stats")]
+
+use static_init::statistics::Statistics;
+use static_init::{dynamic, registry, Generator, GeneratorTolerance, LockedLazy};
+use std::sync::Barrier;
+use std::thread;
+use std::time::Duration;
+
+#[dynamic(lazy, stats)]
+static mut CONTENDED: Vec<u32> = vec![];
+
+#[dynamic(stats, drop)]
+static mut DROPED: u32 = 1;
+
+#[dynamic(lazy)]
+static mut NOT_OBSERVED: u32 = 0;
+
+#[test]
+fn contention_is_recorded() {
+    let stats = CONTENDED.statistics().unwrap();
+    assert_eq!(stats.write_contentions(), 0);
+
+    let locked = Barrier::new(2);
+    thread::scope(|s| {
+        let w = CONTENDED.write();
+        s.spawn(|| {
+            locked.wait();
+            CONTENDED.write().push(2);
+        });
+        locked.wait();
+        thread::sleep(Duration::from_millis(20));
+        drop(w);
+    });
+    assert_eq!(*CONTENDED.read(), vec![2]);
+
+    assert_eq!(stats.write_contentions(), 1);
+    assert!(stats.write_wait_time() >= Duration::from_millis(10));
+    assert!(stats.max_wait_time() >= stats.write_wait_time());
+
+    stats.reset();
+    assert_eq!(stats.write_contentions(), 0);
+    assert_eq!(stats.max_wait_time(), Duration::ZERO);
+}
+
+#[test]
+fn listed_in_registry() {
+    let names = registry::statistics()
+        .filter(|(info, _)| info.file_name == file!())
+        .map(|(info, _)| info.variable_name)
+        .collect::<Vec<_>>();
+    assert!(names.contains(&"CONTENDED"));
+    assert!(names.contains(&"DROPED"));
+    assert!(!names.contains(&"NOT_OBSERVED"));
+    assert!(core::ptr::eq(
+        registry::statistics()
+            .find(|(info, _)| info.variable_name == "DROPED" && info.file_name == file!())
+            .unwrap()
+            .1,
+        DROPED.statistics().unwrap()
+    ));
+    assert!(NOT_OBSERVED.statistics().is_none());
+}
+
+struct Observed;
+
+static STATS: Statistics = Statistics::new();
+
+impl GeneratorTolerance for Observed {
+    const INIT_FAILURE: bool = true;
+    const FINAL_REGISTRATION_FAILURE: bool = false;
+    const STATISTICS: Option<fn() -> &'static Statistics> = Some(|| &STATS);
+}
+
+impl Generator<u32> for Observed {
+    fn generate(&self) -> u32 {
+        0
+    }
+}
+
+#[test]
+fn generator_statistics() {
+    let l = LockedLazy::from_generator(Observed);
+    assert!(core::ptr::eq(l.statistics().unwrap(), &STATS));
+    assert!(LockedLazy::<u32, _>::from_generator(|| 0)
+        .statistics()
+        .is_none());
+
+    let r = l.read();
+    thread::scope(|s| {
+        s.spawn(|| *l.write() += 1);
+        thread::sleep(Duration::from_millis(10));
+        drop(r);
+    });
+    assert_eq!(*l.read(), 1);
+    assert_eq!(STATS.write_contentions(), 1);
+    assert_eq!(STATS.read_contentions(), 0);
+}
+
+#[cfg(feature = "poison")]
+#[dynamic(lazy, poison, stats)]
+static mut POISONED: u32 = 0;
+
+#[cfg(feature = "poison")]
+#[test]
+fn poisoned_static() {
+    *POISONED.write().unwrap() += 1;
+    assert!(
+        registry::statistics().any(|(info, s)| info.variable_name == "POISONED"
+            && core::ptr::eq(s, POISONED.statistics().unwrap()))
+    );
+}
@@ -0,0 +1,133 @@
+#![cfg(feature = "timeout")]
+
+use static_init::{dynamic, TimeoutError};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Barrier;
+use std::thread;
+use std::time::{Duration, Instant};
+
+static INIT_STARTED: AtomicBool = AtomicBool::new(false);
+static INIT_RELEASED: AtomicBool = AtomicBool::new(false);
+
+#[dynamic(lazy)]
+static mut SLOW: u32 = {
+    INIT_STARTED.store(true, Ordering::Release);
+    while !INIT_RELEASED.load(Ordering::Acquire) {
+        thread::sleep(Duration::from_millis(1));
+    }
+    42
+};
+
+#[dynamic(lazy)]
+static mut CONTENDED: Vec<u32> = vec![1];
+
+#[dynamic(lazy, drop)]
+static mut DROPED: Vec<u32> = vec![1];
+
+#[dynamic]
+static mut LESSER: u32 = 3;
+
+#[dynamic(lazy)]
+static mut STRESSED: usize = 0;
+
+#[test]
+fn timeout_during_initialization() {
+    thread::scope(|s| {
+        s.spawn(|| assert_eq!(*SLOW.read(), 42));
+        while !INIT_STARTED.load(Ordering::Acquire) {
+            thread::yield_now();
+        }
+        let start = Instant::now();
+        assert_eq!(
+            SLOW.read_for(Duration::from_millis(50)).unwrap_err(),
+            TimeoutError::Initialization
+        );
+        assert!(start.elapsed() >= Duration::from_millis(50));
+        assert_eq!(
+            SLOW.write_for(Duration::from_millis(10)).unwrap_err(),
+            TimeoutError::Initialization
+        );
+        INIT_RELEASED.store(true, Ordering::Release);
+        assert_eq!(*SLOW.read_for(Duration::from_secs(10)).unwrap(), 42);
+    });
+}
+
+#[test]
+fn timeout_during_contention() {
+    assert_eq!(CONTENDED.read().len(), 1);
+    let locked = Barrier::new(2);
+    let timed_out = Barrier::new(2);
+    thread::scope(|s| {
+        s.spawn(|| {
+            let mut w = CONTENDED.write();
+            locked.wait();
+            timed_out.wait();
+            w.push(2);
+        });
+        locked.wait();
+        assert_eq!(
+            CONTENDED.read_for(Duration::from_millis(20)).unwrap_err(),
+            TimeoutError::Contention
+        );
+        assert_eq!(
+            CONTENDED
+                .write_until(Instant::now() + Duration::from_millis(20))
+                .unwrap_err(),
+            TimeoutError::Contention
+        );
+        timed_out.wait();
+        assert_eq!(
+            *CONTENDED.read_for(Duration::from_secs(10)).unwrap(),
+            vec![1, 2]
+        );
+    });
+    let r = CONTENDED.read();
+    assert_eq!(
+        CONTENDED.write_for(Duration::from_millis(20)).unwrap_err(),
+        TimeoutError::Contention
+    );
+    assert_eq!(CONTENDED.read_for(Duration::ZERO).unwrap().len(), 2);
+    drop(r);
+}
+
+#[test]
+fn droped_and_lesser() {
+    assert_eq!(DROPED.read_for(Duration::from_secs(10)).unwrap().len(), 1);
+    let w = DROPED.write_for(Duration::from_secs(10)).unwrap();
+    thread::scope(|s| {
+        s.spawn(|| {
+            assert_eq!(
+                DROPED.read_for(Duration::from_millis(20)).unwrap_err(),
+                TimeoutError::Contention
+            )
+        });
+    });
+    drop(w);
+    DROPED.write_for(Duration::MAX).unwrap().push(2);
+    assert_eq!(*DROPED.read_until(Instant::now()).unwrap(), vec![1, 2]);
+
+    *LESSER.write_for(Duration::from_secs(10)).unwrap() += 1;
+    assert_eq!(*LESSER.read_for(Duration::ZERO).unwrap(), 4);
+}
+
+#[test]
+fn abandoned_waits_leave_the_lock_usable() {
+    let successes = AtomicUsize::new(0);
+    thread::scope(|s| {
+        for _ in 0..8 {
+            s.spawn(|| {
+                for i in 0..500 {
+                    if i % 3 == 0 {
+                        let _ = STRESSED.read_for(Duration::from_micros(20));
+                    } else if let Ok(mut w) = STRESSED.write_for(Duration::from_micros(20)) {
+                        *w += 1;
+                        successes.fetch_add(1, Ordering::Relaxed);
+                        thread::yield_now();
+                    }
+                }
+            });
+        }
+    });
+    *STRESSED.write() += 1;
+    assert_eq!(*STRESSED.read(), successes.load(Ordering::Relaxed) + 1);
+}
@@ -0,0 +1,129 @@
+#![cfg(feature = "tracing")]
+
+use static_init::{dynamic, trace, Finaly};
+use std::fmt::{Debug, Write};
+use std::panic::catch_unwind;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Vec<String>>>);
+
+struct Fields(String);
+
+impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        write!(self.0, " {}={:?}", field.name(), value).unwrap();
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        static ID: AtomicU64 = AtomicU64::new(1);
+        let mut f = Fields(format!("span {}", span.metadata().name()));
+        span.record(&mut f);
+        self.0.lock().unwrap().push(f.0);
+        Id::from_u64(ID.fetch_add(1, Ordering::Relaxed))
+    }
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+    fn event(&self, event: &Event<'_>) {
+        let mut f = Fields(format!("event {}", event.metadata().level()));
+        event.record(&mut f);
+        self.0.lock().unwrap().push(f.0);
+    }
+    fn enter(&self, _: &Id) {}
+    fn exit(&self, _: &Id) {}
+}
+
+impl Recorder {
+    fn record(&self, f: impl FnOnce()) -> Vec<String> {
+        tracing::subscriber::with_default(self.clone(), f);
+        self.0.lock().unwrap().clone()
+    }
+}
+
+#[dynamic(lazy)]
+static INITED: u32 = 1;
+
+#[dynamic(lazy)]
+static PANICKING: u32 = panic!("failed");
+
+#[dynamic(lazy, drop)]
+static mut DROPED: Vec<u32> = vec![1];
+
+struct A;
+
+impl Finaly for A {
+    fn finaly(&self) {}
+}
+
+#[dynamic(lazy, finalize)]
+static FINALIZED: A = A;
+
+#[dynamic(lazy)]
+static mut CONTENDED: u32 = 0;
+
+#[test]
+fn init_span() {
+    let log = Recorder::default().record(|| assert_eq!(*INITED, 1));
+    assert_eq!(log.len(), 1);
+    assert!(log[0].starts_with("span init variable_name=\"INITED\""));
+    assert!(log[0].contains("file_name=\"tests/tracing.rs\""));
+    assert!(log[0].contains("init_mode=Lazy"));
+
+    let log = Recorder::default().record(|| assert_eq!(*INITED, 1));
+    assert!(log.is_empty());
+}
+
+#[test]
+fn init_panic_event() {
+    let log = Recorder::default().record(|| assert!(catch_unwind(|| *PANICKING).is_err()));
+    assert!(log[0].starts_with("span init variable_name=\"PANICKING\""));
+    assert_eq!(log[1], "event ERROR message=initialization panicked");
+}
+
+#[test]
+fn finalization_spans() {
+    let log = Recorder::default().record(|| {
+        assert_eq!(DROPED.read().len(), 1);
+        DROPED.finalize_now();
+        let _ = &*FINALIZED;
+        FINALIZED.finalize_now();
+    });
+    assert!(log
+        .iter()
+        .any(|l| l.starts_with("span drop variable_name=\"DROPED\"")));
+    assert!(log.iter().any(
+        |l| l.starts_with("span finalize variable_name=\"FINALIZED\"")
+            && l.contains("drop_mode=Finalize")
+    ));
+}
+
+#[cfg(not(feature = "spin_loop"))]
+#[test]
+fn long_wait_event() {
+    trace::set_wait_threshold(Duration::from_millis(5));
+    assert_eq!(trace::wait_threshold(), Duration::from_millis(5));
+
+    let recorder = Recorder::default();
+    let w = CONTENDED.write();
+    thread::scope(|s| {
+        s.spawn(|| recorder.record(|| *CONTENDED.write() += 1));
+        thread::sleep(Duration::from_millis(30));
+        drop(w);
+    });
+    let log = recorder.0.lock().unwrap();
+    assert!(log.iter().any(|l| l
+        .starts_with("event WARN message=long wait on the lock of a lazy")
+        && l.contains("variable_name=\"CONTENDED\"")
+        && l.contains("waited=")));
+}
@@ -0,0 +1,117 @@
+use static_init::{dynamic, InitError, Lazy, Phase, TryLazy, TryLockedLazy};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+static FAIL_COUNT: AtomicU32 = AtomicU32::new(0);
+
+#[dynamic(lazy, fallible)]
+static MAY_FAIL: Result<Vec<i32>, u32> = {
+    let c = FAIL_COUNT.fetch_add(1, Ordering::Relaxed);
+    if c < 2 {
+        Err(c)
+    } else {
+        Ok(vec![1, 2])
+    }
+};
+
+#[test]
+fn may_fail() {
+    assert!(MAY_FAIL.phase().is_empty());
+
+    assert!(MAY_FAIL.try_get().is_err());
+
+    assert_eq!(MAY_FAIL.get().unwrap_err(), InitError::Generator(0));
+
+    assert_eq!(MAY_FAIL.phase(), Phase::INITIALIZATION_SKIPED);
+
+    assert_eq!(MAY_FAIL.get().unwrap_err(), InitError::Generator(1));
+
+    assert_eq!(*MAY_FAIL.get().unwrap(), vec![1, 2]);
+
+    assert_eq!(MAY_FAIL.phase(), Phase::INITIALIZED);
+
+    assert_eq!(FAIL_COUNT.load(Ordering::Relaxed), 3);
+
+    assert_eq!(*MAY_FAIL.try_get().unwrap(), vec![1, 2]);
+}
+
+static FAIL_ONCE_COUNT: AtomicU32 = AtomicU32::new(0);
+
+#[dynamic(lazy, fallible, try_init_once)]
+static FAIL_ONCE: Result<i32, &'static str> = {
+    FAIL_ONCE_COUNT.fetch_add(1, Ordering::Relaxed);
+    Err("failed on purpose")
+};
+
+#[test]
+fn fail_once() {
+    assert_eq!(
+        FAIL_ONCE.get().unwrap_err(),
+        InitError::Generator("failed on purpose")
+    );
+
+    assert!(matches!(FAIL_ONCE.get(), Err(InitError::Access(_))));
+
+    assert_eq!(FAIL_ONCE.phase(), Phase::INITIALIZATION_SKIPED);
+
+    assert_eq!(FAIL_ONCE_COUNT.load(Ordering::Relaxed), 1);
+}
+
+#[dynamic(lazy, fallible)]
+static mut LOCKED: Result<Vec<i32>, ()> = Ok(vec![1, 2]);
+
+#[test]
+fn locked() {
+    assert!(LOCKED.phase().is_empty());
+
+    LOCKED.write().unwrap().push(3);
+
+    assert_eq!(*LOCKED.read().unwrap(), vec![1, 2, 3]);
+
+    assert_eq!(LOCKED.phase(), Phase::INITIALIZED);
+}
+
+#[test]
+fn local() {
+    let v: TryLazy<i32, ()> = TryLazy::new(|| panic!("Panicked on purpose"));
+
+    assert!(catch_unwind(AssertUnwindSafe(|| v.get().is_ok())).is_err());
+
+    assert_eq!(
+        v.phase(),
+        Phase::INITIALIZATION_PANICKED | Phase::INITIALIZATION_SKIPED
+    );
+
+    let v: TryLockedLazy<i32, &str> = TryLockedLazy::new(|| Err("error"));
+
+    assert!(matches!(v.write(), Err(InitError::Generator("error"))));
+
+    assert!(v.read().is_err());
+}
+
+#[test]
+fn lazy_try_get_or_init() {
+    let v: Lazy<i32> = Lazy::from_generator(|| 0);
+
+    assert_eq!(
+        v.try_get_or_init(|| Err("error")).unwrap_err(),
+        InitError::Generator("error")
+    );
+
+    assert_eq!(Lazy::phase(&v), Phase::INITIALIZATION_SKIPED);
+
+    assert_eq!(*v.try_get_or_init(|| Ok::<_, ()>(33)).unwrap(), 33);
+
+    assert_eq!(Lazy::phase(&v), Phase::INITIALIZED);
+
+    assert_eq!(*v.try_get_or_init(|| Ok::<_, ()>(42)).unwrap(), 33);
+
+    let v = Lazy::new(|| 0);
+
+    assert!(v.try_get_or_init(|| Err(())).is_err());
+
+    assert!(matches!(
+        v.try_get_or_init(|| Ok::<_, ()>(1)),
+        Err(InitError::Access(_))
+    ));
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "alloc")]
+
+use static_init::{destructor, Phase, TypeMap};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::thread;
+
+static FINALIZE_A_COUNT: AtomicU32 = AtomicU32::new(0);
+
+struct A(u32);
+
+impl Drop for A {
+    fn drop(&mut self) {
+        FINALIZE_A_COUNT.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+static GENERATED: AtomicU32 = AtomicU32::new(0);
+
+static DROPED: TypeMap<A> = TypeMap::new(|| A(GENERATED.fetch_add(1, Ordering::Relaxed) + 33));
+
+#[test]
+fn one_lazy_per_type() {
+    let a = DROPED.get::<u8>();
+    assert!(a.phase().is_empty());
+    assert!(a.try_read().is_err());
+
+    assert!(std::ptr::eq(a, DROPED.get::<u8>()));
+    assert!(!std::ptr::eq(a, DROPED.get::<u16>()));
+    assert!(!std::ptr::eq(a, DROPED.get::<str>()));
+    assert_eq!(DROPED.len(), 3);
+
+    let v = a.read().0;
+    assert!(v >= 33);
+    assert_eq!(a.phase(), Phase::INITIALIZED | Phase::REGISTERED);
+
+    a.write().0 = 12;
+    assert_eq!(DROPED.get::<u8>().read().0, 12);
+    assert_ne!(DROPED.get::<u16>().read().0, 12);
+}
+
+#[destructor(10)]
+extern "C" fn check_a_finalized() {
+    // the lazies of u8 and u16 are initialized, not the one of str
+    assert_eq!(FINALIZE_A_COUNT.load(Ordering::Relaxed), 2)
+}
+
+struct Key<const I: usize>;
+
+static SHARED: TypeMap<Vec<usize>> = TypeMap::new(Vec::new);
+
+#[test]
+fn concurrent_access() {
+    assert!(SHARED.is_empty());
+    let threads = (0..8)
+        .map(|i| {
+            thread::spawn(move || {
+                SHARED.get::<Key<0>>().write().push(i);
+                SHARED.get::<Key<1>>().write().push(i);
+                SHARED.get::<Key<0>>() as *const _ as usize
+            })
+        })
+        .collect::<Vec<_>>();
+    for t in threads {
+        assert_eq!(
+            t.join().unwrap(),
+            SHARED.get::<Key<0>>() as *const _ as usize
+        );
+    }
+    assert_eq!(SHARED.len(), 2);
+    let mut v = SHARED.get::<Key<0>>().read().to_vec();
+    v.sort_unstable();
+    assert_eq!(v, (0..8).collect::<Vec<_>>());
+    assert_eq!(SHARED.get::<Key<1>>().read().len(), 8);
+}
@@ -257,3 +257,22 @@ fn local_lazy_mut() {
 
     assert_eq!(*v.get_mut(), vec![1, 2, 3, 4, 5]);
 }
+
+#[test]
+fn local_reset() {
+    let v = UnSyncLockedLazy::from_generator(|| vec![1, 2]);
+
+    assert!(v.reset().is_empty());
+
+    v.write().push(3);
+
+    assert_eq!(v.reset(), Phase::INITIALIZED);
+
+    assert!(v.try_read().is_err());
+
+    assert_eq!(*v.read(), vec![1, 2]);
+
+    v.reinit().push(4);
+
+    assert_eq!(*v.read(), vec![1, 2, 4]);
+}
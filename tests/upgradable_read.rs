@@ -0,0 +1,139 @@
+use static_init::lazy::{lesser_locked_lazy, locked_lazy, locked_lazy_droped};
+use static_init::{dynamic, LockedLazy};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Barrier;
+use std::thread;
+use std::time::Duration;
+
+#[dynamic]
+static mut ROUTES: Vec<u32> = vec![];
+
+#[dynamic(lazy)]
+static mut COUNTER: u32 = 0;
+
+#[dynamic(lazy, drop)]
+static mut DROPED: Vec<u32> = vec![1];
+
+#[test]
+fn coexist_with_readers() {
+    let l = LockedLazy::new(|| vec![1, 2]);
+
+    let u = l.upgradable_read();
+    assert_eq!(u.len(), 2);
+    let r1 = l.read();
+    let r2 = l.fast_read().unwrap();
+    assert_eq!(*r1, *u);
+    assert_eq!(*r2, *u);
+    assert!(l.fast_write().is_none());
+    drop(r1);
+    drop(r2);
+
+    let mut w = locked_lazy::UpgradableReadGuard::upgrade(u);
+    w.push(3);
+    assert!(l.fast_read().is_none());
+
+    let u: locked_lazy::UpgradableReadGuard<_> = w.into();
+    assert_eq!(*l.read(), vec![1, 2, 3]);
+    let r: locked_lazy::ReadGuard<_> = u.into();
+    let _u = l.upgradable_read();
+    assert_eq!(*r, vec![1, 2, 3]);
+}
+
+#[test]
+fn upgrade_waits_for_readers() {
+    let released = AtomicBool::new(false);
+    let barrier = Barrier::new(2);
+
+    thread::scope(|s| {
+        s.spawn(|| {
+            let r = COUNTER.read();
+            barrier.wait();
+            thread::sleep(Duration::from_millis(100));
+            released.store(true, Ordering::Relaxed);
+            drop(r);
+        });
+        barrier.wait();
+        let u = COUNTER.upgradable_read();
+        let mut w = locked_lazy::UpgradableReadGuard::upgrade(u);
+        assert!(released.load(Ordering::Relaxed));
+        *w += 1;
+    });
+    assert_eq!(*COUNTER.read(), 1);
+}
+
+#[test]
+fn upgrade_is_not_starved_by_readers() {
+    let l = LockedLazy::new(|| 0);
+    let upgraded = AtomicBool::new(false);
+    let u = l.upgradable_read();
+
+    thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                // read locks overlap so that there is always a reader
+                while !upgraded.load(Ordering::Relaxed) {
+                    let r = l.read();
+                    thread::sleep(Duration::from_millis(1));
+                    drop(r);
+                }
+            });
+        }
+        thread::sleep(Duration::from_millis(20));
+        let mut w = locked_lazy::UpgradableReadGuard::upgrade(u);
+        upgraded.store(true, Ordering::Relaxed);
+        *w += 1;
+    });
+    assert_eq!(*l.read(), 1);
+}
+
+#[test]
+fn upgrade_while_writers_wait() {
+    let l = LockedLazy::new(|| 0);
+    thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for _ in 0..200 {
+                    *l.write() += 1;
+                }
+            });
+        }
+        for _ in 0..200 {
+            let u = l.upgradable_read();
+            *locked_lazy::UpgradableReadGuard::upgrade(u) += 1;
+        }
+    });
+    assert_eq!(*l.read(), 1000);
+}
+
+#[test]
+fn insert_if_absent() {
+    const THREADS: u32 = 8;
+    thread::scope(|s| {
+        for i in 0..THREADS {
+            s.spawn(move || {
+                for k in 0..100 {
+                    let id = (i + k) % THREADS;
+                    let u = ROUTES.upgradable_read();
+                    let r = ROUTES.read();
+                    assert_eq!(*r, *u);
+                    drop(r);
+                    if !u.contains(&id) {
+                        let mut w = lesser_locked_lazy::UpgradableReadGuard::upgrade(u);
+                        w.push(id);
+                    }
+                }
+            });
+        }
+    });
+    let mut routes = (*ROUTES.read()).clone();
+    routes.sort_unstable();
+    assert_eq!(routes, (0..THREADS).collect::<Vec<_>>());
+}
+
+#[test]
+fn droped_lazy() {
+    let u = DROPED.upgradable_read();
+    assert_eq!(*u, vec![1]);
+    locked_lazy_droped::UpgradableReadGuard::upgrade(u).push(2);
+    assert_eq!(*DROPED.read(), vec![1, 2]);
+}